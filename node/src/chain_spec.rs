@@ -4,3 +4,5 @@ pub mod testnet;
 pub mod mainnet;
 
 pub mod util;
+
+pub mod predeploy;
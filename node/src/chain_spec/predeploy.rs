@@ -0,0 +1,405 @@
+//! predeploy manifest compiled into the genesis of built-in chain specs
+//!
+//! A manifest lists the system contracts to instantiate at genesis, either as TOML or JSON:
+//!
+//! ```toml
+//! gas_limit = 200000000000
+//!
+//! [[contracts]]
+//! name = "native_erc20"
+//! # relative to the manifest
+//! wasm = "native_fungible_token.wasm"
+//! address = "0x0000000000000000000000000000000000000000000000000000000000000001"
+//! constructor = "0x45fd0674"
+//! # SCALE encoded constructor arguments
+//! args = "0x00000000"
+//! # endowment in LAGUNAS, taken from the treasury
+//! balance = 10
+//! capabilities = ["NativeTransferFrom"]
+//!
+//! # register the contract as a verified asset
+//! [contracts.asset]
+//! standard = "Erc20"
+//! decimals = 18
+//! fee_enabled = true
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use laguna_runtime::{
+	constants::{LAGUNAS, LAGUNA_NATIVE_CURRENCY},
+	impl_pallet_treasury::TreasuryPalletId,
+	Contracts, GenesisConfig, Runtime,
+};
+use pallet_system_contract_deployer::Capability;
+use primitives::{AccountId, CurrencyId, TokenStandard};
+use serde::Deserialize;
+use sp_runtime::traits::{AccountIdConversion, Hash};
+
+const DEFAULT_GAS_LIMIT: u64 = 200_000_000_000;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Manifest {
+	/// gas limit of each constructor
+	#[serde(default = "default_gas_limit")]
+	pub gas_limit: u64,
+	#[serde(default)]
+	pub contracts: Vec<Predeploy>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Predeploy {
+	pub name: String,
+	pub wasm: PathBuf,
+	/// 32 bytes hex encoded destined address
+	pub address: String,
+	/// hex encoded constructor selector
+	pub constructor: String,
+	/// hex encoded arguments appended to the selector
+	#[serde(default)]
+	pub args: Option<String>,
+	/// initial balance of the contract in LAGUNAS
+	#[serde(default)]
+	pub balance: Option<u64>,
+	#[serde(default)]
+	pub capabilities: Vec<Capability>,
+	#[serde(default)]
+	pub asset: Option<PredeployAsset>,
+	/// loaded from `wasm`
+	#[serde(skip)]
+	pub code: Vec<u8>,
+}
+
+/// registration of a predeployed contract in the contract asset registry
+#[derive(Debug, Clone, Deserialize)]
+pub struct PredeployAsset {
+	pub standard: TokenStandard,
+	pub decimals: u8,
+	#[serde(default)]
+	pub mintable: bool,
+	/// accept the asset for fees
+	#[serde(default)]
+	pub fee_enabled: bool,
+}
+
+fn default_gas_limit() -> u64 {
+	DEFAULT_GAS_LIMIT
+}
+
+fn decode_hex(name: &str, field: &str, value: &str) -> Result<Vec<u8>, String> {
+	hex::decode(value.trim_start_matches("0x"))
+		.map_err(|e| format!("predeploy {}: invalid {}: {}", name, field, e))
+}
+
+impl Predeploy {
+	fn destined_address(&self) -> Result<[u8; 32], String> {
+		decode_hex(&self.name, "address", &self.address)?
+			.try_into()
+			.map_err(|_| format!("predeploy {}: address must be 32 bytes", self.name))
+	}
+
+	fn constructor_data(&self) -> Result<Vec<u8>, String> {
+		let mut data = decode_hex(&self.name, "constructor", &self.constructor)?;
+		if let Some(args) = &self.args {
+			data.append(&mut decode_hex(&self.name, "args", args)?);
+		}
+		Ok(data)
+	}
+}
+
+impl Manifest {
+	/// parse the manifest, as JSON if its extension is `.json` and TOML otherwise, and read the
+	/// wasm blobs it refers to
+	pub fn load(path: &Path) -> Result<Self, String> {
+		let content = std::fs::read_to_string(path)
+			.map_err(|e| format!("cannot read predeploy manifest {}: {}", path.display(), e))?;
+
+		let mut manifest: Manifest = match path.extension().and_then(|ext| ext.to_str()) {
+			Some("json") => serde_json::from_str(&content).map_err(|e| e.to_string())?,
+			_ => toml::from_str(&content).map_err(|e| e.to_string())?,
+		};
+
+		let base = path.parent().unwrap_or_else(|| Path::new("."));
+		for contract in manifest.contracts.iter_mut() {
+			let wasm = base.join(&contract.wasm);
+			contract.code = std::fs::read(&wasm).map_err(|e| {
+				format!("predeploy {}: cannot read {}: {}", contract.name, wasm.display(), e)
+			})?;
+
+			// fail early rather than in the genesis build
+			contract.destined_address()?;
+			contract.constructor_data()?;
+		}
+
+		Ok(manifest)
+	}
+
+	/// add the predeploys to the genesis, the system contract deployer is expected to be endowed
+	/// and the endowments of the contracts are taken from the treasury
+	pub fn apply(&self, genesis: &mut GenesisConfig) -> Result<(), String> {
+		let deployer: AccountId =
+			<Runtime as pallet_system_contract_deployer::Config>::PalletId::get()
+				.try_into_account()
+				.expect("Invalid PalletId");
+		let treasury: AccountId = TreasuryPalletId::get().try_into_account().unwrap();
+
+		genesis.system_contract_deployer.gas_limit = self.gas_limit;
+
+		for contract in &self.contracts {
+			let destined = contract.destined_address().expect("checked when loaded");
+			let code_hash = <Runtime as frame_system::Config>::Hashing::hash(&contract.code);
+			// the address the contract ends up at once mapped by the address generator
+			let address = Contracts::contract_address(&deployer, &code_hash, &destined);
+
+			let deployer_config = &mut genesis.system_contract_deployer;
+			deployer_config.addr.push(destined);
+			deployer_config.code.push(contract.code.clone());
+			deployer_config
+				.data
+				.push(contract.constructor_data().expect("checked when loaded"));
+			// keep capabilities aligned with the contracts deployed before the manifest
+			deployer_config.capabilities.resize(deployer_config.addr.len() - 1, vec![]);
+			deployer_config.capabilities.push(contract.capabilities.clone());
+
+			if let Some(balance) = contract.balance {
+				let amount = LAGUNAS * balance as u128;
+				let balances = &mut genesis.tokens.balances;
+
+				// the endowment doesn't change the initial supply
+				let (_, _, treasury_balance) = balances
+					.iter_mut()
+					.find(|(who, currency, _)| {
+						*who == treasury && *currency == LAGUNA_NATIVE_CURRENCY
+					})
+					.ok_or_else(|| {
+						format!("predeploy {}: the treasury is not endowed", contract.name)
+					})?;
+				*treasury_balance = treasury_balance.checked_sub(amount).ok_or_else(|| {
+					format!(
+						"predeploy {}: the treasury can't afford an endowment of {} LAGUNAs",
+						contract.name, balance
+					)
+				})?;
+
+				balances.push((address.clone(), LAGUNA_NATIVE_CURRENCY, amount));
+			}
+
+			if let Some(asset) = &contract.asset {
+				let registry = &mut genesis.contract_assets_registry;
+				registry.approved_code_hashes.push(code_hash);
+				registry.assets.push((
+					address.clone(),
+					code_hash,
+					asset.standard,
+					asset.decimals,
+					asset.mintable,
+				));
+
+				if asset.fee_enabled {
+					genesis
+						.fee_enablement
+						.enabled
+						.push((CurrencyId::Erc20(address.clone().into()), true));
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const ADDRESS: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+	/// write the manifest next to a dummy wasm blob in a fresh directory
+	fn write_manifest(test: &str, file: &str, content: &str) -> PathBuf {
+		let dir =
+			std::env::temp_dir().join(format!("laguna-predeploy-{}-{}", test, std::process::id()));
+		std::fs::create_dir_all(&dir).unwrap();
+		std::fs::write(dir.join("token.wasm"), b"\0asm").unwrap();
+
+		let path = dir.join(file);
+		std::fs::write(&path, content).unwrap();
+		path
+	}
+
+	fn toml_manifest() -> String {
+		format!(
+			r#"
+gas_limit = 100
+
+[[contracts]]
+name = "token"
+wasm = "token.wasm"
+address = "{}"
+constructor = "0x45fd0674"
+args = "0x0102"
+balance = 10
+capabilities = ["NativeTransferFrom"]
+
+[contracts.asset]
+standard = "Erc20"
+decimals = 18
+fee_enabled = true
+"#,
+			ADDRESS
+		)
+	}
+
+	fn treasury() -> AccountId {
+		TreasuryPalletId::get().try_into_account().unwrap()
+	}
+
+	fn treasury_balance(genesis: &GenesisConfig) -> u128 {
+		genesis
+			.tokens
+			.balances
+			.iter()
+			.find(|(who, currency, _)| *who == treasury() && *currency == LAGUNA_NATIVE_CURRENCY)
+			.map(|(_, _, amount)| *amount)
+			.unwrap()
+	}
+
+	fn endowed_genesis(amount: u128) -> GenesisConfig {
+		let mut genesis = GenesisConfig::default();
+		genesis.tokens.balances.push((treasury(), LAGUNA_NATIVE_CURRENCY, amount));
+		genesis
+	}
+
+	#[test]
+	fn test_load_toml() {
+		let manifest =
+			Manifest::load(&write_manifest("toml", "predeploy.toml", &toml_manifest())).unwrap();
+
+		assert_eq!(manifest.gas_limit, 100);
+		assert_eq!(manifest.contracts.len(), 1);
+
+		let contract = &manifest.contracts[0];
+		assert_eq!(contract.code, b"\0asm".to_vec());
+		assert_eq!(contract.destined_address().unwrap()[31], 1);
+		assert_eq!(contract.constructor_data().unwrap(), vec![0x45, 0xfd, 0x06, 0x74, 0x01, 0x02]);
+		assert_eq!(contract.balance, Some(10));
+		assert_eq!(contract.capabilities, vec![Capability::NativeTransferFrom]);
+
+		let asset = contract.asset.as_ref().unwrap();
+		assert_eq!(asset.standard, TokenStandard::Erc20);
+		assert_eq!(asset.decimals, 18);
+		assert!(!asset.mintable);
+		assert!(asset.fee_enabled);
+	}
+
+	#[test]
+	fn test_load_json() {
+		let json = format!(
+			r#"{{"contracts": [{{
+				"name": "token",
+				"wasm": "token.wasm",
+				"address": "{}",
+				"constructor": "0x45fd0674"
+			}}]}}"#,
+			ADDRESS
+		);
+		let manifest = Manifest::load(&write_manifest("json", "predeploy.json", &json)).unwrap();
+
+		// omitted fields fall back to their defaults
+		assert_eq!(manifest.gas_limit, DEFAULT_GAS_LIMIT);
+		let contract = &manifest.contracts[0];
+		assert_eq!(contract.constructor_data().unwrap(), vec![0x45, 0xfd, 0x06, 0x74]);
+		assert_eq!(contract.balance, None);
+		assert!(contract.capabilities.is_empty());
+		assert!(contract.asset.is_none());
+	}
+
+	#[test]
+	fn test_load_invalid() {
+		// a JSON manifest is not valid TOML
+		assert!(Manifest::load(&write_manifest("format", "predeploy.toml", "{}")).is_err());
+
+		let short_address = toml_manifest().replace(ADDRESS, "0x01");
+		assert!(Manifest::load(&write_manifest("address", "predeploy.toml", &short_address))
+			.unwrap_err()
+			.contains("address must be 32 bytes"));
+
+		let missing_wasm = toml_manifest().replace("token.wasm", "missing.wasm");
+		assert!(Manifest::load(&write_manifest("wasm", "predeploy.toml", &missing_wasm))
+			.unwrap_err()
+			.contains("cannot read"));
+	}
+
+	#[test]
+	fn test_apply() {
+		let manifest =
+			Manifest::load(&write_manifest("apply", "predeploy.toml", &toml_manifest())).unwrap();
+
+		let mut genesis = endowed_genesis(100 * LAGUNAS);
+		// a system contract configured before the manifest, without capabilities
+		genesis.system_contract_deployer.addr.push([0x11; 32]);
+		genesis.system_contract_deployer.code.push(vec![]);
+		genesis.system_contract_deployer.data.push(vec![]);
+
+		manifest.apply(&mut genesis).unwrap();
+
+		let deployer = &genesis.system_contract_deployer;
+		assert_eq!(deployer.gas_limit, 100);
+		assert_eq!(deployer.addr.len(), 2);
+		assert_eq!(deployer.capabilities, vec![vec![], vec![Capability::NativeTransferFrom]]);
+		assert_eq!(deployer.data[1], vec![0x45, 0xfd, 0x06, 0x74, 0x01, 0x02]);
+
+		// the endowment is taken from the treasury
+		let address: AccountId = deployer.addr[1].into();
+		assert_eq!(treasury_balance(&genesis), 90 * LAGUNAS);
+		assert!(genesis.tokens.balances.contains(&(
+			address.clone(),
+			LAGUNA_NATIVE_CURRENCY,
+			10 * LAGUNAS
+		)));
+
+		let code_hash = <Runtime as frame_system::Config>::Hashing::hash(b"\0asm");
+		let registry = &genesis.contract_assets_registry;
+		assert_eq!(registry.approved_code_hashes, vec![code_hash]);
+		assert_eq!(
+			registry.assets,
+			vec![(address.clone(), code_hash, TokenStandard::Erc20, 18, false)]
+		);
+		assert_eq!(genesis.fee_enablement.enabled, vec![(CurrencyId::Erc20(address.into()), true)]);
+	}
+
+	#[test]
+	fn test_apply_without_fee() {
+		let manifest = Manifest::load(&write_manifest(
+			"fee",
+			"predeploy.toml",
+			&toml_manifest().replace("fee_enabled = true", "fee_enabled = false"),
+		))
+		.unwrap();
+
+		let mut genesis = endowed_genesis(100 * LAGUNAS);
+		manifest.apply(&mut genesis).unwrap();
+
+		assert_eq!(genesis.contract_assets_registry.assets.len(), 1);
+		assert!(genesis.fee_enablement.enabled.is_empty());
+	}
+
+	#[test]
+	fn test_apply_unaffordable_endowment() {
+		let manifest =
+			Manifest::load(&write_manifest("afford", "predeploy.toml", &toml_manifest())).unwrap();
+
+		let mut genesis = endowed_genesis(LAGUNAS);
+		assert!(manifest
+			.apply(&mut genesis)
+			.unwrap_err()
+			.contains("the treasury can't afford an endowment of 10 LAGUNAs"));
+		assert_eq!(treasury_balance(&genesis), LAGUNAS);
+
+		let mut genesis = endowed_genesis(LAGUNAS);
+		genesis.tokens.balances.clear();
+		assert!(manifest
+			.apply(&mut genesis)
+			.unwrap_err()
+			.contains("the treasury is not endowed"));
+	}
+}
@@ -1,19 +1,20 @@
 // chain-wise primitives and modules
 
-use primitives::{AccountId, CurrencyId, TokenId};
+use primitives::{AccountId, Balance, BlockNumber, CurrencyId, TokenId};
 
 use laguna_runtime::{
 	constants::{LAGUNAS, LAGUNA_NATIVE_CURRENCY},
 	impl_pallet_treasury::TreasuryPalletId,
-	AuraConfig, EvmCompat, FeeEnablementConfig, GenesisConfig, GrandpaConfig, SudoConfig,
-	SystemConfig, TokensConfig, TreasuryConfig, WASM_BINARY,
+	AuraConfig, EvmCompat, FeeEnablementConfig, GenesisConfig, GrandpaConfig, MultiVestingConfig,
+	SudoConfig, SystemConfig, TokensConfig, TreasuryConfig, WASM_BINARY,
 };
 
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_finality_grandpa::AuthorityId as GrandpaId;
 
-use super::util::{
-	authority_keys_from_seed, get_account_id_from_seed, laguna_chain_spec_properties,
+use super::{
+	predeploy::Manifest,
+	util::{authority_keys_from_seed, get_account_id_from_seed, laguna_chain_spec_properties},
 };
 use sc_service::ChainType;
 use sp_core::{sr25519, H160};
@@ -22,10 +23,50 @@ use sp_runtime::traits::AccountIdConversion;
 // Spec derived from runtiem GenisisConfig
 pub type ChainSpec = sc_service::GenericChainSpec<GenesisConfig>;
 
-pub fn local_testnet_config() -> Result<ChainSpec, String> {
+pub fn local_testnet_config(manifest: Option<Manifest>) -> Result<ChainSpec, String> {
 	let wasm_binary =
 		WASM_BINARY.ok_or_else(|| -> String { "dev runtime wasm blob missing".into() })?;
 
+	let build_genesis = move || -> Result<GenesisConfig, String> {
+		let pallet_system_contract_deployer_id =
+			<laguna_runtime::Runtime as pallet_system_contract_deployer::Config>::PalletId::get()
+				.try_into_account()
+				.expect("Invalid PalletId");
+
+		let mut genesis = testnet_genesis(
+			wasm_binary,
+			// Initial PoA authorities
+			vec![authority_keys_from_seed("Alice"), authority_keys_from_seed("Bob")],
+			// Sudo account
+			get_account_id_from_seed::<sr25519::Public>("Alice"),
+			vec![
+				get_account_id_from_seed::<sr25519::Public>("Alice"),
+				get_account_id_from_seed::<sr25519::Public>("Bob"),
+				get_account_id_from_seed::<sr25519::Public>("Charlie"),
+				get_account_id_from_seed::<sr25519::Public>("Dave"),
+				get_account_id_from_seed::<sr25519::Public>("Eve"),
+				get_account_id_from_seed::<sr25519::Public>("Ferdie"),
+				get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
+				get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
+				get_account_id_from_seed::<sr25519::Public>("Charlie//stash"),
+				get_account_id_from_seed::<sr25519::Public>("Dave//stash"),
+				get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
+				get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
+				pallet_system_contract_deployer_id,
+			], // prefund accounts
+			vec![],
+			vec![],
+		);
+
+		if let Some(manifest) = &manifest {
+			manifest.apply(&mut genesis)?;
+		}
+
+		Ok(genesis)
+	};
+	// errors of the manifest are reported here rather than when the genesis is built
+	build_genesis()?;
+
 	// create genesis state from preconfigured accounts
 	Ok(ChainSpec::from_genesis(
 		// Name
@@ -33,35 +74,7 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 		// ID
 		"local_testnet",
 		ChainType::Local,
-		move || {
-			let pallet_system_contract_deployer_id =
-				<laguna_runtime::Runtime as pallet_system_contract_deployer::Config>::PalletId::get()
-					.try_into_account().expect("Invalid PalletId");
-
-			testnet_genesis(
-				wasm_binary,
-				// Initial PoA authorities
-				vec![authority_keys_from_seed("Alice"), authority_keys_from_seed("Bob")],
-				// Sudo account
-				get_account_id_from_seed::<sr25519::Public>("Alice"),
-				vec![
-					get_account_id_from_seed::<sr25519::Public>("Alice"),
-					get_account_id_from_seed::<sr25519::Public>("Bob"),
-					get_account_id_from_seed::<sr25519::Public>("Charlie"),
-					get_account_id_from_seed::<sr25519::Public>("Dave"),
-					get_account_id_from_seed::<sr25519::Public>("Eve"),
-					get_account_id_from_seed::<sr25519::Public>("Ferdie"),
-					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Charlie//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Dave//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
-					pallet_system_contract_deployer_id,
-				], // prefund accounts
-				vec![],
-			)
-		},
+		move || build_genesis().expect("checked when the chain spec is created"),
 		// Bootnodes
 		vec![],
 		// Telemetry
@@ -76,10 +89,58 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 	))
 }
 
-pub fn devnet_config() -> Result<ChainSpec, String> {
+pub fn devnet_config(manifest: Option<Manifest>) -> Result<ChainSpec, String> {
 	let wasm_binary =
 		WASM_BINARY.ok_or_else(|| -> String { "dev runtime wasm blob missing".into() })?;
 
+	let build_genesis = move || -> Result<GenesisConfig, String> {
+		let pallet_system_contract_deployer_id =
+			<laguna_runtime::Runtime as pallet_system_contract_deployer::Config>::PalletId::get()
+				.try_into_account()
+				.expect("Invalid PalletId");
+
+		let mut genesis = testnet_genesis(
+			wasm_binary,
+			// Initial PoA authorities
+			vec![authority_keys_from_seed("Alice"), authority_keys_from_seed("Bob")],
+			// Sudo account
+			get_account_id_from_seed::<sr25519::Public>("Alice"),
+			vec![
+				get_account_id_from_seed::<sr25519::Public>("Alice"),
+				get_account_id_from_seed::<sr25519::Public>("Bob"),
+				get_account_id_from_seed::<sr25519::Public>("Charlie"),
+				get_account_id_from_seed::<sr25519::Public>("Dave"),
+				get_account_id_from_seed::<sr25519::Public>("Eve"),
+				get_account_id_from_seed::<sr25519::Public>("Ferdie"),
+				get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
+				get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
+				get_account_id_from_seed::<sr25519::Public>("Charlie//stash"),
+				get_account_id_from_seed::<sr25519::Public>("Dave//stash"),
+				get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
+				get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
+				pallet_system_contract_deployer_id,
+			], // prefund accounts
+			vec![],
+			// vest half of Ferdie's endowment over the first 1000 blocks
+			vec![(
+				get_account_id_from_seed::<sr25519::Public>("Ferdie"),
+				LAGUNA_NATIVE_CURRENCY,
+				0,
+				0,
+				1000,
+				LAGUNAS * 500,
+			)],
+		);
+
+		if let Some(manifest) = &manifest {
+			manifest.apply(&mut genesis)?;
+		}
+
+		Ok(genesis)
+	};
+	// errors of the manifest are reported here rather than when the genesis is built
+	build_genesis()?;
+
 	// create genesis state from preconfigured accounts
 	Ok(ChainSpec::from_genesis(
 		// Name
@@ -87,35 +148,7 @@ pub fn devnet_config() -> Result<ChainSpec, String> {
 		// ID
 		"dev",
 		ChainType::Development,
-		move || {
-			let pallet_system_contract_deployer_id =
-			<laguna_runtime::Runtime as pallet_system_contract_deployer::Config>::PalletId::get()
-				.try_into_account().expect("Invalid PalletId");
-
-			testnet_genesis(
-				wasm_binary,
-				// Initial PoA authorities
-				vec![authority_keys_from_seed("Alice"), authority_keys_from_seed("Bob")],
-				// Sudo account
-				get_account_id_from_seed::<sr25519::Public>("Alice"),
-				vec![
-					get_account_id_from_seed::<sr25519::Public>("Alice"),
-					get_account_id_from_seed::<sr25519::Public>("Bob"),
-					get_account_id_from_seed::<sr25519::Public>("Charlie"),
-					get_account_id_from_seed::<sr25519::Public>("Dave"),
-					get_account_id_from_seed::<sr25519::Public>("Eve"),
-					get_account_id_from_seed::<sr25519::Public>("Ferdie"),
-					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Charlie//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Dave//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
-					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
-					pallet_system_contract_deployer_id,
-				], // prefund accounts
-				vec![],
-			)
-		},
+		move || build_genesis().expect("checked when the chain spec is created"),
 		// Bootnodes
 		vec![],
 		// Telemetry
@@ -137,6 +170,7 @@ fn testnet_genesis(
 	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
 	endowed_eth_addresses: Vec<H160>,
+	vesting: Vec<(AccountId, CurrencyId, BlockNumber, BlockNumber, BlockNumber, Balance)>,
 ) -> GenesisConfig {
 	// generated by construct_runtime! macro
 	GenesisConfig {
@@ -191,5 +225,8 @@ fn testnet_genesis(
 		},
 		treasury: TreasuryConfig {},
 		system_contract_deployer: Default::default(),
+		contract_assets_registry: Default::default(),
+		native_asset_registry: Default::default(),
+		multi_vesting: MultiVestingConfig { vesting },
 	}
 }
@@ -1,6 +1,7 @@
 //! All subcommands exposed to sc-cli, constructed by StructOpt
 
 use sc_cli::RunCmd;
+use std::path::PathBuf;
 
 #[derive(Debug, clap::Parser)]
 pub struct LagunaCli {
@@ -9,6 +10,10 @@ pub struct LagunaCli {
 
 	#[clap(flatten)]
 	pub run: RunCmd,
+
+	/// Manifest (TOML or JSON) of system contracts predeployed in the `dev` and `local` genesis.
+	#[clap(long, global = true)]
+	pub predeploy: Option<PathBuf>,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -41,9 +41,16 @@ impl SubstrateCli for LagunaCli {
 	}
 
 	fn load_spec(&self, id: &str) -> std::result::Result<Box<dyn ChainSpec>, String> {
+		let manifest = match &self.predeploy {
+			Some(path) => Some(chain_spec::predeploy::Manifest::load(path)?),
+			None => None,
+		};
+
 		Ok(match id {
-			"dev" => Box::new(chain_spec::testnet::devnet_config()?),
-			"local" => Box::new(chain_spec::testnet::local_testnet_config()?),
+			"dev" => Box::new(chain_spec::testnet::devnet_config(manifest)?),
+			"local" => Box::new(chain_spec::testnet::local_testnet_config(manifest)?),
+			_ if manifest.is_some() =>
+				return Err("predeploy manifest only applies to the dev and local chains".into()),
 			path => Box::new(chain_spec::testnet::ChainSpec::from_json_file(
 				std::path::PathBuf::from(path),
 			)?),
@@ -5,6 +5,10 @@ use laguna_runtime::opaque::Block;
 use primitives::{AccountId, Balance, BlockNumber, Hash, Index};
 use std::sync::Arc;
 
+use pallet_contract_asset_registry_rpc::{
+	ContractAssetRegistryApiServer, ContractAssetRegistryRpc, ContractAssetRegistryRuntimeApi,
+	NonFungibleApiServer, NonFungibleRpc, NonFungibleRuntimeApi,
+};
 use pallet_contracts_rpc::{Contracts, ContractsApiServer, ContractsRuntimeApi};
 use pallet_currencies_rpc::{CurrenciesApiServer, CurrenciesRpc, CurrenciesRuntimeApi};
 use pallet_evm_compat_rpc::{EvmCompatApiRuntimeApi, EvmCompatApiServer, EvmCompatRpc};
@@ -60,6 +64,8 @@ where
 	Client::Api: TransactionPaymentRuntimeApi<Block, Balance>,
 	Client::Api: ContractsRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash>,
 	Client::Api: CurrenciesRuntimeApi<Block, AccountId, Balance>,
+	Client::Api: ContractAssetRegistryRuntimeApi<Block, AccountId, Balance, Hash>,
+	Client::Api: NonFungibleRuntimeApi<Block, AccountId>,
 	Client::Api: ConvertTransactionRuntimeApi<Block>,
 	Client::Api: ConvertTransactionRuntimeApi<Block>,
 	Client::Api: EvmCompatApiRuntimeApi<Block, AccountId, Balance>,
@@ -85,6 +91,8 @@ where
 
 	module.merge(Contracts::new(client.clone()).into_rpc())?;
 	module.merge(CurrenciesRpc::new(client.clone()).into_rpc())?;
+	module.merge(ContractAssetRegistryRpc::new(client.clone()).into_rpc())?;
+	module.merge(NonFungibleRpc::new(client.clone()).into_rpc())?;
 
 	module.merge(EvmCompatRpc::new(client.clone()).into_rpc())?;
 
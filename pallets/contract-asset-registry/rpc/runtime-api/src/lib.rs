@@ -0,0 +1,35 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use primitives::{CollectionTokens, ContractAssetInfo};
+use sp_core::U256;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+
+	pub trait ContractAssetRegistryApi<AccountId, Balance, Hash>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		Hash: Codec,
+	{
+		/// every registered asset, including suspended ones
+		fn assets() -> Vec<ContractAssetInfo<AccountId, Balance, Hash>>;
+
+		fn asset(address: AccountId) -> Option<ContractAssetInfo<AccountId, Balance, Hash>>;
+	}
+
+	pub trait NonFungibleApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		fn collections() -> Vec<AccountId>;
+
+		/// tokens held by the account in every registered collection
+		fn account_tokens(account: AccountId) -> Vec<CollectionTokens<AccountId>>;
+
+		fn owner_of(collection: AccountId, token_id: U256) -> Option<AccountId>;
+
+		fn token_uri(collection: AccountId, token_id: U256) -> Option<Vec<u8>>;
+	}
+}
@@ -0,0 +1,172 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::CallError};
+
+pub use pallet_contract_asset_registry_rpc_runtime_api::{
+	ContractAssetRegistryApi as ContractAssetRegistryRuntimeApi,
+	NonFungibleApi as NonFungibleRuntimeApi,
+};
+
+use sp_api::{BlockId, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::U256;
+use sp_runtime::traits::Block as BlockT;
+use sp_std::vec::Vec;
+
+use primitives::{CollectionTokens, ContractAssetInfo};
+
+#[rpc(client, server)]
+pub trait ContractAssetRegistryApi<BlockHash, AccountId, Balance, Hash> {
+	#[method(name = "contractAssets_list")]
+	fn assets(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ContractAssetInfo<AccountId, Balance, Hash>>>;
+
+	#[method(name = "contractAssets_asset")]
+	fn asset(
+		&self,
+		address: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ContractAssetInfo<AccountId, Balance, Hash>>>;
+}
+
+pub struct ContractAssetRegistryRpc<Client, Block> {
+	client: Arc<Client>,
+	_marker: PhantomData<Block>,
+}
+
+impl<Client, Block> ContractAssetRegistryRpc<Client, Block> {
+	pub fn new(client: Arc<Client>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<Client, Block, AccountId, Balance, Hash>
+	ContractAssetRegistryApiServer<<Block as BlockT>::Hash, AccountId, Balance, Hash>
+	for ContractAssetRegistryRpc<Client, Block>
+where
+	Block: BlockT,
+	Client: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	AccountId: Codec,
+	Balance: Codec,
+	Hash: Codec,
+	Client::Api: ContractAssetRegistryRuntimeApi<Block, AccountId, Balance, Hash>,
+{
+	fn assets(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ContractAssetInfo<AccountId, Balance, Hash>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.assets(&at).map_err(|e| CallError::from_std_error(e).into())
+	}
+
+	fn asset(
+		&self,
+		address: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<ContractAssetInfo<AccountId, Balance, Hash>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.asset(&at, address).map_err(|e| CallError::from_std_error(e).into())
+	}
+}
+
+#[rpc(client, server)]
+pub trait NonFungibleApi<BlockHash, AccountId> {
+	#[method(name = "nft_collections")]
+	fn collections(&self, at: Option<BlockHash>) -> RpcResult<Vec<AccountId>>;
+
+	#[method(name = "nft_accountTokens")]
+	fn account_tokens(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CollectionTokens<AccountId>>>;
+
+	#[method(name = "nft_ownerOf")]
+	fn owner_of(
+		&self,
+		collection: AccountId,
+		token_id: U256,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AccountId>>;
+
+	#[method(name = "nft_tokenUri")]
+	fn token_uri(
+		&self,
+		collection: AccountId,
+		token_id: U256,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Vec<u8>>>;
+}
+
+pub struct NonFungibleRpc<Client, Block> {
+	client: Arc<Client>,
+	_marker: PhantomData<Block>,
+}
+
+impl<Client, Block> NonFungibleRpc<Client, Block> {
+	pub fn new(client: Arc<Client>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<Client, Block, AccountId> NonFungibleApiServer<<Block as BlockT>::Hash, AccountId>
+	for NonFungibleRpc<Client, Block>
+where
+	Block: BlockT,
+	Client: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	AccountId: Codec,
+	Client::Api: NonFungibleRuntimeApi<Block, AccountId>,
+{
+	fn collections(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<AccountId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.collections(&at).map_err(|e| CallError::from_std_error(e).into())
+	}
+
+	fn account_tokens(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<CollectionTokens<AccountId>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.account_tokens(&at, account)
+			.map_err(|e| CallError::from_std_error(e).into())
+	}
+
+	fn owner_of(
+		&self,
+		collection: AccountId,
+		token_id: U256,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<AccountId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.owner_of(&at, collection, token_id)
+			.map_err(|e| CallError::from_std_error(e).into())
+	}
+
+	fn token_uri(
+		&self,
+		collection: AccountId,
+		token_id: U256,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Vec<u8>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.token_uri(&at, collection, token_id)
+			.map_err(|e| CallError::from_std_error(e).into())
+	}
+}
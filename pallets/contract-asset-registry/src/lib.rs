@@ -1,29 +1,69 @@
 //! ## pallet-contract-asset-registry
 //!
 //! This pallet allows contract based asset to be represented as native tokens
+//!
+//! Assets can be declared mintable at registration, in which case this pallet account is expected
+//! to hold the minter role of the contract, allowing supply to be changed through `TokenAccess`.
+//!
+//! Every asset is registered with the `TokenStandard` it implements, the matching codec in
+//! `standard` builds the call data and decodes the results, covering solang ERC20 and ink PSP22
+//! tokens.
+//!
+//! Every access through `TokenAccess` is bounded by `MaxGas` and reports the gas consumed by the
+//! contract, whether it succeeded or not.
+//!
+//! Reads are cached for the rest of the block and reused as long as no event has been deposited
+//! since, which covers any `ContractEmitted` of the asset, while transfers and approvals routed
+//! through this pallet drop the cached reads of the asset.
+//!
+//! Registration dry-runs the read-only methods of the token and rejects contracts which revert or
+//! return undecodable data, the code of the contract must be approved by governance beforehand.
+//! The detected standard, decimals and code hash are recorded.
+//!
+//! Anyone can register an asset by reserving `RegistrationDeposit`, such assets remain unverified
+//! and may be rejected by `AllowedOrigin` during `ChallengePeriod`, slashing the deposit. Once the
+//! period is over anyone can promote the asset, `AllowedOrigin` can promote it at any time. The
+//! deposit is returned when the asset is unregistered, which the depositor can only do once the
+//! challenge period is over or the asset is verified. Unverified assets are fully usable through
+//! `TokenAccess` but should not be accepted for fees until promoted.
+//!
+//! Non-fungible collections following ERC721 or PSP34 are registered by `AllowedOrigin` and
+//! accessed through `NonFungibleAccess`, see `nft`.
+//!
+//! Contracts have no notion of existential deposit, a minimum balance can be configured for each
+//! asset instead, which is reported as its minimum balance by `TokenAccess`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::HasCompact;
 use frame_support::{
+	dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
 	pallet_prelude::*,
 	sp_runtime::{
 		app_crypto::UncheckedFrom,
-		traits::{AccountIdConversion, StaticLookup},
+		traits::{AccountIdConversion, Hash, Zero},
 	},
 	sp_std::{fmt::Debug, prelude::*},
-	traits::Currency,
+	traits::{Currency, OnUnbalanced, ReservableCurrency},
 	PalletId,
 };
-use frame_system::{pallet_prelude::*, RawOrigin};
+use frame_system::pallet_prelude::*;
 use hex_literal::hex;
+pub use nft::{Erc721Codec, NftCodec, NftSelector, Psp34Codec};
 pub use pallet::*;
-use sp_core::{hexdisplay::AsBytesRef, U256};
-use traits::currencies::TokenAccess;
+use primitives::{AssetMetadata, CollectionTokens, NftStandard, TokenStandard};
+use sp_core::U256;
+pub use standard::{Erc20Codec, Psp22Codec, Selector, TokenCodec};
+use traits::currencies::{TokenAccess, TokenAccessError, TokenAccessResult};
 use weights::WeightInfo;
 
+pub mod nft;
+pub mod standard;
 pub mod weights;
 
+/// calls made to a token contract to probe a standard
+const PROBE_CALLS: u64 = 5;
+
 #[cfg(test)]
 mod tests;
 
@@ -32,17 +72,53 @@ mod mock;
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
+type CodeHash<T> = <T as frame_system::Config>::Hash;
+
 type BalanceOf<T> =
 	<<T as pallet_contracts::Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
 
+type NegativeImbalanceOf<T> =
+	<<T as pallet_contracts::Config>::Currency as Currency<AccountIdOf<T>>>::NegativeImbalance;
+
+/// a read of a token contract cached for the current block
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CachedRead<Balance> {
+	pub value: Balance,
+	/// number of events deposited in the block when the value was read
+	pub event_count: u32,
+}
+
+/// deposit bonded by the account which registered an asset without governance approval
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AssetDeposit<AccountId, Balance, BlockNumber> {
+	pub depositor: AccountId,
+	pub amount: Balance,
+	/// the asset can be rejected until this block
+	pub challenge_end: BlockNumber,
+}
+
 #[frame_support::pallet]
 mod pallet {
 	use super::*;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_contracts::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// origin registering verified assets without deposit, rejecting or promoting assets
 		type AllowedOrigin: EnsureOrigin<Self::Origin>;
 
+		/// amount reserved from the account registering an asset
+		#[pallet::constant]
+		type RegistrationDeposit: Get<BalanceOf<Self>>;
+
+		/// number of blocks during which a permissionless registration can be rejected
+		#[pallet::constant]
+		type ChallengePeriod: Get<Self::BlockNumber>;
+
+		/// handler of the deposits slashed from rejected assets
+		type OnSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
 		// generate unique account_id and sub_account_id for this pallet
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -50,6 +126,11 @@ mod pallet {
 		#[pallet::constant]
 		type MaxGas: Get<u64>;
 
+		/// number of tokens listed for an account in a single collection, the remaining tokens
+		/// are left out
+		#[pallet::constant]
+		type MaxListedTokens: Get<u32>;
+
 		#[pallet::constant]
 		type ContractDebugFlag: Get<bool>;
 
@@ -59,27 +140,264 @@ mod pallet {
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// reads are only cached within a block, the event count they are checked against is
+			// reset along with the events
+			let removed = ReadCache::<T>::clear(u32::MAX, None);
+
+			<T as frame_system::Config>::DbWeight::get()
+				.reads_writes(removed.loops.into(), removed.unique.into())
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// asset registered, with the bonded deposit when registered without governance
+		AssetRegistered {
+			asset_contract_address: AccountIdOf<T>,
+			depositor: Option<AccountIdOf<T>>,
+			deposit: BalanceOf<T>,
+		},
+		AssetRejected {
+			asset_contract_address: AccountIdOf<T>,
+			slashed: BalanceOf<T>,
+		},
+		AssetPromoted {
+			asset_contract_address: AccountIdOf<T>,
+		},
+		AssetUnregistered {
+			asset_contract_address: AccountIdOf<T>,
+			refunded: BalanceOf<T>,
+		},
+		CollectionRegistered {
+			collection_address: AccountIdOf<T>,
+			standard: NftStandard,
+		},
+		CollectionUnregistered {
+			collection_address: AccountIdOf<T>,
+		},
+	}
+
 	#[pallet::error]
 	pub enum Error<T> {
 		InvalidAsset,
+		NotMintable,
+		/// the amount can not be represented by the token standard
+		AmountOverflow,
+		NotContract,
+		CodeHashNotApproved,
+		/// the contract doesn't behave like a token of the expected standard
+		UnsupportedToken,
+		AlreadyRegistered,
+		/// only `AllowedOrigin` can register mintable assets
+		MintableNotAllowed,
+		/// the asset has been promoted or was registered by governance
+		AlreadyVerified,
+		ChallengePeriodOver,
+		ChallengePeriodNotOver,
+		/// the contract doesn't behave like a collection of the expected standard
+		UnsupportedCollection,
+		InvalidCollection,
+		/// the token id can not be represented by the standard of the collection
+		InvalidTokenId,
 	}
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_registered)]
 	pub type RegisteredAsset<T: Config> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, bool>;
 
+	/// assets which grant the minter role to this pallet account
+	#[pallet::storage]
+	#[pallet::getter(fn is_mintable)]
+	pub type MintableAsset<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_standard)]
+	pub type AssetStandard<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, TokenStandard, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_decimals)]
+	pub type AssetDecimals<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u8, ValueQuery>;
+
+	/// code hash of an asset at the time of registration
+	#[pallet::storage]
+	#[pallet::getter(fn asset_code_hash)]
+	pub type AssetCodeHash<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, CodeHash<T>>;
+
+	#[pallet::storage]
+	pub type MinimumBalances<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, BalanceOf<T>, ValueQuery>;
+
+	/// token code allowed to be registered
+	#[pallet::storage]
+	#[pallet::getter(fn is_approved_code_hash)]
+	pub type ApprovedCodeHashes<T: Config> = StorageMap<_, Identity, CodeHash<T>, bool, ValueQuery>;
+
+	/// assets registered by governance or promoted after their challenge period
+	#[pallet::storage]
+	#[pallet::getter(fn is_verified)]
+	pub type VerifiedAsset<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, bool, ValueQuery>;
+
+	/// token reads of the current block, keyed by the hash of the call data
+	#[pallet::storage]
+	pub type ReadCache<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Identity,
+		T::Hash,
+		CachedRead<BalanceOf<T>>,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_deposit)]
+	pub type AssetDeposits<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		AssetDeposit<AccountIdOf<T>, BalanceOf<T>, T::BlockNumber>,
+	>;
+
+	/// non-fungible collections accessible through `NonFungibleAccess`
+	#[pallet::storage]
+	#[pallet::getter(fn is_registered_collection)]
+	pub type RegisteredCollection<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_standard)]
+	pub type CollectionStandard<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, NftStandard, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// verified assets as (address, code hash, standard, decimals, mintable), the contracts
+		/// may be instantiated later in the genesis so they are not probed
+		pub assets: Vec<(AccountIdOf<T>, CodeHash<T>, TokenStandard, u8, bool)>,
+		pub approved_code_hashes: Vec<CodeHash<T>>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { assets: vec![], approved_code_hashes: vec![] }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for code_hash in &self.approved_code_hashes {
+				ApprovedCodeHashes::<T>::insert(code_hash, true);
+			}
+
+			for (address, code_hash, standard, decimals, mintable) in &self.assets {
+				RegisteredAsset::<T>::insert(address, true);
+				MintableAsset::<T>::insert(address, mintable);
+				AssetStandard::<T>::insert(address, standard);
+				AssetDecimals::<T>::insert(address, decimals);
+				AssetCodeHash::<T>::insert(address, code_hash);
+				VerifiedAsset::<T>::insert(address, true);
+			}
+		}
+	}
+
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		#[pallet::weight(<T as Config>::WeightInfo::register_asset())]
+	impl<T: Config> Pallet<T>
+	where
+		T::AccountId: UncheckedFrom<<T as frame_system::Config>::Hash> + AsRef<[u8]>,
+		<BalanceOf<T> as HasCompact>::Type: Clone + Eq + PartialEq + TypeInfo + Encode + Debug,
+	{
+		/// register a token contract, its standard is detected when not specified
+		///
+		/// signed origins reserve `RegistrationDeposit` and the asset stays unverified until
+		/// promoted
+		#[pallet::weight(Pallet::<T>::register_weight(standard))]
 		pub fn register_asset(
 			origin: OriginFor<T>,
 			asset_contract_address: AccountIdOf<T>,
 			enabled: bool,
+			mintable: bool,
+			standard: Option<TokenStandard>,
+		) -> DispatchResultWithPostInfo {
+			// only the weight consumed while probing the contract is charged
+			let mut consumed: Weight = 0;
+			let result = Self::do_register_asset(
+				origin,
+				asset_contract_address,
+				enabled,
+				mintable,
+				standard,
+				&mut consumed,
+			);
+
+			Self::with_actual_weight(T::WeightInfo::register_asset(), consumed, result)
+		}
+
+		/// reject an unverified asset during its challenge period, the deposit is slashed
+		#[pallet::weight(<T as Config>::WeightInfo::reject_asset())]
+		pub fn reject_asset(
+			origin: OriginFor<T>,
+			asset_contract_address: AccountIdOf<T>,
 		) -> DispatchResult {
 			T::AllowedOrigin::ensure_origin(origin)?;
 
-			RegisteredAsset::<T>::insert(asset_contract_address, enabled);
+			ensure!(!Self::is_verified(&asset_contract_address), Error::<T>::AlreadyVerified);
+			let deposit =
+				Self::asset_deposit(&asset_contract_address).ok_or(Error::<T>::InvalidAsset)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= deposit.challenge_end,
+				Error::<T>::ChallengePeriodOver
+			);
+
+			let (imbalance, _) = <T as pallet_contracts::Config>::Currency::slash_reserved(
+				&deposit.depositor,
+				deposit.amount,
+			);
+			T::OnSlash::on_unbalanced(imbalance);
+
+			Self::remove_asset(&asset_contract_address);
+
+			Self::deposit_event(Event::<T>::AssetRejected {
+				asset_contract_address,
+				slashed: deposit.amount,
+			});
+			Ok(())
+		}
 
+		/// mark an asset as verified, anyone can promote it once the challenge period is over
+		#[pallet::weight(<T as Config>::WeightInfo::promote_asset())]
+		pub fn promote_asset(
+			origin: OriginFor<T>,
+			asset_contract_address: AccountIdOf<T>,
+		) -> DispatchResult {
+			let deposit =
+				Self::asset_deposit(&asset_contract_address).ok_or(Error::<T>::InvalidAsset)?;
+			ensure!(!Self::is_verified(&asset_contract_address), Error::<T>::AlreadyVerified);
+
+			let governance = match T::AllowedOrigin::try_origin(origin) {
+				Ok(_) => true,
+				Err(origin) => {
+					ensure_signed(origin)?;
+					false
+				},
+			};
+			ensure!(
+				governance || frame_system::Pallet::<T>::block_number() > deposit.challenge_end,
+				Error::<T>::ChallengePeriodNotOver
+			);
+
+			VerifiedAsset::<T>::insert(asset_contract_address.clone(), true);
+
+			Self::deposit_event(Event::<T>::AssetPromoted { asset_contract_address });
 			Ok(())
 		}
 
@@ -94,14 +412,127 @@ mod pallet {
 			Ok(())
 		}
 
+		/// remove an asset and return its deposit, the depositor can unregister its own asset once
+		/// it can no longer be rejected
 		#[pallet::weight(<T as Config>::WeightInfo::unregister_asset())]
 		pub fn unregister_asset(
 			origin: OriginFor<T>,
 			asset_contract_address: AccountIdOf<T>,
+		) -> DispatchResult {
+			let deposit = Self::asset_deposit(&asset_contract_address);
+
+			if let Err(origin) = T::AllowedOrigin::try_origin(origin) {
+				let who = ensure_signed(origin)?;
+				let deposit = deposit
+					.as_ref()
+					.filter(|d| d.depositor == who)
+					.ok_or(DispatchError::BadOrigin)?;
+				ensure!(
+					Self::is_verified(&asset_contract_address) ||
+						frame_system::Pallet::<T>::block_number() > deposit.challenge_end,
+					Error::<T>::ChallengePeriodNotOver
+				);
+			}
+
+			let refunded = deposit
+				.map(|d| {
+					<T as pallet_contracts::Config>::Currency::unreserve(&d.depositor, d.amount);
+					d.amount
+				})
+				.unwrap_or_default();
+
+			Self::remove_asset(&asset_contract_address);
+
+			Self::deposit_event(Event::<T>::AssetUnregistered { asset_contract_address, refunded });
+			Ok(())
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::set_minimum_balance())]
+		pub fn set_minimum_balance(
+			origin: OriginFor<T>,
+			asset_contract_address: AccountIdOf<T>,
+			minimum_balance: BalanceOf<T>,
+		) -> DispatchResult {
+			T::AllowedOrigin::ensure_origin(origin)?;
+			ensure!(
+				RegisteredAsset::<T>::contains_key(asset_contract_address.clone()),
+				Error::<T>::InvalidAsset
+			);
+
+			MinimumBalances::<T>::insert(asset_contract_address, minimum_balance);
+			Ok(())
+		}
+
+		/// allow contracts running the code to be registered, registered assets are not affected
+		/// when it is revoked
+		#[pallet::weight(<T as Config>::WeightInfo::approve_code_hash())]
+		pub fn approve_code_hash(origin: OriginFor<T>, code_hash: CodeHash<T>) -> DispatchResult {
+			T::AllowedOrigin::ensure_origin(origin)?;
+
+			ApprovedCodeHashes::<T>::insert(code_hash, true);
+			Ok(())
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::approve_code_hash())]
+		pub fn revoke_code_hash(origin: OriginFor<T>, code_hash: CodeHash<T>) -> DispatchResult {
+			T::AllowedOrigin::ensure_origin(origin)?;
+
+			ApprovedCodeHashes::<T>::remove(code_hash);
+			Ok(())
+		}
+
+		/// register a non-fungible collection, the contract is probed for the standard
+		#[pallet::weight(
+			<T as Config>::WeightInfo::register_collection().saturating_add(T::MaxGas::get())
+		)]
+		pub fn register_collection(
+			origin: OriginFor<T>,
+			collection_address: AccountIdOf<T>,
+			standard: NftStandard,
+		) -> DispatchResultWithPostInfo {
+			T::AllowedOrigin::ensure_origin(origin)?;
+
+			let code_hash = pallet_contracts::Pallet::<T>::code_hash(&collection_address)
+				.ok_or(Error::<T>::NotContract)?;
+			ensure!(Self::is_approved_code_hash(code_hash), Error::<T>::CodeHashNotApproved);
+
+			// only the weight consumed while probing the contract is charged
+			let mut consumed: Weight = 0;
+			let result = if Self::probe_collection(&collection_address, standard, &mut consumed) {
+				RegisteredCollection::<T>::insert(collection_address.clone(), true);
+				CollectionStandard::<T>::insert(collection_address.clone(), standard);
+
+				Self::deposit_event(Event::<T>::CollectionRegistered {
+					collection_address,
+					standard,
+				});
+				Ok(())
+			} else {
+				Err(Error::<T>::UnsupportedCollection.into())
+			};
+
+			Self::with_actual_weight(
+				<T as Config>::WeightInfo::register_collection(),
+				consumed,
+				result,
+			)
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::unregister_collection())]
+		pub fn unregister_collection(
+			origin: OriginFor<T>,
+			collection_address: AccountIdOf<T>,
 		) -> DispatchResult {
 			T::AllowedOrigin::ensure_origin(origin)?;
+			ensure!(
+				Self::is_registered_collection(&collection_address),
+				Error::<T>::InvalidCollection
+			);
 
-			RegisteredAsset::<T>::remove(asset_contract_address);
+			RegisteredCollection::<T>::remove(&collection_address);
+			CollectionStandard::<T>::remove(&collection_address);
+
+			Self::deposit_event(Event::<T>::CollectionUnregistered { collection_address });
 			Ok(())
 		}
 	}
@@ -113,180 +544,405 @@ impl<T: Config> Pallet<T> {
 			.filter_map(|(k, v)| if v { Some(k) } else { None })
 			.collect::<Vec<_>>()
 	}
-}
 
-// TODO: hard-coded erc20 selector, we should extend support to ink tokens as well, or come up with
-// an adapter to bridge to both types of assets(solang sol and ink)
-enum Selector<T: frame_system::Config> {
-	TotalSupply,
-	BalanceOf { owner: AccountIdOf<T> },
-	Transfer { to: AccountIdOf<T>, amount: U256 },
-	Allowance { owner: AccountIdOf<T>, spender: AccountIdOf<T> },
-	Approve { spender: AccountIdOf<T>, amount: U256 },
-	TransferFrom { from: AccountIdOf<T>, to: AccountIdOf<T>, amount: U256 },
-}
+	/// enabled and suspended assets
+	pub fn registered_assets() -> Vec<AccountIdOf<T>> {
+		RegisteredAsset::<T>::iter_keys().collect()
+	}
 
-// TODO: create selector buf at compile-time using proc-macro
-impl<T: Config> Selector<T> {
-	/// generate buffer of method selector from contract abi
-	fn method_selector(&self) -> [u8; 4] {
-		match self {
-			Selector::TotalSupply => hex!("18160ddd"),
-			Selector::BalanceOf { owner: _ } => hex!("70a08231"),
-			Selector::Transfer { to: _, amount: _ } => hex!("a9059cbb"),
-			Selector::Allowance { owner: _, spender: _ } => hex!("dd62ed3e"),
-			Selector::Approve { spender: _, amount: _amout } => hex!("095ea7b3"),
-			Selector::TransferFrom { from: _, to: _, amount: _ } => hex!("23b872dd"),
-		}
+	pub fn registered_collections() -> Vec<AccountIdOf<T>> {
+		RegisteredCollection::<T>::iter_keys().collect()
 	}
 
-	/// generate full method selector with encoded arguments appended
-	pub fn selector_buf(&self) -> Vec<u8> {
-		let mut selector = self.method_selector().to_vec();
+	/// account of this pallet, used as the caller of read-only methods
+	pub fn account_id() -> AccountIdOf<T> {
+		T::PalletId::get().try_into_account().expect("Invalid PalletId")
+	}
 
-		match self {
-			Selector::TotalSupply => {},
-			Selector::BalanceOf { owner } => {
-				selector.append(&mut owner.encode());
-			},
-			Selector::Transfer { to, amount } => {
-				selector.append(&mut to.encode());
-				selector.append(&mut amount.encode());
-			},
-			Selector::Allowance { owner, spender } => {
-				selector.append(&mut owner.encode());
-				selector.append(&mut spender.encode());
-			},
-			Selector::Approve { spender, amount } => {
-				selector.append(&mut spender.encode());
-				selector.append(&mut amount.encode());
-			},
-			Selector::TransferFrom { from, to, amount } => {
-				selector.append(&mut from.encode());
-				selector.append(&mut to.encode());
-				selector.append(&mut amount.encode());
-			},
+	/// upper bound of the weight of `register_asset`, every candidate standard is probed
+	pub fn register_weight(standard: &Option<TokenStandard>) -> Weight {
+		let candidates = if standard.is_some() { 1 } else { 2 };
+
+		T::WeightInfo::register_asset()
+			.saturating_add(T::MaxGas::get().saturating_mul(PROBE_CALLS).saturating_mul(candidates))
+	}
+
+	/// charge the benchmarked weight of a call along with the weight consumed by token
+	/// contracts, failures before reaching any contract are charged the declared weight
+	fn with_actual_weight(
+		base: Weight,
+		consumed: Weight,
+		result: DispatchResult,
+	) -> DispatchResultWithPostInfo {
+		let post_info = PostDispatchInfo::from(Some(base.saturating_add(consumed)));
+
+		match result {
+			Ok(()) => Ok(post_info),
+			Err(error) if consumed.is_zero() => Err(error.into()),
+			Err(error) => Err(DispatchErrorWithPostInfo { post_info, error }),
 		}
-		selector
 	}
 }
 
-impl<T> TokenAccess<T> for Pallet<T>
+impl<T> Pallet<T>
 where
 	T: Config,
 	T::AccountId: UncheckedFrom<<T as frame_system::Config>::Hash> + AsRef<[u8]>,
 	<BalanceOf<T> as HasCompact>::Type: Clone + Eq + PartialEq + TypeInfo + Encode + Debug,
 {
-	type Balance = BalanceOf<T>;
+	/// register an asset, the weight consumed by the token contract is added to `consumed`
+	fn do_register_asset(
+		origin: OriginFor<T>,
+		asset_contract_address: AccountIdOf<T>,
+		enabled: bool,
+		mintable: bool,
+		standard: Option<TokenStandard>,
+		consumed: &mut Weight,
+	) -> DispatchResult {
+		let depositor = match T::AllowedOrigin::try_origin(origin) {
+			Ok(_) => None,
+			Err(origin) => Some(ensure_signed(origin)?),
+		};
+
+		if depositor.is_some() {
+			ensure!(
+				!RegisteredAsset::<T>::contains_key(&asset_contract_address),
+				Error::<T>::AlreadyRegistered
+			);
+			ensure!(!mintable, Error::<T>::MintableNotAllowed);
+		}
 
-	fn total_supply(asset_address: AccountIdOf<T>) -> Option<Self::Balance> {
-		Self::get_registered(asset_address.clone()).and_then(
-			|rv| {
-				if rv {
-					Some(())
-				} else {
-					None
-				}
+		// governance may update its own assets, the deposit of a signed registration must be
+		// settled by rejecting or unregistering the asset first
+		ensure!(
+			!AssetDeposits::<T>::contains_key(&asset_contract_address),
+			Error::<T>::AlreadyRegistered
+		);
+
+		let code_hash = pallet_contracts::Pallet::<T>::code_hash(&asset_contract_address)
+			.ok_or(Error::<T>::NotContract)?;
+		ensure!(Self::is_approved_code_hash(code_hash), Error::<T>::CodeHashNotApproved);
+
+		let candidates = match standard {
+			Some(standard) => Vec::from([standard]),
+			None => Vec::from([TokenStandard::Erc20, TokenStandard::Psp22]),
+		};
+
+		let (standard, decimals) = candidates
+			.into_iter()
+			.find_map(|standard| {
+				Self::probe(&asset_contract_address, standard, consumed)
+					.map(|decimals| (standard, decimals))
+			})
+			.ok_or(Error::<T>::UnsupportedToken)?;
+
+		RegisteredAsset::<T>::insert(asset_contract_address.clone(), enabled);
+		MintableAsset::<T>::insert(asset_contract_address.clone(), mintable);
+		AssetStandard::<T>::insert(asset_contract_address.clone(), standard);
+		AssetDecimals::<T>::insert(asset_contract_address.clone(), decimals);
+		AssetCodeHash::<T>::insert(asset_contract_address.clone(), code_hash);
+
+		let deposit = match depositor.clone() {
+			Some(who) => {
+				let amount = T::RegistrationDeposit::get();
+				<T as pallet_contracts::Config>::Currency::reserve(&who, amount)?;
+
+				AssetDeposits::<T>::insert(
+					asset_contract_address.clone(),
+					AssetDeposit {
+						depositor: who,
+						amount,
+						challenge_end: frame_system::Pallet::<T>::block_number() +
+							T::ChallengePeriod::get(),
+					},
+				);
+				amount
 			},
-		)?;
+			None => {
+				VerifiedAsset::<T>::insert(asset_contract_address.clone(), true);
+				Default::default()
+			},
+		};
+
+		Self::deposit_event(Event::<T>::AssetRegistered {
+			asset_contract_address,
+			depositor,
+			deposit,
+		});
+		Ok(())
+	}
 
-		pallet_contracts::Pallet::<T>::bare_call(
-			T::PalletId::get().try_into_account().expect("Invalid PalletId"),
+	fn remove_asset(asset_address: &AccountIdOf<T>) {
+		RegisteredAsset::<T>::remove(asset_address);
+		MintableAsset::<T>::remove(asset_address);
+		AssetStandard::<T>::remove(asset_address);
+		AssetDecimals::<T>::remove(asset_address);
+		AssetCodeHash::<T>::remove(asset_address);
+		MinimumBalances::<T>::remove(asset_address);
+		VerifiedAsset::<T>::remove(asset_address);
+		AssetDeposits::<T>::remove(asset_address);
+		Self::invalidate_cache(asset_address);
+	}
+
+	fn invalidate_cache(asset_address: &AccountIdOf<T>) {
+		let _ = ReadCache::<T>::clear_prefix(asset_address, u32::MAX, None);
+	}
+
+	/// a value read from the asset in this block, as long as no event has been deposited since
+	fn cached_read(asset_address: &AccountIdOf<T>, key: &T::Hash) -> Option<BalanceOf<T>> {
+		ReadCache::<T>::get(asset_address, key)
+			.filter(|cached| cached.event_count == frame_system::Pallet::<T>::event_count())
+			.map(|cached| cached.value)
+	}
+
+	fn is_enabled(asset_address: &AccountIdOf<T>) -> bool {
+		Self::get_registered(asset_address).unwrap_or_default()
+	}
+
+	/// call a method of a contract on behalf of `origin`, changes made by a reverted call are
+	/// discarded
+	fn invoke(
+		origin: AccountIdOf<T>,
+		asset_address: AccountIdOf<T>,
+		data: Vec<u8>,
+	) -> TokenAccessResult<Vec<u8>> {
+		let res = pallet_contracts::Pallet::<T>::bare_call(
+			origin,
 			asset_address,
-			Self::Balance::default(),
+			Default::default(),
 			T::MaxGas::get(),
 			None,
-			Selector::<T>::TotalSupply.selector_buf(),
+			data,
 			T::ContractDebugFlag::get(),
-		)
-		.result
-		.ok()
-		.filter(|v| !v.did_revert())
-		.and_then(|res| -> Option<Self::Balance> {
-			Decode::decode(&mut res.data.as_bytes_ref()).ok()
-		})
+		);
+
+		let result = match res.result {
+			Ok(v) if v.did_revert() => Err(TokenAccessError::Reverted),
+			Ok(v) => Ok(v.data.0),
+			Err(err) if err == pallet_contracts::Error::<T>::OutOfGas.into() =>
+				Err(TokenAccessError::OutOfGas),
+			Err(err) if err == pallet_contracts::Error::<T>::ContractReverted.into() =>
+				Err(TokenAccessError::Reverted),
+			Err(err) => Err(TokenAccessError::Other(err)),
+		};
+
+		TokenAccessResult::new(res.gas_consumed, result)
 	}
 
-	fn balance_of(asset_address: AccountIdOf<T>, who: AccountIdOf<T>) -> Option<Self::Balance> {
-		Self::get_registered(asset_address.clone()).and_then(
-			|rv| {
-				if rv {
-					Some(())
-				} else {
-					None
-				}
+	/// read a balance from the token contract
+	fn query(
+		asset_address: AccountIdOf<T>,
+		selector: Selector<T>,
+	) -> TokenAccessResult<BalanceOf<T>> {
+		if !Self::is_enabled(&asset_address) {
+			return TokenAccessResult::new(0, Err(TokenAccessError::Unregistered))
+		}
+
+		let token_standard = AssetStandard::<T>::get(&asset_address);
+		let data = match standard::encode(token_standard, &selector) {
+			Some(data) => data,
+			None => return TokenAccessResult::new(0, Err(Error::<T>::AmountOverflow.into())),
+		};
+
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		let key = <T as frame_system::Config>::Hashing::hash(&data);
+		if let Some(value) = Self::cached_read(&asset_address, &key) {
+			return TokenAccessResult::new(db_weight.reads(2), Ok(value))
+		}
+
+		let TokenAccessResult { weight, result } = Self::invoke(
+			T::PalletId::get().try_into_account().expect("Invalid PalletId"),
+			asset_address.clone(),
+			data,
+		);
+		let result = result.and_then(|data| {
+			standard::decode_balance::<T, _>(token_standard, &data)
+				.ok_or(TokenAccessError::DecodeFailed)
+		});
+
+		match result {
+			Ok(value) => {
+				ReadCache::<T>::insert(
+					asset_address,
+					key,
+					CachedRead { value, event_count: frame_system::Pallet::<T>::event_count() },
+				);
+				// the entry is removed at the end of the block
+				TokenAccessResult::new(
+					weight.saturating_add(db_weight.reads_writes(2, 2)),
+					Ok(value),
+				)
 			},
+			Err(err) => TokenAccessResult::new(weight.saturating_add(db_weight.reads(2)), Err(err)),
+		}
+	}
+
+	/// read a text field from the token contract, text fields are not cached
+	fn query_text(
+		asset_address: AccountIdOf<T>,
+		selector: Selector<T>,
+	) -> TokenAccessResult<Vec<u8>> {
+		if !Self::is_enabled(&asset_address) {
+			return TokenAccessResult::new(0, Err(TokenAccessError::Unregistered))
+		}
+
+		let token_standard = AssetStandard::<T>::get(&asset_address);
+		let data = match standard::encode(token_standard, &selector) {
+			Some(data) => data,
+			None => return TokenAccessResult::new(0, Err(TokenAccessError::DecodeFailed)),
+		};
+
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		let TokenAccessResult { weight, result } =
+			Self::invoke(Self::account_id(), asset_address, data);
+		let result = result.and_then(|data| {
+			standard::decode_text::<T>(token_standard, &data).ok_or(TokenAccessError::DecodeFailed)
+		});
+
+		TokenAccessResult::new(weight.saturating_add(db_weight.reads(2)), result)
+	}
+
+	/// dry-run the read-only methods of a token, returns its decimals if all of them are
+	/// understood
+	fn probe(
+		asset_address: &AccountIdOf<T>,
+		token_standard: TokenStandard,
+		consumed: &mut Weight,
+	) -> Option<u8> {
+		let owner: AccountIdOf<T> =
+			T::PalletId::get().try_into_account().expect("Invalid PalletId");
+		let mut call = |selector: Selector<T>| {
+			let access = Self::invoke(
+				owner.clone(),
+				asset_address.clone(),
+				standard::encode(token_standard, &selector)?,
+			);
+			*consumed = consumed.saturating_add(access.weight);
+			access.result.ok()
+		};
+
+		standard::decode_balance::<T, BalanceOf<T>>(token_standard, &call(Selector::TotalSupply)?)?;
+		standard::decode_balance::<T, BalanceOf<T>>(
+			token_standard,
+			&call(Selector::BalanceOf { owner })?,
 		)?;
+		standard::decode_text::<T>(token_standard, &call(Selector::Name)?)?;
+		standard::decode_text::<T>(token_standard, &call(Selector::Symbol)?)?;
+		standard::decode_decimals::<T>(token_standard, &call(Selector::Decimals)?)
+	}
 
-		pallet_contracts::Pallet::<T>::bare_call(
-			T::PalletId::get().try_into_account().expect("Invalid PalletId"),
-			asset_address,
-			Self::Balance::default(),
-			T::MaxGas::get(),
-			None,
-			Selector::<T>::BalanceOf { owner: who }.selector_buf(),
-			T::ContractDebugFlag::get(),
-		)
-		.result
-		.ok()
-		.filter(|v| !v.did_revert())
-		.and_then(|res| -> Option<Self::Balance> {
-			Decode::decode(&mut res.data.as_bytes_ref()).ok()
+	/// metadata of a registered asset, name and symbol are read from the contract
+	pub fn asset_metadata(
+		asset_address: AccountIdOf<T>,
+	) -> Option<AssetMetadata<BalanceOf<T>, Vec<u8>>> {
+		RegisteredAsset::<T>::get(&asset_address)?;
+
+		let token_standard = AssetStandard::<T>::get(&asset_address);
+		let text = |selector: Selector<T>| {
+			let data = Self::invoke(
+				Self::account_id(),
+				asset_address.clone(),
+				standard::encode(token_standard, &selector)?,
+			)
+			.result
+			.ok()?;
+			standard::decode_text::<T>(token_standard, &data)
+		};
+
+		Some(AssetMetadata {
+			name: text(Selector::Name)?,
+			symbol: text(Selector::Symbol)?,
+			decimals: AssetDecimals::<T>::get(&asset_address),
+			existential_deposit: MinimumBalances::<T>::get(&asset_address),
 		})
 	}
 
+	/// call a method of the token contract on behalf of `origin`
+	fn execute(
+		asset_address: AccountIdOf<T>,
+		origin: AccountIdOf<T>,
+		selector: Selector<T>,
+	) -> TokenAccessResult<()> {
+		if !Self::is_enabled(&asset_address) {
+			return TokenAccessResult::new(0, Err(TokenAccessError::Unregistered))
+		}
+
+		let token_standard = AssetStandard::<T>::get(&asset_address);
+		let data = match standard::encode(token_standard, &selector) {
+			Some(data) => data,
+			None => return TokenAccessResult::new(0, Err(Error::<T>::AmountOverflow.into())),
+		};
+
+		Self::invalidate_cache(&asset_address);
+
+		// the token may refuse the operation without reverting
+		let TokenAccessResult { weight, result } = Self::invoke(origin, asset_address, data);
+		TokenAccessResult::new(
+			weight,
+			result.and_then(|data| standard::decode_ack::<T>(token_standard, &data)),
+		)
+	}
+}
+
+impl<T> TokenAccess<T> for Pallet<T>
+where
+	T: Config,
+	T::AccountId: UncheckedFrom<<T as frame_system::Config>::Hash> + AsRef<[u8]>,
+	<BalanceOf<T> as HasCompact>::Type: Clone + Eq + PartialEq + TypeInfo + Encode + Debug,
+{
+	type Balance = BalanceOf<T>;
+
+	fn minimum_balance(asset_address: AccountIdOf<T>) -> Self::Balance {
+		MinimumBalances::<T>::get(asset_address)
+	}
+
+	fn max_weight() -> Weight {
+		T::MaxGas::get()
+	}
+
+	fn name(asset_address: AccountIdOf<T>) -> TokenAccessResult<Vec<u8>> {
+		Self::query_text(asset_address, Selector::Name)
+	}
+
+	fn symbol(asset_address: AccountIdOf<T>) -> TokenAccessResult<Vec<u8>> {
+		Self::query_text(asset_address, Selector::Symbol)
+	}
+
+	fn decimals(asset_address: AccountIdOf<T>) -> TokenAccessResult<u8> {
+		if !Self::is_enabled(&asset_address) {
+			return TokenAccessResult::new(0, Err(TokenAccessError::Unregistered))
+		}
+
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		TokenAccessResult::new(db_weight.reads(2), Ok(AssetDecimals::<T>::get(&asset_address)))
+	}
+
+	fn total_supply(asset_address: AccountIdOf<T>) -> TokenAccessResult<Self::Balance> {
+		Self::query(asset_address, Selector::TotalSupply)
+	}
+
+	fn balance_of(
+		asset_address: AccountIdOf<T>,
+		who: AccountIdOf<T>,
+	) -> TokenAccessResult<Self::Balance> {
+		Self::query(asset_address, Selector::BalanceOf { owner: who })
+	}
+
 	fn transfer(
 		asset_address: AccountIdOf<T>,
 		who: AccountIdOf<T>,
 		to: AccountIdOf<T>,
 		amount: U256,
-	) -> DispatchResultWithPostInfo {
-		if Self::get_registered(asset_address.clone())
-			.and_then(|rv| if rv { Some(()) } else { None })
-			.is_none()
-		{
-			return Err(Error::<T>::InvalidAsset.into())
-		}
-
-		pallet_contracts::Pallet::<T>::call(
-			RawOrigin::Signed(who).into(),
-			T::Lookup::unlookup(asset_address),
-			Default::default(),
-			T::MaxGas::get(),
-			None,
-			Selector::<T>::Transfer { to, amount }.selector_buf(),
-		)
+	) -> TokenAccessResult<()> {
+		Self::execute(asset_address, who, Selector::Transfer { to, amount })
 	}
 
 	fn allowance(
 		asset_address: AccountIdOf<T>,
 		owner: AccountIdOf<T>,
 		spender: AccountIdOf<T>,
-	) -> Option<Self::Balance> {
-		Self::get_registered(asset_address.clone()).and_then(
-			|rv| {
-				if rv {
-					Some(())
-				} else {
-					None
-				}
-			},
-		)?;
-		pallet_contracts::Pallet::<T>::bare_call(
-			T::PalletId::get().try_into_account().expect("Invalid PalletId"),
-			asset_address,
-			Self::Balance::default(),
-			T::MaxGas::get(),
-			None,
-			Selector::<T>::Allowance { owner, spender }.selector_buf(),
-			T::ContractDebugFlag::get(),
-		)
-		.result
-		.ok()
-		.filter(|v| !v.did_revert())
-		.and_then(|res| -> Option<Self::Balance> {
-			Decode::decode(&mut res.data.as_bytes_ref()).ok()
-		})
+	) -> TokenAccessResult<Self::Balance> {
+		Self::query(asset_address, Selector::Allowance { owner, spender })
 	}
 
 	fn approve(
@@ -294,21 +950,8 @@ where
 		owner: AccountIdOf<T>,
 		spender: AccountIdOf<T>,
 		amount: U256,
-	) -> DispatchResultWithPostInfo {
-		if Self::get_registered(asset_address.clone())
-			.and_then(|rv| if rv { Some(()) } else { None })
-			.is_none()
-		{
-			return Err(Error::<T>::InvalidAsset.into())
-		}
-		pallet_contracts::Pallet::<T>::call(
-			RawOrigin::Signed(owner).into(),
-			T::Lookup::unlookup(asset_address),
-			Default::default(),
-			T::MaxGas::get(),
-			None,
-			Selector::<T>::Approve { spender, amount }.selector_buf(),
-		)
+	) -> TokenAccessResult<()> {
+		Self::execute(asset_address, owner, Selector::Approve { spender, amount })
 	}
 
 	fn transfer_from(
@@ -317,20 +960,45 @@ where
 		from: AccountIdOf<T>,
 		to: AccountIdOf<T>,
 		amount: U256,
-	) -> DispatchResultWithPostInfo {
-		if Self::get_registered(asset_address.clone())
-			.and_then(|rv| if rv { Some(()) } else { None })
-			.is_none()
-		{
-			return Err(Error::<T>::InvalidAsset.into())
+	) -> TokenAccessResult<()> {
+		Self::execute(asset_address, who, Selector::TransferFrom { from, to, amount })
+	}
+
+	fn mint(
+		asset_address: AccountIdOf<T>,
+		to: AccountIdOf<T>,
+		amount: U256,
+	) -> TokenAccessResult<()> {
+		if !Self::is_enabled(&asset_address) {
+			return TokenAccessResult::new(0, Err(TokenAccessError::Unregistered))
 		}
-		pallet_contracts::Pallet::<T>::call(
-			RawOrigin::Signed(who).into(),
-			T::Lookup::unlookup(asset_address),
-			Default::default(),
-			T::MaxGas::get(),
-			None,
-			Selector::<T>::TransferFrom { from, to, amount }.selector_buf(),
+		if !Self::is_mintable(asset_address.clone()) {
+			return TokenAccessResult::new(0, Err(Error::<T>::NotMintable.into()))
+		}
+
+		Self::execute(
+			asset_address,
+			T::PalletId::get().try_into_account().expect("Invalid PalletId"),
+			Selector::Mint { to, amount },
+		)
+	}
+
+	fn burn(
+		asset_address: AccountIdOf<T>,
+		from: AccountIdOf<T>,
+		amount: U256,
+	) -> TokenAccessResult<()> {
+		if !Self::is_enabled(&asset_address) {
+			return TokenAccessResult::new(0, Err(TokenAccessError::Unregistered))
+		}
+		if !Self::is_mintable(asset_address.clone()) {
+			return TokenAccessResult::new(0, Err(Error::<T>::NotMintable.into()))
+		}
+
+		Self::execute(
+			asset_address,
+			T::PalletId::get().try_into_account().expect("Invalid PalletId"),
+			Selector::Burn { from, amount },
 		)
 	}
 }
@@ -177,15 +177,27 @@ impl pallet_contracts::Config for Runtime {
 parameter_types! {
 	pub const PId: PalletId = PalletId(*b"tkn/reg_");
 	pub const MaxGas: u64 = 200_000_000_000;
+	pub const MaxListedTokens: u32 = 3;
 	pub const DebugFlag: bool = true;
+	pub const RegistrationDeposit: Balance = 1000;
+	pub const ChallengePeriod: BlockNumber = 10;
 }
 
 impl Config for Runtime {
+	type Event = Event;
 	type AllowedOrigin = EnsureRoot<AccountId>;
+
+	type RegistrationDeposit = RegistrationDeposit;
+
+	type ChallengePeriod = ChallengePeriod;
+
+	type OnSlash = ();
 	type PalletId = PId;
 
 	type MaxGas = MaxGas;
 
+	type MaxListedTokens = MaxListedTokens;
+
 	type ContractDebugFlag = DebugFlag;
 
 	type WeightInfo = ();
@@ -0,0 +1,463 @@
+//! access to contract-based non-fungible collections
+//!
+//! Collections are registered along with the `NftStandard` they implement, the matching codec
+//! builds the call data and decodes the results, covering solang ERC721 and ink PSP34 collections.
+//! Listing the tokens of an account requires the enumerable extension of the standard, at most
+//! `MaxListedTokens` are listed whatever the balance reported by the collection.
+//!
+//! ERC721 collections are recognized through ERC165, PSP34 collections by their `balance_of`.
+
+use super::*;
+use traits::currencies::NonFungibleAccess;
+
+/// ERC165 interface id of ERC721
+const ERC721_INTERFACE_ID: [u8; 4] = hex!("80ac58cd");
+
+/// methods of a non-fungible collection contract
+pub enum NftSelector<T: frame_system::Config> {
+	OwnerOf { id: U256 },
+	TransferFrom { from: AccountIdOf<T>, to: AccountIdOf<T>, id: U256 },
+	Approve { spender: AccountIdOf<T>, id: U256 },
+	TokenUri { id: U256 },
+	BalanceOf { owner: AccountIdOf<T> },
+	TokenOfOwnerByIndex { owner: AccountIdOf<T>, index: u32 },
+	SupportsInterface { interface_id: [u8; 4] },
+}
+
+pub trait NftCodec<T: frame_system::Config> {
+	/// full method selector with encoded arguments appended, None if the arguments can not be
+	/// represented by the standard
+	fn encode(selector: &NftSelector<T>) -> Option<Vec<u8>>;
+
+	/// decode the owner of a token, None if the token doesn't exist
+	fn decode_owner(data: &[u8]) -> Option<Option<AccountIdOf<T>>>;
+
+	/// decode the number of tokens held by an account
+	fn decode_count(data: &[u8]) -> Option<u32>;
+
+	fn decode_id(data: &[u8]) -> Option<U256>;
+
+	fn decode_uri(data: &[u8]) -> Option<Vec<u8>>;
+
+	/// decode the acknowledgement returned by a method changing the state of the collection
+	fn decode_ack(data: &[u8]) -> Result<(), TokenAccessError>;
+}
+
+/// solang compiled erc721 collections
+pub struct Erc721Codec;
+
+impl<T: frame_system::Config> NftCodec<T> for Erc721Codec {
+	fn encode(selector: &NftSelector<T>) -> Option<Vec<u8>> {
+		let mut buf = match selector {
+			NftSelector::OwnerOf { .. } => hex!("6352211e"),
+			NftSelector::TransferFrom { .. } => hex!("23b872dd"),
+			NftSelector::Approve { .. } => hex!("095ea7b3"),
+			NftSelector::TokenUri { .. } => hex!("c87b56dd"),
+			NftSelector::BalanceOf { .. } => hex!("70a08231"),
+			NftSelector::TokenOfOwnerByIndex { .. } => hex!("2f745c59"),
+			NftSelector::SupportsInterface { .. } => hex!("01ffc9a7"),
+		}
+		.to_vec();
+
+		match selector {
+			NftSelector::OwnerOf { id } | NftSelector::TokenUri { id } => {
+				buf.append(&mut id.encode());
+			},
+			NftSelector::TransferFrom { from, to, id } => {
+				buf.append(&mut from.encode());
+				buf.append(&mut to.encode());
+				buf.append(&mut id.encode());
+			},
+			NftSelector::Approve { spender, id } => {
+				buf.append(&mut spender.encode());
+				buf.append(&mut id.encode());
+			},
+			NftSelector::BalanceOf { owner } => {
+				buf.append(&mut owner.encode());
+			},
+			NftSelector::TokenOfOwnerByIndex { owner, index } => {
+				buf.append(&mut owner.encode());
+				buf.append(&mut U256::from(*index).encode());
+			},
+			NftSelector::SupportsInterface { interface_id } => {
+				buf.extend_from_slice(interface_id);
+			},
+		}
+		Some(buf)
+	}
+
+	// ownerOf reverts for unknown tokens
+	fn decode_owner(data: &[u8]) -> Option<Option<AccountIdOf<T>>> {
+		Decode::decode(&mut &data[..]).ok().map(Some)
+	}
+
+	fn decode_count(data: &[u8]) -> Option<u32> {
+		<U256 as Decode>::decode(&mut &data[..])
+			.ok()
+			.and_then(|count| count.try_into().ok())
+	}
+
+	fn decode_id(data: &[u8]) -> Option<U256> {
+		Decode::decode(&mut &data[..]).ok()
+	}
+
+	fn decode_uri(data: &[u8]) -> Option<Vec<u8>> {
+		Decode::decode(&mut &data[..]).ok()
+	}
+
+	// transferFrom and approve return nothing and revert on failure
+	fn decode_ack(_data: &[u8]) -> Result<(), TokenAccessError> {
+		Ok(())
+	}
+}
+
+/// token identifier of PSP34
+#[derive(Encode, Decode)]
+enum Psp34Id {
+	U8(u8),
+	U16(u16),
+	U32(u32),
+	U64(u64),
+	U128(u128),
+	Bytes(Vec<u8>),
+}
+
+impl Psp34Id {
+	fn into_u256(self) -> Option<U256> {
+		match self {
+			Psp34Id::U8(id) => Some(id.into()),
+			Psp34Id::U16(id) => Some(id.into()),
+			Psp34Id::U32(id) => Some(id.into()),
+			Psp34Id::U64(id) => Some(id.into()),
+			Psp34Id::U128(id) => Some(id.into()),
+			Psp34Id::Bytes(id) if id.len() <= 32 => Some(U256::from_big_endian(&id)),
+			Psp34Id::Bytes(_) => None,
+		}
+	}
+}
+
+/// ink collections following PSP34, including the PSP34Enumerable and PSP34Metadata extensions
+pub struct Psp34Codec;
+
+impl<T: frame_system::Config> NftCodec<T> for Psp34Codec {
+	fn encode(selector: &NftSelector<T>) -> Option<Vec<u8>> {
+		let to_id = |id: &U256| u128::try_from(*id).ok().map(Psp34Id::U128);
+		// transfers carry additional data passed to the receiver
+		let data = Vec::<u8>::new();
+
+		let mut buf = match selector {
+			NftSelector::OwnerOf { .. } => hex!("1168624d"),
+			// PSP34 transfers are made by the owner or an approved operator
+			NftSelector::TransferFrom { .. } => hex!("3128d61b"),
+			NftSelector::Approve { .. } => hex!("1932a8b0"),
+			// PSP34Metadata exposes the uri as an attribute of the token
+			NftSelector::TokenUri { .. } => hex!("f19d48d1"),
+			NftSelector::BalanceOf { .. } => hex!("cde7e55f"),
+			NftSelector::TokenOfOwnerByIndex { .. } => hex!("3bcfb511"),
+			// PSP34 has no introspection
+			NftSelector::SupportsInterface { .. } => return None,
+		}
+		.to_vec();
+
+		match selector {
+			NftSelector::OwnerOf { id } => {
+				buf.append(&mut to_id(id)?.encode());
+			},
+			NftSelector::TransferFrom { to, id, .. } => {
+				buf.append(&mut to.encode());
+				buf.append(&mut to_id(id)?.encode());
+				buf.append(&mut data.encode());
+			},
+			NftSelector::Approve { spender, id } => {
+				buf.append(&mut spender.encode());
+				buf.append(&mut Some(to_id(id)?).encode());
+				buf.append(&mut true.encode());
+			},
+			NftSelector::TokenUri { id } => {
+				buf.append(&mut to_id(id)?.encode());
+				buf.append(&mut b"uri".to_vec().encode());
+			},
+			NftSelector::BalanceOf { owner } => {
+				buf.append(&mut owner.encode());
+			},
+			NftSelector::TokenOfOwnerByIndex { owner, index } => {
+				buf.append(&mut owner.encode());
+				buf.append(&mut u128::from(*index).encode());
+			},
+			NftSelector::SupportsInterface { .. } => {},
+		}
+		Some(buf)
+	}
+
+	fn decode_owner(data: &[u8]) -> Option<Option<AccountIdOf<T>>> {
+		Decode::decode(&mut &data[..]).ok()
+	}
+
+	fn decode_count(data: &[u8]) -> Option<u32> {
+		Decode::decode(&mut &data[..]).ok()
+	}
+
+	// owners_token_by_index returns Result<Id, PSP34Error>
+	fn decode_id(data: &[u8]) -> Option<U256> {
+		match data.split_first() {
+			Some((0, id)) => <Psp34Id as Decode>::decode(&mut &id[..]).ok()?.into_u256(),
+			_ => None,
+		}
+	}
+
+	fn decode_uri(data: &[u8]) -> Option<Vec<u8>> {
+		<Option<Vec<u8>> as Decode>::decode(&mut &data[..])
+			.ok()
+			.map(Option::unwrap_or_default)
+	}
+
+	// transfer and approve return `Result<(), PSP34Error>`
+	fn decode_ack(data: &[u8]) -> Result<(), TokenAccessError> {
+		match data.first() {
+			Some(0) => Ok(()),
+			Some(1) => Err(TokenAccessError::Rejected),
+			_ => Err(TokenAccessError::DecodeFailed),
+		}
+	}
+}
+
+pub(crate) fn encode<T: frame_system::Config>(
+	standard: NftStandard,
+	selector: &NftSelector<T>,
+) -> Option<Vec<u8>> {
+	match standard {
+		NftStandard::Erc721 => <Erc721Codec as NftCodec<T>>::encode(selector),
+		NftStandard::Psp34 => <Psp34Codec as NftCodec<T>>::encode(selector),
+	}
+}
+
+pub(crate) fn decode_owner<T: frame_system::Config>(
+	standard: NftStandard,
+	data: &[u8],
+) -> Option<Option<AccountIdOf<T>>> {
+	match standard {
+		NftStandard::Erc721 => <Erc721Codec as NftCodec<T>>::decode_owner(data),
+		NftStandard::Psp34 => <Psp34Codec as NftCodec<T>>::decode_owner(data),
+	}
+}
+
+pub(crate) fn decode_count<T: frame_system::Config>(
+	standard: NftStandard,
+	data: &[u8],
+) -> Option<u32> {
+	match standard {
+		NftStandard::Erc721 => <Erc721Codec as NftCodec<T>>::decode_count(data),
+		NftStandard::Psp34 => <Psp34Codec as NftCodec<T>>::decode_count(data),
+	}
+}
+
+fn decode_id<T: frame_system::Config>(standard: NftStandard, data: &[u8]) -> Option<U256> {
+	match standard {
+		NftStandard::Erc721 => <Erc721Codec as NftCodec<T>>::decode_id(data),
+		NftStandard::Psp34 => <Psp34Codec as NftCodec<T>>::decode_id(data),
+	}
+}
+
+fn decode_uri<T: frame_system::Config>(standard: NftStandard, data: &[u8]) -> Option<Vec<u8>> {
+	match standard {
+		NftStandard::Erc721 => <Erc721Codec as NftCodec<T>>::decode_uri(data),
+		NftStandard::Psp34 => <Psp34Codec as NftCodec<T>>::decode_uri(data),
+	}
+}
+
+fn decode_ack<T: frame_system::Config>(
+	standard: NftStandard,
+	data: &[u8],
+) -> Result<(), TokenAccessError> {
+	match standard {
+		NftStandard::Erc721 => <Erc721Codec as NftCodec<T>>::decode_ack(data),
+		NftStandard::Psp34 => <Psp34Codec as NftCodec<T>>::decode_ack(data),
+	}
+}
+
+impl<T> Pallet<T>
+where
+	T: Config,
+	T::AccountId: UncheckedFrom<<T as frame_system::Config>::Hash> + AsRef<[u8]>,
+	<BalanceOf<T> as HasCompact>::Type: Clone + Eq + PartialEq + TypeInfo + Encode + Debug,
+{
+	/// whether the contract implements the standard, the weight consumed by the contract is
+	/// added to `consumed`
+	pub(crate) fn probe_collection(
+		collection: &AccountIdOf<T>,
+		standard: NftStandard,
+		consumed: &mut Weight,
+	) -> bool {
+		let owner = Self::account_id();
+		let selector = match standard {
+			NftStandard::Erc721 =>
+				NftSelector::SupportsInterface { interface_id: ERC721_INTERFACE_ID },
+			NftStandard::Psp34 => NftSelector::BalanceOf { owner: owner.clone() },
+		};
+		let data = match encode::<T>(standard, &selector) {
+			Some(data) => data,
+			None => return false,
+		};
+
+		let TokenAccessResult { weight, result } = Self::invoke(owner, collection.clone(), data);
+		*consumed = consumed.saturating_add(weight);
+
+		match (standard, result) {
+			(NftStandard::Erc721, Ok(data)) => bool::decode(&mut &data[..]).unwrap_or_default(),
+			(NftStandard::Psp34, Ok(data)) => decode_count::<T>(standard, &data).is_some(),
+			(_, Err(_)) => false,
+		}
+	}
+
+	/// call a method of the collection on behalf of `origin` and decode its result
+	pub(crate) fn call_collection<R>(
+		collection: AccountIdOf<T>,
+		origin: AccountIdOf<T>,
+		selector: NftSelector<T>,
+		decode: impl FnOnce(NftStandard, &[u8]) -> Option<R>,
+	) -> TokenAccessResult<R> {
+		Self::access_collection(collection, origin, selector, |standard, data| {
+			decode(standard, data).ok_or(TokenAccessError::DecodeFailed)
+		})
+	}
+
+	/// call a method of the collection on behalf of `origin`, `decode` may reject its result
+	fn access_collection<R>(
+		collection: AccountIdOf<T>,
+		origin: AccountIdOf<T>,
+		selector: NftSelector<T>,
+		decode: impl FnOnce(NftStandard, &[u8]) -> Result<R, TokenAccessError>,
+	) -> TokenAccessResult<R> {
+		if !Self::is_registered_collection(&collection) {
+			return TokenAccessResult::new(0, Err(TokenAccessError::Unregistered))
+		}
+
+		let nft_standard = CollectionStandard::<T>::get(&collection);
+		let data = match encode(nft_standard, &selector) {
+			Some(data) => data,
+			None => return TokenAccessResult::new(0, Err(Error::<T>::InvalidTokenId.into())),
+		};
+
+		let TokenAccessResult { weight, result } = Self::invoke(origin, collection, data);
+		TokenAccessResult::new(weight, result.and_then(|data| decode(nft_standard, &data)))
+	}
+
+	/// tokens held by the account in every registered collection, collections which can not be
+	/// enumerated are skipped
+	pub fn account_nfts(who: AccountIdOf<T>) -> Vec<CollectionTokens<AccountIdOf<T>>> {
+		RegisteredCollection::<T>::iter_keys()
+			.filter_map(|collection| {
+				let tokens =
+					<Self as NonFungibleAccess<T>>::tokens_of(collection.clone(), who.clone())
+						.result
+						.ok()?;
+				Some(CollectionTokens { collection, tokens })
+			})
+			.collect()
+	}
+}
+
+impl<T> NonFungibleAccess<T> for Pallet<T>
+where
+	T: Config,
+	T::AccountId: UncheckedFrom<<T as frame_system::Config>::Hash> + AsRef<[u8]>,
+	<BalanceOf<T> as HasCompact>::Type: Clone + Eq + PartialEq + TypeInfo + Encode + Debug,
+{
+	fn owner_of(
+		collection: AccountIdOf<T>,
+		token_id: U256,
+	) -> TokenAccessResult<Option<AccountIdOf<T>>> {
+		Self::call_collection(
+			collection,
+			Self::account_id(),
+			NftSelector::OwnerOf { id: token_id },
+			decode_owner::<T>,
+		)
+	}
+
+	fn transfer_from(
+		collection: AccountIdOf<T>,
+		who: AccountIdOf<T>,
+		from: AccountIdOf<T>,
+		to: AccountIdOf<T>,
+		token_id: U256,
+	) -> TokenAccessResult<()> {
+		// PSP34 transfers move the token of whoever owns it, so the owner is checked first
+		let mut weight = 0;
+		if CollectionStandard::<T>::get(&collection) == NftStandard::Psp34 {
+			let owner = Self::owner_of(collection.clone(), token_id);
+			weight = owner.weight;
+			match owner.result {
+				Ok(Some(owner)) if owner == from => {},
+				Ok(_) => return TokenAccessResult::new(weight, Err(TokenAccessError::Rejected)),
+				Err(err) => return TokenAccessResult::new(weight, Err(err)),
+			}
+		}
+
+		let access = Self::access_collection(
+			collection,
+			who,
+			NftSelector::TransferFrom { from, to, id: token_id },
+			decode_ack::<T>,
+		);
+		TokenAccessResult::new(weight.saturating_add(access.weight), access.result)
+	}
+
+	fn approve(
+		collection: AccountIdOf<T>,
+		owner: AccountIdOf<T>,
+		spender: AccountIdOf<T>,
+		token_id: U256,
+	) -> TokenAccessResult<()> {
+		Self::access_collection(
+			collection,
+			owner,
+			NftSelector::Approve { spender, id: token_id },
+			decode_ack::<T>,
+		)
+	}
+
+	fn token_uri(collection: AccountIdOf<T>, token_id: U256) -> TokenAccessResult<Vec<u8>> {
+		Self::call_collection(
+			collection,
+			Self::account_id(),
+			NftSelector::TokenUri { id: token_id },
+			decode_uri::<T>,
+		)
+	}
+
+	fn tokens_of(
+		collection: AccountIdOf<T>,
+		owner: AccountIdOf<T>,
+	) -> TokenAccessResult<Vec<U256>> {
+		let TokenAccessResult { mut weight, result } = Self::call_collection(
+			collection.clone(),
+			Self::account_id(),
+			NftSelector::BalanceOf { owner: owner.clone() },
+			decode_count::<T>,
+		);
+		let count = match result {
+			Ok(count) => count,
+			Err(err) => return TokenAccessResult::new(weight, Err(err)),
+		};
+
+		// the balance is reported by the collection itself
+		let mut tokens = Vec::new();
+		for index in 0..count.min(T::MaxListedTokens::get()) {
+			let access = Self::call_collection(
+				collection.clone(),
+				Self::account_id(),
+				NftSelector::TokenOfOwnerByIndex { owner: owner.clone(), index },
+				decode_id::<T>,
+			);
+			weight = weight.saturating_add(access.weight);
+
+			match access.result {
+				Ok(id) => tokens.push(id),
+				Err(err) => return TokenAccessResult::new(weight, Err(err)),
+			}
+		}
+
+		TokenAccessResult::new(weight, Ok(tokens))
+	}
+}
@@ -0,0 +1,250 @@
+//! codecs translating token methods into the call data of each supported token standard
+//!
+//! Every standard is served by a `TokenCodec`, which builds the selector buffer of a method and
+//! decodes the returned data, so supporting another standard only takes a new codec.
+
+use super::*;
+
+/// methods of a fungible token contract
+pub enum Selector<T: frame_system::Config> {
+	TotalSupply,
+	BalanceOf { owner: AccountIdOf<T> },
+	Transfer { to: AccountIdOf<T>, amount: U256 },
+	Allowance { owner: AccountIdOf<T>, spender: AccountIdOf<T> },
+	Approve { spender: AccountIdOf<T>, amount: U256 },
+	TransferFrom { from: AccountIdOf<T>, to: AccountIdOf<T>, amount: U256 },
+	Mint { to: AccountIdOf<T>, amount: U256 },
+	Burn { from: AccountIdOf<T>, amount: U256 },
+	Name,
+	Symbol,
+	Decimals,
+}
+
+pub trait TokenCodec<T: frame_system::Config> {
+	/// full method selector with encoded arguments appended, None if the arguments can not be
+	/// represented by the standard
+	fn encode(selector: &Selector<T>) -> Option<Vec<u8>>;
+
+	/// decode a balance returned by the token contract
+	fn decode_balance<Balance: Decode>(data: &[u8]) -> Option<Balance>;
+
+	/// decode the name or symbol returned by the token contract
+	fn decode_text(data: &[u8]) -> Option<Vec<u8>>;
+
+	fn decode_decimals(data: &[u8]) -> Option<u8> {
+		Decode::decode(&mut &data[..]).ok()
+	}
+
+	/// decode the acknowledgement returned by a method changing the state of the token
+	fn decode_ack(data: &[u8]) -> Result<(), TokenAccessError>;
+}
+
+/// solang compiled erc20 tokens
+pub struct Erc20Codec;
+
+// TODO: create selector buf at compile-time using proc-macro
+impl<T: frame_system::Config> TokenCodec<T> for Erc20Codec {
+	fn encode(selector: &Selector<T>) -> Option<Vec<u8>> {
+		let mut buf = match selector {
+			Selector::TotalSupply => hex!("18160ddd"),
+			Selector::BalanceOf { .. } => hex!("70a08231"),
+			Selector::Transfer { .. } => hex!("a9059cbb"),
+			Selector::Allowance { .. } => hex!("dd62ed3e"),
+			Selector::Approve { .. } => hex!("095ea7b3"),
+			Selector::TransferFrom { .. } => hex!("23b872dd"),
+			Selector::Mint { .. } => hex!("40c10f19"),
+			Selector::Burn { .. } => hex!("9dc29fac"),
+			Selector::Name => hex!("06fdde03"),
+			Selector::Symbol => hex!("95d89b41"),
+			Selector::Decimals => hex!("313ce567"),
+		}
+		.to_vec();
+
+		match selector {
+			Selector::TotalSupply | Selector::Name | Selector::Symbol | Selector::Decimals => {},
+			Selector::BalanceOf { owner } => {
+				buf.append(&mut owner.encode());
+			},
+			Selector::Transfer { to, amount } => {
+				buf.append(&mut to.encode());
+				buf.append(&mut amount.encode());
+			},
+			Selector::Allowance { owner, spender } => {
+				buf.append(&mut owner.encode());
+				buf.append(&mut spender.encode());
+			},
+			Selector::Approve { spender, amount } => {
+				buf.append(&mut spender.encode());
+				buf.append(&mut amount.encode());
+			},
+			Selector::TransferFrom { from, to, amount } => {
+				buf.append(&mut from.encode());
+				buf.append(&mut to.encode());
+				buf.append(&mut amount.encode());
+			},
+			Selector::Mint { to, amount } => {
+				buf.append(&mut to.encode());
+				buf.append(&mut amount.encode());
+			},
+			Selector::Burn { from, amount } => {
+				buf.append(&mut from.encode());
+				buf.append(&mut amount.encode());
+			},
+		}
+		Some(buf)
+	}
+
+	// uint256 is returned in little endian, so the balance is decoded from its lower bytes
+	fn decode_balance<Balance: Decode>(data: &[u8]) -> Option<Balance> {
+		Decode::decode(&mut &data[..]).ok()
+	}
+
+	fn decode_text(data: &[u8]) -> Option<Vec<u8>> {
+		Decode::decode(&mut &data[..]).ok()
+	}
+
+	// methods return `bool`, tokens returning nothing are considered successful
+	fn decode_ack(data: &[u8]) -> Result<(), TokenAccessError> {
+		if data.is_empty() {
+			return Ok(())
+		}
+
+		match bool::decode(&mut &data[..]) {
+			Ok(true) => Ok(()),
+			Ok(false) => Err(TokenAccessError::Rejected),
+			Err(_) => Err(TokenAccessError::DecodeFailed),
+		}
+	}
+}
+
+/// ink tokens following PSP22, including the PSP22Mintable and PSP22Burnable extensions
+pub struct Psp22Codec;
+
+impl<T: frame_system::Config> TokenCodec<T> for Psp22Codec {
+	fn encode(selector: &Selector<T>) -> Option<Vec<u8>> {
+		// PSP22 balances are u128
+		let to_balance = |amount: &U256| u128::try_from(*amount).ok();
+		// transfers carry additional data passed to the receiver
+		let data = Vec::<u8>::new();
+
+		let mut buf = match selector {
+			Selector::TotalSupply => hex!("162df8c2"),
+			Selector::BalanceOf { .. } => hex!("6568382f"),
+			Selector::Transfer { .. } => hex!("db20f9f5"),
+			Selector::Allowance { .. } => hex!("4d47d921"),
+			Selector::Approve { .. } => hex!("b20f1bbd"),
+			Selector::TransferFrom { .. } => hex!("54b3c76e"),
+			Selector::Mint { .. } => hex!("fc3c75d4"),
+			Selector::Burn { .. } => hex!("7a9da510"),
+			Selector::Name => hex!("3d261bd4"),
+			Selector::Symbol => hex!("34205be5"),
+			Selector::Decimals => hex!("7271b782"),
+		}
+		.to_vec();
+
+		match selector {
+			Selector::TotalSupply | Selector::Name | Selector::Symbol | Selector::Decimals => {},
+			Selector::BalanceOf { owner } => {
+				buf.append(&mut owner.encode());
+			},
+			Selector::Transfer { to, amount } => {
+				buf.append(&mut to.encode());
+				buf.append(&mut to_balance(amount)?.encode());
+				buf.append(&mut data.encode());
+			},
+			Selector::Allowance { owner, spender } => {
+				buf.append(&mut owner.encode());
+				buf.append(&mut spender.encode());
+			},
+			Selector::Approve { spender, amount } => {
+				buf.append(&mut spender.encode());
+				buf.append(&mut to_balance(amount)?.encode());
+			},
+			Selector::TransferFrom { from, to, amount } => {
+				buf.append(&mut from.encode());
+				buf.append(&mut to.encode());
+				buf.append(&mut to_balance(amount)?.encode());
+				buf.append(&mut data.encode());
+			},
+			Selector::Mint { to, amount } => {
+				buf.append(&mut to.encode());
+				buf.append(&mut to_balance(amount)?.encode());
+			},
+			Selector::Burn { from, amount } => {
+				buf.append(&mut from.encode());
+				buf.append(&mut to_balance(amount)?.encode());
+			},
+		}
+		Some(buf)
+	}
+
+	fn decode_balance<Balance: Decode>(data: &[u8]) -> Option<Balance> {
+		Decode::decode(&mut &data[..]).ok()
+	}
+
+	// PSP22Metadata returns optional name and symbol
+	fn decode_text(data: &[u8]) -> Option<Vec<u8>> {
+		<Option<Vec<u8>> as Decode>::decode(&mut &data[..])
+			.ok()
+			.map(Option::unwrap_or_default)
+	}
+
+	// methods return `Result<(), PSP22Error>`, the error itself is not needed
+	fn decode_ack(data: &[u8]) -> Result<(), TokenAccessError> {
+		match data.first() {
+			Some(0) => Ok(()),
+			Some(1) => Err(TokenAccessError::Rejected),
+			_ => Err(TokenAccessError::DecodeFailed),
+		}
+	}
+}
+
+pub(crate) fn encode<T: frame_system::Config>(
+	standard: TokenStandard,
+	selector: &Selector<T>,
+) -> Option<Vec<u8>> {
+	match standard {
+		TokenStandard::Erc20 => <Erc20Codec as TokenCodec<T>>::encode(selector),
+		TokenStandard::Psp22 => <Psp22Codec as TokenCodec<T>>::encode(selector),
+	}
+}
+
+pub(crate) fn decode_balance<T: frame_system::Config, Balance: Decode>(
+	standard: TokenStandard,
+	data: &[u8],
+) -> Option<Balance> {
+	match standard {
+		TokenStandard::Erc20 => <Erc20Codec as TokenCodec<T>>::decode_balance(data),
+		TokenStandard::Psp22 => <Psp22Codec as TokenCodec<T>>::decode_balance(data),
+	}
+}
+
+pub(crate) fn decode_text<T: frame_system::Config>(
+	standard: TokenStandard,
+	data: &[u8],
+) -> Option<Vec<u8>> {
+	match standard {
+		TokenStandard::Erc20 => <Erc20Codec as TokenCodec<T>>::decode_text(data),
+		TokenStandard::Psp22 => <Psp22Codec as TokenCodec<T>>::decode_text(data),
+	}
+}
+
+pub(crate) fn decode_decimals<T: frame_system::Config>(
+	standard: TokenStandard,
+	data: &[u8],
+) -> Option<u8> {
+	match standard {
+		TokenStandard::Erc20 => <Erc20Codec as TokenCodec<T>>::decode_decimals(data),
+		TokenStandard::Psp22 => <Psp22Codec as TokenCodec<T>>::decode_decimals(data),
+	}
+}
+
+pub(crate) fn decode_ack<T: frame_system::Config>(
+	standard: TokenStandard,
+	data: &[u8],
+) -> Result<(), TokenAccessError> {
+	match standard {
+		TokenStandard::Erc20 => <Erc20Codec as TokenCodec<T>>::decode_ack(data),
+		TokenStandard::Psp22 => <Psp22Codec as TokenCodec<T>>::decode_ack(data),
+	}
+}
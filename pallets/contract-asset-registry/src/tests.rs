@@ -1,28 +1,15 @@
 use super::mock::*;
 use crate::*;
 use codec::Encode;
-use primitives::AccountId;
+use primitives::{AccountId, NftStandard, TokenStandard};
 use sp_core::Bytes;
 use std::str::FromStr;
 
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 
-fn create_token<T>(owner: AccountId, tkn_name: &str, tkn_symbol: &str, init_amount: T) -> AccountId
-where
-	U256: From<T>,
-{
-	let blob = std::fs::read(
-		"../../runtime/integration-tests/contracts-data/solidity/token/dist/DemoToken.wasm",
-	)
-	.expect("unable to read contract");
-
-	let mut sel_constuctor = Bytes::from_str("0x835a15cb")
-		.map(|v| v.to_vec())
-		.expect("unable to parse selector");
-
-	sel_constuctor.append(&mut tkn_name.encode());
-	sel_constuctor.append(&mut tkn_symbol.encode());
-	sel_constuctor.append(&mut U256::from(init_amount).encode());
+/// instantiate a contract and approve its code for registration
+fn deploy(owner: AccountId, path: &str, input: Vec<u8>) -> AccountId {
+	let blob = std::fs::read(path).expect("unable to read contract");
 
 	assert_ok!(Contracts::instantiate_with_code(
 		Origin::signed(owner),
@@ -31,7 +18,7 @@ where
 		None, /* if not specified, it's allowed to charge the max amount of free balance of the
 		       * creator */
 		blob,
-		sel_constuctor,
+		input,
 		vec![]
 	));
 
@@ -50,9 +37,78 @@ where
 				None
 			}
 		})
-		.expect("unable to find deployed contract");
+		.expect("unable to find deployed contract")
+		.clone();
+
+	// the token code is approved by governance before it can be registered
+	let code_hash =
+		pallet_contracts::Pallet::<Runtime>::code_hash(&deployed).expect("contract should exist");
+	assert_ok!(ContractTokenRegistry::approve_code_hash(Origin::root(), code_hash));
+
+	deployed
+}
+
+fn create_token<T>(owner: AccountId, tkn_name: &str, tkn_symbol: &str, init_amount: T) -> AccountId
+where
+	U256: From<T>,
+{
+	let mut sel_constuctor = Bytes::from_str("0x835a15cb")
+		.map(|v| v.to_vec())
+		.expect("unable to parse selector");
+
+	sel_constuctor.append(&mut tkn_name.encode());
+	sel_constuctor.append(&mut tkn_symbol.encode());
+	sel_constuctor.append(&mut U256::from(init_amount).encode());
+
+	deploy(
+		owner,
+		"../../runtime/integration-tests/contracts-data/solidity/token/dist/DemoToken.wasm",
+		sel_constuctor,
+	)
+}
+
+/// ink PSP22 token which can be minted and burnt by the registry
+fn create_psp22_token(owner: AccountId, init_amount: u128) -> AccountId {
+	let mut sel_constuctor = Bytes::from_str("0x9bae9d5e")
+		.map(|v| v.to_vec())
+		.expect("unable to parse selector");
+
+	sel_constuctor.append(&mut init_amount.encode());
+	sel_constuctor.append(&mut "PSP".encode());
+	sel_constuctor.append(&mut "PSP".encode());
+	sel_constuctor.append(&mut 12_u8.encode());
+	sel_constuctor.append(&mut ContractTokenRegistry::account_id().encode());
+
+	deploy(
+		owner,
+		"../../runtime/integration-tests/contracts-data/ink/psp22_token/dist/psp22_token.wasm",
+		sel_constuctor,
+	)
+}
+
+/// ink PSP34 collection, the owner is allowed to mint
+fn create_psp34_collection(owner: AccountId) -> AccountId {
+	let sel_constuctor = Bytes::from_str("0x9bae9d5e")
+		.map(|v| v.to_vec())
+		.expect("unable to parse selector");
+
+	deploy(
+		owner,
+		"../../runtime/integration-tests/contracts-data/ink/psp34_collection/dist/psp34_collection.wasm",
+		sel_constuctor,
+	)
+}
+
+/// mint a PSP34 token, ids are encoded as `Id::U128`
+fn mint_psp34(collection: AccountId, minter: AccountId, to: AccountId, id: u128) {
+	let mut input = Bytes::from_str("0x6c41f2ec")
+		.map(|v| v.to_vec())
+		.expect("unable to parse selector");
+
+	input.append(&mut to.encode());
+	input.append(&mut (4u8, id).encode());
 
-	deployed.clone()
+	assert_ok!(Contracts::call(Origin::signed(minter), collection, 0, MaxGas::get(), None, input));
 }
 
 #[test]
@@ -67,83 +123,133 @@ fn test_total_supply() {
 			assert_ok!(ContractTokenRegistry::register_asset(
 				Origin::root(),
 				deployed.clone(),
-				true
+				true,
+				false,
+				None
 			));
 
 			assert_eq!(
-				ContractTokenRegistry::total_supply(deployed.clone()),
-				Some(init_amount as u128)
+				ContractTokenRegistry::total_supply(deployed.clone()).result,
+				Ok(init_amount as u128)
 			);
 
 			assert_eq!(
-				ContractTokenRegistry::balance_of(deployed.clone(), ALICE),
-				Some(init_amount as u128)
+				ContractTokenRegistry::balance_of(deployed.clone(), ALICE).result,
+				Ok(init_amount as u128)
 			);
 
-			assert_eq!(ContractTokenRegistry::balance_of(deployed.clone(), BOB), Some(0));
+			assert_eq!(ContractTokenRegistry::balance_of(deployed.clone(), BOB).result, Ok(0));
 
-			assert_ok!(ContractTokenRegistry::transfer(
-				deployed.clone(),
-				ALICE,
-				BOB,
-				U256::from(init_amount / 10)
-			));
+			assert_ok!(
+				ContractTokenRegistry::transfer(
+					deployed.clone(),
+					ALICE,
+					BOB,
+					U256::from(init_amount / 10)
+				)
+				.result
+			);
 
 			assert_eq!(
-				ContractTokenRegistry::balance_of(deployed.clone(), BOB),
-				Some(init_amount as u128 / 10)
+				ContractTokenRegistry::balance_of(deployed.clone(), BOB).result,
+				Ok(init_amount as u128 / 10)
 			);
 
 			// alice should have no allowance from bob to spend
-			assert_eq!(ContractTokenRegistry::allowance(deployed.clone(), BOB, ALICE), Some(0));
+			assert_eq!(
+				ContractTokenRegistry::allowance(deployed.clone(), BOB, ALICE).result,
+				Ok(0)
+			);
 
 			// alice should not be able to spend on be half of bob
-			assert!(ContractTokenRegistry::transfer_from(
-				deployed.clone(),
-				ALICE,
-				BOB,
-				ALICE,
-				U256::from(init_amount / 100)
-			)
-			.is_err());
+			assert_eq!(
+				ContractTokenRegistry::transfer_from(
+					deployed.clone(),
+					ALICE,
+					BOB,
+					ALICE,
+					U256::from(init_amount / 100)
+				)
+				.result,
+				Err(TokenAccessError::Reverted)
+			);
 
 			assert_eq!(
-				ContractTokenRegistry::balance_of(deployed.clone(), BOB),
-				Some(init_amount as u128 / 10)
+				ContractTokenRegistry::balance_of(deployed.clone(), BOB).result,
+				Ok(init_amount as u128 / 10)
 			);
 
 			// bob should be able to allow alice to spend
-			assert_ok!(ContractTokenRegistry::approve(
-				deployed.clone(),
-				BOB,
-				ALICE,
-				U256::from(init_amount / 100)
-			));
+			assert_ok!(
+				ContractTokenRegistry::approve(
+					deployed.clone(),
+					BOB,
+					ALICE,
+					U256::from(init_amount / 100)
+				)
+				.result
+			);
 
 			// alice should have the correct allowance
 			assert_eq!(
-				ContractTokenRegistry::allowance(deployed.clone(), BOB, ALICE),
-				Some(init_amount as u128 / 100)
+				ContractTokenRegistry::allowance(deployed.clone(), BOB, ALICE).result,
+				Ok(init_amount as u128 / 100)
 			);
 
 			assert_eq!(
-				ContractTokenRegistry::balance_of(deployed.clone(), BOB),
-				Some(init_amount as u128 / 10)
+				ContractTokenRegistry::balance_of(deployed.clone(), BOB).result,
+				Ok(init_amount as u128 / 10)
 			);
 
 			// alice should be able to spend the allowance
-			assert_ok!(ContractTokenRegistry::transfer_from(
-				deployed.clone(),
-				ALICE,
-				BOB,
-				ALICE,
-				U256::from(init_amount / 100)
-			));
+			assert_ok!(
+				ContractTokenRegistry::transfer_from(
+					deployed.clone(),
+					ALICE,
+					BOB,
+					ALICE,
+					U256::from(init_amount / 100)
+				)
+				.result
+			);
+
+			assert_eq!(
+				ContractTokenRegistry::balance_of(deployed, BOB).result,
+				Ok(init_amount as u128 * 90 / 1000)
+			);
+		});
+}
+
+#[test]
+fn test_access_result() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (BOB, UNIT)])
+		.build()
+		.execute_with(|| {
+			let deployed = create_token(ALICE, "TKN", "TKN", 1000_u64);
 
+			// nothing is consumed when the asset is unknown
 			assert_eq!(
-				ContractTokenRegistry::balance_of(deployed, BOB),
-				Some(init_amount as u128 * 90 / 1000)
+				ContractTokenRegistry::balance_of(deployed.clone(), ALICE),
+				TokenAccessResult::new(0, Err(TokenAccessError::Unregistered))
 			);
+
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+
+			let read = ContractTokenRegistry::balance_of(deployed.clone(), ALICE);
+			assert_eq!(read.result, Ok(1000));
+			assert!(read.weight > 0 && read.weight <= MaxGas::get());
+
+			// the gas consumed by the contract is reported for failed calls as well
+			let write = ContractTokenRegistry::transfer(deployed, BOB, ALICE, U256::from(10));
+			assert_eq!(write.result, Err(TokenAccessError::Reverted));
+			assert!(write.weight > 0 && write.weight <= MaxGas::get());
 		});
 }
 
@@ -159,10 +265,53 @@ fn test_register() {
 			assert_ok!(ContractTokenRegistry::register_asset(
 				Origin::root(),
 				deployed.clone(),
-				true
+				true,
+				false,
+				None
+			));
+
+			assert_eq!(ContractTokenRegistry::get_registered(deployed.clone()), Some(true));
+			// governance registrations are verified without deposit
+			assert!(ContractTokenRegistry::is_verified(deployed.clone()));
+			assert_eq!(ContractTokenRegistry::asset_deposit(deployed), None);
+		});
+}
+
+#[test]
+fn test_asset_metadata() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (BOB, UNIT)])
+		.sudo(ALICE)
+		.build()
+		.execute_with(|| {
+			let deployed = create_token(ALICE, "ABC", "XYZ", UNIT);
+			assert_eq!(ContractTokenRegistry::asset_metadata(deployed.clone()), None);
+
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				false,
+				false,
+				None
+			));
+			assert_ok!(ContractTokenRegistry::set_minimum_balance(
+				Origin::root(),
+				deployed.clone(),
+				10
 			));
 
-			assert_eq!(ContractTokenRegistry::get_registered(deployed), Some(true));
+			// suspended assets are still described
+			assert_eq!(
+				ContractTokenRegistry::asset_metadata(deployed.clone()),
+				Some(AssetMetadata {
+					name: b"ABC".to_vec(),
+					symbol: b"XYZ".to_vec(),
+					decimals: 18,
+					existential_deposit: 10,
+				})
+			);
+			assert_eq!(ContractTokenRegistry::registered_assets(), vec![deployed]);
+			assert!(ContractTokenRegistry::enabled_assets().is_empty());
 		});
 }
 
@@ -178,7 +327,9 @@ fn test_suspend() {
 			assert_ok!(ContractTokenRegistry::register_asset(
 				Origin::root(),
 				deployed.clone(),
-				true
+				true,
+				false,
+				None
 			));
 
 			assert_eq!(ContractTokenRegistry::get_registered(deployed.clone()), Some(true));
@@ -201,7 +352,9 @@ fn test_unregister() {
 			assert_ok!(ContractTokenRegistry::register_asset(
 				Origin::root(),
 				deployed.clone(),
-				true
+				true,
+				false,
+				None
 			));
 
 			assert_eq!(ContractTokenRegistry::get_registered(deployed.clone()), Some(true));
@@ -211,3 +364,740 @@ fn test_unregister() {
 			assert_eq!(ContractTokenRegistry::get_registered(deployed), None);
 		});
 }
+
+#[test]
+fn test_mintable() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (BOB, UNIT)])
+		.sudo(ALICE)
+		.build()
+		.execute_with(|| {
+			let deployed = create_token(ALICE, "ABC", "ABC", UNIT);
+
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+			assert!(!ContractTokenRegistry::is_mintable(deployed.clone()));
+
+			// supply can not be changed unless declared mintable
+			assert_eq!(
+				ContractTokenRegistry::mint(deployed.clone(), BOB, U256::from(UNIT)).result,
+				Err(Error::<Runtime>::NotMintable.into())
+			);
+			assert_eq!(
+				ContractTokenRegistry::burn(deployed.clone(), ALICE, U256::from(UNIT)).result,
+				Err(Error::<Runtime>::NotMintable.into())
+			);
+
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				true,
+				None
+			));
+			assert!(ContractTokenRegistry::is_mintable(deployed.clone()));
+
+			assert_ok!(ContractTokenRegistry::unregister_asset(Origin::root(), deployed.clone()));
+			assert!(!ContractTokenRegistry::is_mintable(deployed));
+		});
+}
+
+#[test]
+fn test_mint_burn() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (BOB, UNIT)])
+		.build()
+		.execute_with(|| {
+			let deployed = create_psp22_token(ALICE, 1000);
+
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				true,
+				None
+			));
+			assert_eq!(
+				ContractTokenRegistry::asset_standard(deployed.clone()),
+				TokenStandard::Psp22
+			);
+			assert_eq!(ContractTokenRegistry::asset_decimals(deployed.clone()), 12);
+
+			assert_ok!(ContractTokenRegistry::mint(deployed.clone(), BOB, U256::from(500)).result);
+			assert_eq!(ContractTokenRegistry::balance_of(deployed.clone(), BOB).result, Ok(500));
+			assert_eq!(ContractTokenRegistry::total_supply(deployed.clone()).result, Ok(1500));
+
+			assert_ok!(
+				ContractTokenRegistry::burn(deployed.clone(), ALICE, U256::from(400)).result
+			);
+			assert_eq!(ContractTokenRegistry::balance_of(deployed.clone(), ALICE).result, Ok(600));
+			assert_eq!(ContractTokenRegistry::total_supply(deployed).result, Ok(1100));
+		});
+}
+
+#[test]
+fn test_psp22_ack() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (BOB, UNIT)])
+		.build()
+		.execute_with(|| {
+			let deployed = create_psp22_token(ALICE, 1000);
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+
+			assert_ok!(
+				ContractTokenRegistry::transfer(deployed.clone(), ALICE, BOB, U256::from(400))
+					.result
+			);
+			assert_eq!(ContractTokenRegistry::balance_of(deployed.clone(), BOB).result, Ok(400));
+
+			// PSP22 errors are returned without reverting the call
+			let rejected =
+				ContractTokenRegistry::transfer(deployed.clone(), BOB, ALICE, U256::from(401));
+			assert_eq!(rejected.result, Err(TokenAccessError::Rejected));
+			assert!(rejected.weight > 0);
+			assert_eq!(
+				ContractTokenRegistry::transfer_from(
+					deployed.clone(),
+					BOB,
+					ALICE,
+					BOB,
+					U256::from(100)
+				)
+				.result,
+				Err(TokenAccessError::Rejected)
+			);
+
+			assert_ok!(
+				ContractTokenRegistry::approve(deployed.clone(), ALICE, BOB, U256::from(100))
+					.result
+			);
+			assert_ok!(
+				ContractTokenRegistry::transfer_from(
+					deployed.clone(),
+					BOB,
+					ALICE,
+					BOB,
+					U256::from(100)
+				)
+				.result
+			);
+			assert_eq!(ContractTokenRegistry::balance_of(deployed.clone(), ALICE).result, Ok(500));
+			assert_eq!(ContractTokenRegistry::balance_of(deployed, BOB).result, Ok(500));
+		});
+}
+
+#[test]
+fn test_erc20_ack() {
+	let decode = <Erc20Codec as TokenCodec<Runtime>>::decode_ack;
+
+	// tokens returning nothing are considered successful
+	assert_ok!(decode(&[]));
+	assert_ok!(decode(&true.encode()));
+	assert_eq!(decode(&false.encode()), Err(TokenAccessError::Rejected));
+	assert_eq!(decode(&[2]), Err(TokenAccessError::DecodeFailed));
+}
+
+#[test]
+fn test_minimum_balance() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (BOB, UNIT)])
+		.sudo(ALICE)
+		.build()
+		.execute_with(|| {
+			let deployed = create_token(ALICE, "ABC", "ABC", UNIT);
+
+			assert_noop!(
+				ContractTokenRegistry::set_minimum_balance(Origin::root(), deployed.clone(), 10),
+				Error::<Runtime>::InvalidAsset
+			);
+
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+			assert_eq!(
+				<ContractTokenRegistry as TokenAccess<Runtime>>::minimum_balance(deployed.clone()),
+				0
+			);
+
+			assert_ok!(ContractTokenRegistry::set_minimum_balance(
+				Origin::root(),
+				deployed.clone(),
+				10
+			));
+			assert_eq!(
+				<ContractTokenRegistry as TokenAccess<Runtime>>::minimum_balance(deployed.clone()),
+				10
+			);
+
+			assert_ok!(ContractTokenRegistry::unregister_asset(Origin::root(), deployed.clone()));
+			assert_eq!(
+				<ContractTokenRegistry as TokenAccess<Runtime>>::minimum_balance(deployed),
+				0
+			);
+		});
+}
+
+#[test]
+fn test_psp22_codec() {
+	let to_balance = |amount: u128| amount.encode();
+
+	assert_eq!(
+		<Psp22Codec as TokenCodec<Runtime>>::encode(&Selector::BalanceOf { owner: ALICE }),
+		Some([hex!("6568382f").to_vec(), ALICE.encode()].concat())
+	);
+	assert_eq!(
+		<Psp22Codec as TokenCodec<Runtime>>::encode(&Selector::Transfer {
+			to: BOB,
+			amount: U256::from(UNIT)
+		}),
+		Some(
+			[hex!("db20f9f5").to_vec(), BOB.encode(), to_balance(UNIT), Vec::<u8>::new().encode()]
+				.concat()
+		)
+	);
+	// PSP22 balances are u128
+	assert_eq!(
+		<Psp22Codec as TokenCodec<Runtime>>::encode(&Selector::Approve {
+			spender: BOB,
+			amount: U256::MAX
+		}),
+		None
+	);
+	assert_eq!(
+		<Psp22Codec as TokenCodec<Runtime>>::decode_balance::<u128>(&to_balance(UNIT)),
+		Some(UNIT)
+	);
+
+	assert_eq!(
+		<Erc20Codec as TokenCodec<Runtime>>::encode(&Selector::Transfer {
+			to: BOB,
+			amount: U256::from(UNIT)
+		}),
+		Some([hex!("a9059cbb").to_vec(), BOB.encode(), U256::from(UNIT).encode()].concat())
+	);
+}
+
+#[test]
+fn test_nft_codec() {
+	let id = U256::from(7u8);
+
+	assert_eq!(
+		<Erc721Codec as NftCodec<Runtime>>::encode(&NftSelector::TransferFrom {
+			from: ALICE,
+			to: BOB,
+			id
+		}),
+		Some([hex!("23b872dd").to_vec(), ALICE.encode(), BOB.encode(), id.encode()].concat())
+	);
+	assert_eq!(
+		<Psp34Codec as NftCodec<Runtime>>::encode(&NftSelector::OwnerOf { id }),
+		// Id::U128
+		Some([hex!("1168624d").to_vec(), vec![4], 7u128.encode()].concat())
+	);
+	// PSP34 ids are expected to fit in u128
+	assert_eq!(
+		<Psp34Codec as NftCodec<Runtime>>::encode(&NftSelector::OwnerOf { id: U256::MAX }),
+		None
+	);
+	assert_eq!(
+		<Psp34Codec as NftCodec<Runtime>>::decode_owner(&Some(ALICE).encode()),
+		Some(Some(ALICE))
+	);
+	// Ok(Id::U8(7))
+	assert_eq!(<Psp34Codec as NftCodec<Runtime>>::decode_id(&[0, 0, 7]), Some(id));
+	assert_eq!(<Psp34Codec as NftCodec<Runtime>>::decode_id(&[1, 0]), None);
+}
+
+#[test]
+fn test_register_collection() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (BOB, UNIT)])
+		.sudo(ALICE)
+		.build()
+		.execute_with(|| {
+			let deployed = create_token(ALICE, "ABC", "ABC", UNIT);
+
+			assert_noop!(
+				ContractTokenRegistry::register_collection(
+					Origin::root(),
+					BOB,
+					NftStandard::Erc721
+				),
+				Error::<Runtime>::NotContract
+			);
+			assert_noop!(
+				ContractTokenRegistry::register_collection(
+					Origin::signed(BOB),
+					deployed.clone(),
+					NftStandard::Erc721
+				),
+				DispatchError::BadOrigin
+			);
+			// the probe fails on both standards, the probe is still charged
+			for standard in [NftStandard::Psp34, NftStandard::Erc721] {
+				let err = ContractTokenRegistry::register_collection(
+					Origin::root(),
+					deployed.clone(),
+					standard,
+				)
+				.unwrap_err();
+				assert_eq!(err.error, Error::<Runtime>::UnsupportedCollection.into());
+				assert!(
+					err.post_info.actual_weight.unwrap() >
+						<() as WeightInfo>::register_collection()
+				);
+			}
+
+			// collections must be registered to be accessed
+			assert_eq!(
+				<ContractTokenRegistry as traits::currencies::NonFungibleAccess<Runtime>>::owner_of(
+					deployed.clone(),
+					U256::one()
+				)
+				.result,
+				Err(TokenAccessError::Unregistered)
+			);
+			assert!(ContractTokenRegistry::account_nfts(ALICE).is_empty());
+
+			assert_noop!(
+				ContractTokenRegistry::unregister_collection(Origin::root(), deployed),
+				Error::<Runtime>::InvalidCollection
+			);
+		});
+}
+
+#[test]
+fn test_psp34_collection() {
+	use traits::currencies::NonFungibleAccess;
+
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (BOB, UNIT)])
+		.sudo(ALICE)
+		.build()
+		.execute_with(|| {
+			let collection = create_psp34_collection(ALICE);
+
+			// a PSP34 collection doesn't implement ERC165
+			assert_eq!(
+				ContractTokenRegistry::register_collection(
+					Origin::root(),
+					collection.clone(),
+					NftStandard::Erc721
+				)
+				.unwrap_err()
+				.error,
+				Error::<Runtime>::UnsupportedCollection.into()
+			);
+
+			let post_info = ContractTokenRegistry::register_collection(
+				Origin::root(),
+				collection.clone(),
+				NftStandard::Psp34,
+			)
+			.unwrap();
+			let charged = post_info.actual_weight.unwrap();
+			assert!(charged > <() as WeightInfo>::register_collection());
+			assert!(charged < <() as WeightInfo>::register_collection() + MaxGas::get());
+
+			for id in 1..=3 {
+				mint_psp34(collection.clone(), ALICE, ALICE, id);
+			}
+
+			let owner_of = |id: u64| {
+				<ContractTokenRegistry as NonFungibleAccess<Runtime>>::owner_of(
+					collection.clone(),
+					U256::from(id),
+				)
+				.result
+			};
+			let tokens_of = |who: AccountId| {
+				<ContractTokenRegistry as NonFungibleAccess<Runtime>>::tokens_of(
+					collection.clone(),
+					who,
+				)
+				.result
+			};
+			let transfer_from = |who: AccountId, from: AccountId, id: u64| {
+				<ContractTokenRegistry as NonFungibleAccess<Runtime>>::transfer_from(
+					collection.clone(),
+					who,
+					from,
+					BOB,
+					U256::from(id),
+				)
+				.result
+			};
+
+			assert_eq!(owner_of(1), Ok(Some(ALICE)));
+			assert_eq!(owner_of(9), Ok(None));
+			// PSP34 ids are encoded as u128
+			assert_eq!(
+				<ContractTokenRegistry as NonFungibleAccess<Runtime>>::owner_of(
+					collection.clone(),
+					U256::MAX,
+				)
+				.result,
+				Err(TokenAccessError::Other(Error::<Runtime>::InvalidTokenId.into()))
+			);
+			assert_eq!(
+				tokens_of(ALICE),
+				Ok(vec![U256::from(1u8), U256::from(2u8), U256::from(3u8)])
+			);
+			assert_eq!(
+				<ContractTokenRegistry as NonFungibleAccess<Runtime>>::token_uri(
+					collection.clone(),
+					U256::from(2u8)
+				)
+				.result,
+				Ok(b"ipfs://psp34".to_vec())
+			);
+
+			// `from` must own the token
+			let access = <ContractTokenRegistry as NonFungibleAccess<Runtime>>::transfer_from(
+				collection.clone(),
+				ALICE,
+				BOB,
+				BOB,
+				U256::one(),
+			);
+			assert_eq!(access.result, Err(TokenAccessError::Rejected));
+			assert!(access.weight > 0);
+
+			// BOB is not approved, the collection answers with an error
+			assert_eq!(transfer_from(BOB, ALICE, 1), Err(TokenAccessError::Rejected));
+			assert_eq!(owner_of(1), Ok(Some(ALICE)));
+
+			assert_eq!(
+				<ContractTokenRegistry as NonFungibleAccess<Runtime>>::approve(
+					collection.clone(),
+					ALICE,
+					BOB,
+					U256::one()
+				)
+				.result,
+				Ok(())
+			);
+			assert_eq!(transfer_from(BOB, ALICE, 1), Ok(()));
+			assert_eq!(owner_of(1), Ok(Some(BOB)));
+
+			// the last token of ALICE takes the place of the transferred one
+			assert_eq!(tokens_of(ALICE), Ok(vec![U256::from(3u8), U256::from(2u8)]));
+			assert_eq!(tokens_of(BOB), Ok(vec![U256::one()]));
+			assert_eq!(
+				ContractTokenRegistry::account_nfts(BOB),
+				vec![CollectionTokens {
+					collection: collection.clone(),
+					tokens: vec![U256::one()]
+				}]
+			);
+
+			// no more than MaxListedTokens are listed
+			for id in 4..=5 {
+				mint_psp34(collection.clone(), ALICE, ALICE, id);
+			}
+			assert_eq!(
+				tokens_of(ALICE),
+				Ok(vec![U256::from(3u8), U256::from(2u8), U256::from(4u8)])
+			);
+		});
+}
+
+#[test]
+fn test_register_probe() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (BOB, UNIT)])
+		.sudo(ALICE)
+		.build()
+		.execute_with(|| {
+			let deployed = create_token(ALICE, "ABC", "ABC", UNIT);
+			let code_hash = pallet_contracts::Pallet::<Runtime>::code_hash(&deployed).unwrap();
+
+			// only contracts can be registered
+			assert_noop!(
+				ContractTokenRegistry::register_asset(Origin::root(), BOB, true, false, None),
+				Error::<Runtime>::NotContract
+			);
+
+			// a solang token doesn't understand PSP22 selectors, the probe is still charged
+			let err = ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				Some(TokenStandard::Psp22),
+			)
+			.unwrap_err();
+			assert_eq!(err.error, Error::<Runtime>::UnsupportedToken.into());
+			let charged = err.post_info.actual_weight.unwrap();
+			assert!(charged > <() as WeightInfo>::register_asset());
+			assert!(charged < ContractTokenRegistry::register_weight(&Some(TokenStandard::Psp22)));
+			assert_eq!(ContractTokenRegistry::get_registered(deployed.clone()), None);
+
+			assert_ok!(ContractTokenRegistry::revoke_code_hash(Origin::root(), code_hash));
+			assert_noop!(
+				ContractTokenRegistry::register_asset(
+					Origin::root(),
+					deployed.clone(),
+					true,
+					false,
+					None
+				),
+				Error::<Runtime>::CodeHashNotApproved
+			);
+
+			assert_ok!(ContractTokenRegistry::approve_code_hash(Origin::root(), code_hash));
+			let post_info = ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				None,
+			)
+			.unwrap();
+			assert_eq!(
+				ContractTokenRegistry::asset_standard(deployed.clone()),
+				TokenStandard::Erc20
+			);
+			assert_eq!(ContractTokenRegistry::asset_decimals(deployed.clone()), 18);
+
+			// both standards are declared, only the probe of the detected one is charged
+			let charged = post_info.actual_weight.unwrap();
+			assert!(charged > <() as WeightInfo>::register_asset());
+			assert!(charged < ContractTokenRegistry::register_weight(&Some(TokenStandard::Erc20)));
+			assert_eq!(
+				ContractTokenRegistry::register_weight(&None),
+				<() as WeightInfo>::register_asset() + 10 * MaxGas::get()
+			);
+			assert_eq!(ContractTokenRegistry::asset_code_hash(deployed.clone()), Some(code_hash));
+
+			assert_ok!(ContractTokenRegistry::unregister_asset(Origin::root(), deployed.clone()));
+			assert_eq!(ContractTokenRegistry::asset_code_hash(deployed), None);
+		});
+}
+
+#[test]
+fn test_permissionless_register() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (BOB, UNIT)])
+		.sudo(ALICE)
+		.build()
+		.execute_with(|| {
+			let deployed = create_token(ALICE, "ABC", "ABC", UNIT);
+
+			// only governance can declare an asset mintable
+			assert_noop!(
+				ContractTokenRegistry::register_asset(
+					Origin::signed(BOB),
+					deployed.clone(),
+					true,
+					true,
+					None
+				),
+				Error::<Runtime>::MintableNotAllowed
+			);
+
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::signed(BOB),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+			assert_eq!(Balances::reserved_balance(BOB), RegistrationDeposit::get());
+			assert_eq!(ContractTokenRegistry::get_registered(deployed.clone()), Some(true));
+			assert!(!ContractTokenRegistry::is_verified(deployed.clone()));
+
+			// unverified assets are still accessible
+			assert_eq!(
+				<ContractTokenRegistry as TokenAccess<Runtime>>::total_supply(deployed.clone())
+					.result,
+				Ok(UNIT)
+			);
+
+			assert_noop!(
+				ContractTokenRegistry::register_asset(
+					Origin::signed(ALICE),
+					deployed.clone(),
+					true,
+					false,
+					None
+				),
+				Error::<Runtime>::AlreadyRegistered
+			);
+
+			// governance can't register it over the pending deposit
+			assert_noop!(
+				ContractTokenRegistry::register_asset(
+					Origin::root(),
+					deployed.clone(),
+					true,
+					true,
+					None
+				),
+				Error::<Runtime>::AlreadyRegistered
+			);
+			assert_eq!(Balances::reserved_balance(BOB), RegistrationDeposit::get());
+
+			// only the depositor or governance can unregister it
+			assert_noop!(
+				ContractTokenRegistry::unregister_asset(Origin::signed(ALICE), deployed.clone()),
+				DispatchError::BadOrigin
+			);
+
+			// the depositor can't withdraw the deposit while the asset may be rejected
+			assert_noop!(
+				ContractTokenRegistry::unregister_asset(Origin::signed(BOB), deployed.clone()),
+				Error::<Runtime>::ChallengePeriodNotOver
+			);
+
+			System::set_block_number(1 + ChallengePeriod::get() + 1);
+			assert_ok!(ContractTokenRegistry::unregister_asset(
+				Origin::signed(BOB),
+				deployed.clone()
+			));
+			assert_eq!(Balances::reserved_balance(BOB), 0);
+			assert_eq!(ContractTokenRegistry::get_registered(deployed.clone()), None);
+			assert_eq!(ContractTokenRegistry::asset_deposit(deployed), None);
+		});
+}
+
+#[test]
+fn test_challenge_period() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (BOB, UNIT)])
+		.sudo(ALICE)
+		.build()
+		.execute_with(|| {
+			let deployed = create_token(ALICE, "ABC", "ABC", UNIT);
+
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::signed(BOB),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+
+			// governance rejects the asset and slashes the deposit
+			assert_noop!(
+				ContractTokenRegistry::reject_asset(Origin::signed(ALICE), deployed.clone()),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(ContractTokenRegistry::reject_asset(Origin::root(), deployed.clone()));
+			assert_eq!(Balances::reserved_balance(BOB), 0);
+			assert_eq!(Balances::free_balance(BOB), UNIT - RegistrationDeposit::get());
+			assert_eq!(ContractTokenRegistry::get_registered(deployed.clone()), None);
+
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::signed(BOB),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+
+			assert_noop!(
+				ContractTokenRegistry::promote_asset(Origin::signed(ALICE), deployed.clone()),
+				Error::<Runtime>::ChallengePeriodNotOver
+			);
+
+			System::set_block_number(1 + ChallengePeriod::get() + 1);
+
+			assert_noop!(
+				ContractTokenRegistry::reject_asset(Origin::root(), deployed.clone()),
+				Error::<Runtime>::ChallengePeriodOver
+			);
+
+			// anyone can promote the asset once the challenge period is over
+			assert_ok!(ContractTokenRegistry::promote_asset(
+				Origin::signed(ALICE),
+				deployed.clone()
+			));
+			assert!(ContractTokenRegistry::is_verified(deployed.clone()));
+			assert_noop!(
+				ContractTokenRegistry::promote_asset(Origin::root(), deployed.clone()),
+				Error::<Runtime>::AlreadyVerified
+			);
+
+			// the deposit is returned when the asset is unregistered
+			assert_ok!(ContractTokenRegistry::unregister_asset(Origin::root(), deployed.clone()));
+			assert_eq!(Balances::reserved_balance(BOB), 0);
+			assert!(!ContractTokenRegistry::is_verified(deployed));
+		});
+}
+
+#[test]
+fn test_read_cache() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (BOB, UNIT)])
+		.build()
+		.execute_with(|| {
+			let deployed = create_token(ALICE, "TKN", "TKN", 1000_u64);
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+
+			let read = ContractTokenRegistry::balance_of(deployed.clone(), BOB);
+			assert_eq!(read.result, Ok(0));
+			assert!(read.weight > 0);
+			assert_eq!(ReadCache::<Runtime>::iter_prefix(&deployed).count(), 1);
+
+			// the contract is not executed again
+			assert_eq!(
+				ContractTokenRegistry::balance_of(deployed.clone(), BOB),
+				TokenAccessResult::new(0, Ok(0))
+			);
+
+			// transfers through the registry drop the cached reads
+			assert_ok!(
+				ContractTokenRegistry::transfer(deployed.clone(), ALICE, BOB, U256::from(10))
+					.result
+			);
+			assert_eq!(ReadCache::<Runtime>::iter_prefix(&deployed).count(), 0);
+			assert_eq!(ContractTokenRegistry::balance_of(deployed.clone(), BOB).result, Ok(10));
+
+			// the token emits an event when called directly
+			assert_ok!(Contracts::call(
+				Origin::signed(ALICE),
+				deployed.clone(),
+				0,
+				MaxGas::get(),
+				None,
+				<Erc20Codec as TokenCodec<Runtime>>::encode(&Selector::Transfer {
+					to: BOB,
+					amount: U256::from(10)
+				})
+				.unwrap()
+			));
+			let read = ContractTokenRegistry::balance_of(deployed.clone(), BOB);
+			assert_eq!(read.result, Ok(20));
+			assert!(read.weight > 0);
+
+			// reads are not kept across blocks
+			assert_eq!(
+				ContractTokenRegistry::on_initialize(2),
+				<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1)
+			);
+			assert_eq!(ReadCache::<Runtime>::iter_prefix(&deployed).count(), 0);
+			assert_eq!(ContractTokenRegistry::balance_of(deployed.clone(), BOB).result, Ok(20));
+		});
+}
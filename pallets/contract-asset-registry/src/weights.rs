@@ -6,6 +6,18 @@ pub trait WeightInfo {
 	fn unregister_asset() -> Weight;
 
 	fn suspend_asset() -> Weight;
+
+	fn set_minimum_balance() -> Weight;
+
+	fn approve_code_hash() -> Weight;
+
+	fn reject_asset() -> Weight;
+
+	fn promote_asset() -> Weight;
+
+	fn register_collection() -> Weight;
+
+	fn unregister_collection() -> Weight;
 }
 
 impl WeightInfo for () {
@@ -20,4 +32,28 @@ impl WeightInfo for () {
 	fn suspend_asset() -> Weight {
 		1000_u64
 	}
+
+	fn set_minimum_balance() -> Weight {
+		1000_u64
+	}
+
+	fn approve_code_hash() -> Weight {
+		1000_u64
+	}
+
+	fn reject_asset() -> Weight {
+		1000_u64
+	}
+
+	fn promote_asset() -> Weight {
+		1000_u64
+	}
+
+	fn register_collection() -> Weight {
+		1000_u64
+	}
+
+	fn unregister_collection() -> Weight {
+		1000_u64
+	}
 }
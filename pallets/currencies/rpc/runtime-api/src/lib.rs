@@ -1,11 +1,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
-use primitives::CurrencyId;
+use primitives::{AssetBalance, AssetMetadata, CurrencyEvent, CurrencyId};
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
-
+	/// version 2 adds `metadata` and `account_events`, version 3 adds `account_portfolio` and
+	/// version 4 adds `is_frozen` and `frozen_accounts`
+	#[api_version(4)]
 	pub trait CurrenciesApi<AccountId, Balance>
 	where
 		AccountId: Codec,
@@ -17,5 +19,19 @@ sp_api::decl_runtime_apis! {
 		fn free_balance(account: AccountId, asset: CurrencyId) -> Option<Balance>;
 
 		fn total_balance(account: AccountId, asset: CurrencyId) -> Option<Balance>;
+
+		fn metadata(asset: CurrencyId) -> Option<AssetMetadata<Balance, Vec<u8>>>;
+
+		/// balances of the account in every listed currency
+		fn account_portfolio(account: AccountId) -> Vec<AssetBalance<Balance, Vec<u8>>>;
+
+		/// currency events of the current block involving the account
+		fn account_events(account: AccountId) -> Vec<CurrencyEvent<AccountId, Balance>>;
+
+		/// whether the account is not allowed to move the currency, either the account or the
+		/// whole currency is frozen
+		fn is_frozen(account: AccountId, asset: CurrencyId) -> bool;
+
+		fn frozen_accounts(asset: CurrencyId) -> Vec<AccountId>;
 	}
 }
@@ -2,16 +2,20 @@ use std::{marker::PhantomData, sync::Arc};
 
 use codec::Codec;
 
-use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::CallError};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject, METHOD_NOT_FOUND_CODE},
+};
 
 pub use pallet_currencies_rpc_runtime_api::CurrenciesApi as CurrenciesRuntimeApi;
 
-use sp_api::{BlockId, ProvideRuntimeApi};
+use sp_api::{ApiExt, BlockId, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
 use sp_std::vec::Vec;
 
-use primitives::CurrencyId;
+use primitives::{AssetBalance, AssetMetadata, CurrencyEvent, CurrencyId};
 
 #[rpc(client, server)]
 pub trait CurrenciesApi<BlockHash, BlockNumber, AccountId, Balance> {
@@ -33,6 +37,42 @@ pub trait CurrenciesApi<BlockHash, BlockNumber, AccountId, Balance> {
 		currency_id: CurrencyId,
 		at: Option<BlockHash>,
 	) -> RpcResult<Balance>;
+
+	#[method(name = "currencies_metadata")]
+	fn metadata(
+		&self,
+		currency_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AssetMetadata<Balance, Vec<u8>>>>;
+
+	#[method(name = "currencies_portfolio")]
+	fn account_portfolio(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<AssetBalance<Balance, Vec<u8>>>>;
+
+	#[method(name = "currencies_accountEvents")]
+	fn account_events(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CurrencyEvent<AccountId, Balance>>>;
+
+	#[method(name = "currencies_isFrozen")]
+	fn is_frozen(
+		&self,
+		account: AccountId,
+		currency_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+
+	#[method(name = "currencies_frozenAccounts")]
+	fn frozen_accounts(
+		&self,
+		currency_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<AccountId>>;
 }
 
 pub struct CurrenciesRpc<Client, Block> {
@@ -46,6 +86,43 @@ impl<Client, Block> CurrenciesRpc<Client, Block> {
 	}
 }
 
+impl<Client, Block> CurrenciesRpc<Client, Block>
+where
+	Block: BlockT,
+	Client: ProvideRuntimeApi<Block>,
+{
+	/// runtimes older than `version` don't expose `method`
+	fn ensure_api_version<AccountId, Balance>(
+		&self,
+		at: &BlockId<Block>,
+		version: u32,
+		method: &str,
+	) -> RpcResult<()>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		Client::Api: CurrenciesRuntimeApi<Block, AccountId, Balance>,
+	{
+		let supported = self
+			.client
+			.runtime_api()
+			.has_api_with::<dyn CurrenciesRuntimeApi<Block, AccountId, Balance>, _>(at, |v| {
+				v >= version
+			})
+			.map_err(CallError::from_std_error)?;
+		if !supported {
+			return Err(CallError::Custom(ErrorObject::owned(
+				METHOD_NOT_FOUND_CODE,
+				format!("{} is not supported by the runtime", method),
+				None::<()>,
+			))
+			.into())
+		}
+
+		Ok(())
+	}
+}
+
 impl<Client, Block, AccountId, Balance>
 	CurrenciesApiServer<
 		<Block as BlockT>::Hash,
@@ -94,4 +171,69 @@ where
 			.map(|v| v.unwrap_or_default())
 			.map_err(|e| CallError::from_std_error(e).into())
 	}
+
+	fn metadata(
+		&self,
+		currency_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<AssetMetadata<Balance, Vec<u8>>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		self.ensure_api_version::<AccountId, Balance>(&at, 2, "metadata")?;
+		api.metadata(&at, currency_id).map_err(|e| CallError::from_std_error(e).into())
+	}
+
+	fn account_portfolio(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<AssetBalance<Balance, Vec<u8>>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		self.ensure_api_version::<AccountId, Balance>(&at, 3, "account_portfolio")?;
+		api.account_portfolio(&at, account)
+			.map_err(|e| CallError::from_std_error(e).into())
+	}
+
+	fn account_events(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<CurrencyEvent<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		self.ensure_api_version::<AccountId, Balance>(&at, 2, "account_events")?;
+		api.account_events(&at, account)
+			.map_err(|e| CallError::from_std_error(e).into())
+	}
+
+	fn is_frozen(
+		&self,
+		account: AccountId,
+		currency_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		self.ensure_api_version::<AccountId, Balance>(&at, 4, "is_frozen")?;
+		api.is_frozen(&at, account, currency_id)
+			.map_err(|e| CallError::from_std_error(e).into())
+	}
+
+	fn frozen_accounts(
+		&self,
+		currency_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<AccountId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		self.ensure_api_version::<AccountId, Balance>(&at, 4, "frozen_accounts")?;
+		api.frozen_accounts(&at, currency_id)
+			.map_err(|e| CallError::from_std_error(e).into())
+	}
 }
@@ -0,0 +1,245 @@
+//! escrow based reserve and lock support for contract-based assets
+//!
+//! Contract-based tokens have no notion of reserved balance, so reserving moves the tokens into an
+//! escrow account controlled by this pallet and the amount is recorded in `ContractReserves`.
+//! Locks are kept in `ContractLocks` and enforced whenever the balance is moved through this
+//! pallet.
+
+use super::*;
+
+impl<T: Config> Pallet<T>
+where
+	U256: From<BalanceOf<T>>,
+{
+	/// account holding the reserved contract-based assets
+	pub fn escrow_account() -> AccountIdOf<T> {
+		T::PalletId::get().try_into_account().expect("Invalid PalletId")
+	}
+
+	/// the max amount locked for an account, which is not allowed to be moved out
+	pub fn contract_frozen_balance(currency_id: CurrencyId, who: &AccountIdOf<T>) -> BalanceOf<T> {
+		ContractLocks::<T>::get(currency_id, who)
+			.iter()
+			.map(|lock| lock.amount)
+			.max()
+			.unwrap_or_default()
+	}
+
+	#[transactional]
+	pub(crate) fn reserve_contract_asset(
+		currency_id: CurrencyId,
+		addr: [u8; 32],
+		who: &AccountIdOf<T>,
+		value: BalanceOf<T>,
+	) -> DispatchResult {
+		if value.is_zero() {
+			return Ok(())
+		}
+
+		<Self as MultiCurrency<_>>::ensure_can_withdraw(currency_id, who, value)?;
+
+		let asset = T::ConvertIntoAccountId::convert(addr);
+		let escrow = Self::escrow_account();
+
+		// the escrow account pulls the reserved amount, so the token contract sees a regular
+		// allowance flow instead of an arbitrary transfer
+		Self::contract_access(
+			T::ContractAssets::approve(asset.clone(), who.clone(), escrow.clone(), value.into()),
+			&mut 0,
+		)?;
+		Self::contract_access(
+			T::ContractAssets::transfer_from(
+				asset,
+				escrow.clone(),
+				who.clone(),
+				escrow,
+				value.into(),
+			),
+			&mut 0,
+		)?;
+
+		ContractReserves::<T>::mutate(currency_id, who, |reserved| {
+			*reserved = reserved.saturating_add(value)
+		});
+
+		Ok(())
+	}
+
+	/// returns the amount which is not unreserved
+	pub(crate) fn unreserve_contract_asset(
+		currency_id: CurrencyId,
+		addr: [u8; 32],
+		who: &AccountIdOf<T>,
+		value: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		let actual = value.min(ContractReserves::<T>::get(currency_id, who));
+		if actual.is_zero() {
+			return value
+		}
+
+		let asset = T::ConvertIntoAccountId::convert(addr);
+
+		if let Err(err) = Self::contract_access(
+			T::ContractAssets::transfer(asset, Self::escrow_account(), who.clone(), actual.into()),
+			&mut 0,
+		) {
+			log::warn!("unable to release reserved contract asset: {:?}", err);
+			return value
+		}
+
+		Self::decrease_contract_reserve(currency_id, who, actual);
+
+		value.saturating_sub(actual)
+	}
+
+	/// slashed contract-based assets are moved from the escrow account to `SlashReceiver`, since
+	/// the supply of a contract can not be altered from here. Returns the amount which is not
+	/// slashed.
+	pub(crate) fn slash_reserved_contract_asset(
+		currency_id: CurrencyId,
+		addr: [u8; 32],
+		who: &AccountIdOf<T>,
+		value: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		let actual = value.min(ContractReserves::<T>::get(currency_id, who));
+		if actual.is_zero() {
+			return value
+		}
+
+		let asset = T::ConvertIntoAccountId::convert(addr);
+
+		if let Err(err) = Self::contract_access(
+			T::ContractAssets::transfer(
+				asset,
+				Self::escrow_account(),
+				T::SlashReceiver::get(),
+				actual.into(),
+			),
+			&mut 0,
+		) {
+			log::warn!("unable to slash reserved contract asset: {:?}", err);
+			return value
+		}
+
+		Self::decrease_contract_reserve(currency_id, who, actual);
+
+		value.saturating_sub(actual)
+	}
+
+	/// returns the amount which is not moved
+	#[transactional]
+	pub(crate) fn repatriate_reserved_contract_asset(
+		currency_id: CurrencyId,
+		addr: [u8; 32],
+		slashed: &AccountIdOf<T>,
+		beneficiary: &AccountIdOf<T>,
+		value: BalanceOf<T>,
+		status: orml_traits::BalanceStatus,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		if slashed == beneficiary {
+			return match status {
+				orml_traits::BalanceStatus::Free =>
+					Ok(Self::unreserve_contract_asset(currency_id, addr, slashed, value)),
+				orml_traits::BalanceStatus::Reserved =>
+					Ok(value.saturating_sub(ContractReserves::<T>::get(currency_id, slashed))),
+			}
+		}
+
+		let actual = value.min(ContractReserves::<T>::get(currency_id, slashed));
+		if actual.is_zero() {
+			return Ok(value)
+		}
+
+		match status {
+			orml_traits::BalanceStatus::Free => {
+				let asset = T::ConvertIntoAccountId::convert(addr);
+				Self::contract_access(
+					T::ContractAssets::transfer(
+						asset,
+						Self::escrow_account(),
+						beneficiary.clone(),
+						actual.into(),
+					),
+					&mut 0,
+				)?;
+			},
+			orml_traits::BalanceStatus::Reserved =>
+				ContractReserves::<T>::mutate(currency_id, beneficiary, |reserved| {
+					*reserved = reserved.saturating_add(actual)
+				}),
+		}
+
+		Self::decrease_contract_reserve(currency_id, slashed, actual);
+
+		Ok(value.saturating_sub(actual))
+	}
+
+	pub(crate) fn set_contract_lock(
+		lock_id: orml_traits::LockIdentifier,
+		currency_id: CurrencyId,
+		who: &AccountIdOf<T>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			Self::remove_contract_lock(lock_id, currency_id, who);
+			return Ok(())
+		}
+
+		ContractLocks::<T>::try_mutate(currency_id, who, |locks| -> DispatchResult {
+			match locks.iter_mut().find(|lock| lock.id == lock_id) {
+				Some(lock) => lock.amount = amount,
+				None => locks
+					.try_push(BalanceLock { id: lock_id, amount })
+					.map_err(|_| Error::<T>::MaxLocksExceeded)?,
+			}
+			Ok(())
+		})
+	}
+
+	pub(crate) fn extend_contract_lock(
+		lock_id: orml_traits::LockIdentifier,
+		currency_id: CurrencyId,
+		who: &AccountIdOf<T>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+
+		ContractLocks::<T>::try_mutate(currency_id, who, |locks| -> DispatchResult {
+			match locks.iter_mut().find(|lock| lock.id == lock_id) {
+				Some(lock) => lock.amount = lock.amount.max(amount),
+				None => locks
+					.try_push(BalanceLock { id: lock_id, amount })
+					.map_err(|_| Error::<T>::MaxLocksExceeded)?,
+			}
+			Ok(())
+		})
+	}
+
+	pub(crate) fn remove_contract_lock(
+		lock_id: orml_traits::LockIdentifier,
+		currency_id: CurrencyId,
+		who: &AccountIdOf<T>,
+	) {
+		ContractLocks::<T>::mutate_exists(currency_id, who, |maybe_locks| {
+			if let Some(locks) = maybe_locks {
+				locks.retain(|lock| lock.id != lock_id);
+				if locks.is_empty() {
+					*maybe_locks = None;
+				}
+			}
+		});
+	}
+
+	fn decrease_contract_reserve(
+		currency_id: CurrencyId,
+		who: &AccountIdOf<T>,
+		amount: BalanceOf<T>,
+	) {
+		ContractReserves::<T>::mutate_exists(currency_id, who, |maybe_reserved| {
+			let remain = maybe_reserved.unwrap_or_default().saturating_sub(amount);
+			*maybe_reserved = if remain.is_zero() { None } else { Some(remain) };
+		});
+	}
+}
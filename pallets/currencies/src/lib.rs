@@ -13,13 +13,30 @@
 //! used. When trying to operate on contract based tokens, the T::ContractAssets associate type will
 //! be used, be cautious that not all features are enabled for contrat based tokens due to their
 //! natural differences in design.
+//!
+//! Contract-based tokens registered as mintable can be deposited and withdrawn, which mints and
+//! burns them through the token contract.
+//!
+//! Reserving contract-based tokens moves them into an escrow account owned by this pallet, while
+//! locks on them are only tracked by this pallet and enforced on transfers made through it.
+//! Slashed reserves are moved out of the escrow account to `SlashReceiver`.
+//!
+//! Transfers of contract-based tokens are declared with the max weight of the contract accesses
+//! they need, only the weight actually consumed by the token contract is charged.
+//!
+//! For compliance, root or the admin of a currency can freeze the whole currency or single
+//! accounts of it. Frozen balances can not be transferred, withdrawn or reserved through this
+//! pallet.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
+	dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
 	pallet_prelude::*,
 	sp_runtime,
+	sp_std::prelude::*,
 	traits::tokens::{fungible, fungibles, DepositConsequence, WithdrawConsequence},
+	transactional, PalletId,
 };
 
 use frame_system::pallet_prelude::*;
@@ -31,12 +48,15 @@ use orml_traits::{
 };
 
 pub use pallet::*;
-use primitives::CurrencyId;
+use primitives::{CurrencyEvent, CurrencyId};
 use sp_core::U256;
-use sp_runtime::traits::{CheckedAdd, Convert, Saturating, Zero};
-use traits::currencies::TokenAccess;
+use sp_runtime::traits::{AccountIdConversion, CheckedAdd, Convert, Saturating, Zero};
+use traits::currencies::{TokenAccess, TokenAccessError, TokenAccessResult};
+use weights::WeightInfo;
 
 pub mod adapters;
+mod escrow;
+pub mod weights;
 
 /// +++++++++++++++++++++++
 /// specifying type alises.
@@ -50,6 +70,13 @@ type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
 type CurrencyIdOf<T> = <Pallet<T> as MultiCurrency<AccountIdOf<T>>>::CurrencyId;
 
+/// a lock placed on a contract-based asset
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct BalanceLock<Balance> {
+	pub id: orml_traits::LockIdentifier,
+	pub amount: Balance,
+}
+
 #[cfg(test)]
 mod mock;
 
@@ -83,15 +110,160 @@ mod pallet {
 
 		/// provide mechanism to get account_id from pub key, used for contract-asset lookup
 		type ConvertIntoAccountId: Convert<[u8; 32], Self::AccountId>;
+
+		/// used to derive the escrow account holding reserved contract-based assets
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// account receiving slashed contract-based assets, e.g. the treasury
+		type SlashReceiver: Get<AccountIdOf<Self>>;
+
+		/// max number of locks per account on a contract-based asset
+		#[pallet::constant]
+		type MaxLocks: Get<u32>;
+
+		/// max number of transfers in a single batch
+		#[pallet::constant]
+		type MaxBatchTransfers: Get<u32>;
+
+		/// max number of contract-based asset transfers in a single batch, each of them is
+		/// declared with the max weight of its contract accesses
+		#[pallet::constant]
+		type MaxBatchContractTransfers: Get<u32>;
+
+		/// origin allowed to move balances on behalf of other accounts, assign asset admins and
+		/// freeze any currency
+		type AllowedOrigin: EnsureOrigin<Self::Origin>;
+
+		type WeightInfo: WeightInfo;
+
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	/// contract-based assets reserved by an account, the tokens are held by the escrow account
+	#[pallet::storage]
+	#[pallet::getter(fn contract_reserves)]
+	pub type ContractReserves<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn contract_locks)]
+	pub type ContractLocks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		BoundedVec<BalanceLock<BalanceOf<T>>, T::MaxLocks>,
+		ValueQuery,
+	>;
+
+	/// account allowed to freeze and thaw a currency besides AllowedOrigin
+	#[pallet::storage]
+	#[pallet::getter(fn asset_admin)]
+	pub type AssetAdmins<T: Config> = StorageMap<_, Blake2_128Concat, CurrencyId, AccountIdOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn is_currency_frozen)]
+	pub type FrozenCurrencies<T: Config> =
+		StorageMap<_, Blake2_128Concat, CurrencyId, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn is_account_frozen)]
+	pub type FrozenAccounts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		bool,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		Transferred {
+			currency_id: CurrencyId,
+			from: AccountIdOf<T>,
+			to: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		},
+		Deposited {
+			currency_id: CurrencyId,
+			who: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		},
+		Withdrawn {
+			currency_id: CurrencyId,
+			who: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		},
+		Reserved {
+			currency_id: CurrencyId,
+			who: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		},
+		Unreserved {
+			currency_id: CurrencyId,
+			who: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		},
+		/// free or reserved balance slashed
+		Slashed {
+			currency_id: CurrencyId,
+			who: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		},
+		AssetAdminSet {
+			currency_id: CurrencyId,
+			admin: Option<AccountIdOf<T>>,
+		},
+		CurrencyFrozen {
+			currency_id: CurrencyId,
+		},
+		CurrencyThawed {
+			currency_id: CurrencyId,
+		},
+		AccountFrozen {
+			currency_id: CurrencyId,
+			who: AccountIdOf<T>,
+		},
+		AccountThawed {
+			currency_id: CurrencyId,
+			who: AccountIdOf<T>,
+		},
+	}
+
 	#[pallet::error]
 	pub enum Error<T> {
 		BalanceTooLow,
 		InvalidContractOperation,
+		MaxLocksExceeded,
+		LiquidityRestrictions,
+		ReserveTooLow,
+		CurrencyFrozen,
+		AccountFrozen,
+		/// the contract-based asset is not registered or suspended
+		UnregisteredAsset,
+		ContractReverted,
+		ContractOutOfGas,
+		/// the token contract returned data which can not be decoded
+		InvalidContractResponse,
+		/// the token contract refused the operation
+		ContractRejected,
+		/// the batch exceeds `MaxBatchContractTransfers`
+		TooManyContractTransfers,
 	}
 
 	#[pallet::call]
@@ -99,16 +271,187 @@ mod pallet {
 	where
 		U256: From<BalanceOf<T>>,
 	{
-		#[pallet::weight(100_000)]
+		#[pallet::weight(
+			T::WeightInfo::transfer().saturating_add(Pallet::<T>::contract_weight(currency_id))
+		)]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			to: AccountIdOf<T>,
 			currency_id: CurrencyIdOf<T>,
 			balance: BalanceOf<T>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let mut consumed = 0;
+			let result = Self::do_transfer(currency_id, &from, &to, balance, &mut consumed);
+			Self::with_actual_weight(T::WeightInfo::transfer(), consumed, result)
+		}
+
+		/// same as transfer, but the sender account is guaranteed to stay alive
+		#[pallet::weight(
+			T::WeightInfo::transfer_keep_alive()
+				.saturating_add(Pallet::<T>::contract_weight(currency_id))
+		)]
+		pub fn transfer_keep_alive(
+			origin: OriginFor<T>,
+			to: AccountIdOf<T>,
+			currency_id: CurrencyIdOf<T>,
+			balance: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let mut consumed = 0;
+			let result =
+				Self::do_transfer_keep_alive(currency_id, &from, &to, balance, true, &mut consumed);
+			Self::with_actual_weight(T::WeightInfo::transfer_keep_alive(), consumed, result)
+		}
+
+		/// transfer the whole transferable balance of a currency
+		#[pallet::weight(
+			T::WeightInfo::transfer_all()
+				.saturating_add(Pallet::<T>::contract_weight(currency_id))
+				.saturating_add(Pallet::<T>::contract_read_weight(currency_id))
+		)]
+		pub fn transfer_all(
+			origin: OriginFor<T>,
+			to: AccountIdOf<T>,
+			currency_id: CurrencyIdOf<T>,
+			keep_alive: bool,
+		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 
-			<Self as MultiCurrency<AccountIdOf<T>>>::transfer(currency_id, &from, &to, balance)
+			let mut consumed = 0;
+			let result = Self::do_reducible_balance(currency_id, &from, keep_alive, &mut consumed)
+				.and_then(|balance| {
+					Self::do_transfer_keep_alive(
+						currency_id,
+						&from,
+						&to,
+						balance,
+						keep_alive,
+						&mut consumed,
+					)
+				});
+			Self::with_actual_weight(T::WeightInfo::transfer_all(), consumed, result)
+		}
+
+		/// transfer multiple currencies to multiple recipients, all or nothing
+		#[pallet::weight(
+			transfers.iter().fold(
+				T::WeightInfo::transfer_batch(transfers.len() as u32),
+				|weight, (_, currency_id, _)| {
+					weight.saturating_add(Pallet::<T>::contract_weight(currency_id))
+				},
+			)
+		)]
+		pub fn transfer_batch(
+			origin: OriginFor<T>,
+			transfers: BoundedVec<
+				(AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>),
+				T::MaxBatchTransfers,
+			>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let contract_transfers = transfers
+				.iter()
+				.filter(|(_, currency_id, _)| matches!(currency_id, CurrencyId::Erc20(_)))
+				.count();
+			ensure!(
+				contract_transfers <= T::MaxBatchContractTransfers::get() as usize,
+				Error::<T>::TooManyContractTransfers
+			);
+
+			let base = T::WeightInfo::transfer_batch(transfers.len() as u32);
+			let mut consumed = 0;
+			let result = transfers.into_iter().try_for_each(|(to, currency_id, balance)| {
+				Self::do_transfer(currency_id, &from, &to, balance, &mut consumed)
+			});
+			Self::with_actual_weight(base, consumed, result)
+		}
+
+		#[pallet::weight(
+			T::WeightInfo::force_transfer().saturating_add(Pallet::<T>::contract_weight(currency_id))
+		)]
+		pub fn force_transfer(
+			origin: OriginFor<T>,
+			from: AccountIdOf<T>,
+			to: AccountIdOf<T>,
+			currency_id: CurrencyIdOf<T>,
+			balance: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::AllowedOrigin::ensure_origin(origin)?;
+
+			let mut consumed = 0;
+			let result = Self::do_transfer(currency_id, &from, &to, balance, &mut consumed);
+			Self::with_actual_weight(T::WeightInfo::force_transfer(), consumed, result)
+		}
+
+		#[pallet::weight(T::WeightInfo::set_asset_admin())]
+		pub fn set_asset_admin(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			admin: Option<AccountIdOf<T>>,
+		) -> DispatchResult {
+			T::AllowedOrigin::ensure_origin(origin)?;
+
+			AssetAdmins::<T>::set(currency_id, admin.clone());
+			Self::deposit_event(Event::AssetAdminSet { currency_id, admin });
+
+			Ok(())
+		}
+
+		/// stop all accounts from moving the currency
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn freeze_currency(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+		) -> DispatchResult {
+			Self::ensure_freezer(origin, currency_id)?;
+
+			FrozenCurrencies::<T>::insert(currency_id, true);
+			Self::deposit_event(Event::CurrencyFrozen { currency_id });
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn thaw_currency(origin: OriginFor<T>, currency_id: CurrencyIdOf<T>) -> DispatchResult {
+			Self::ensure_freezer(origin, currency_id)?;
+
+			FrozenCurrencies::<T>::remove(currency_id);
+			Self::deposit_event(Event::CurrencyThawed { currency_id });
+
+			Ok(())
+		}
+
+		/// stop an account from moving the currency
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn freeze_account(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			who: AccountIdOf<T>,
+		) -> DispatchResult {
+			Self::ensure_freezer(origin, currency_id)?;
+
+			FrozenAccounts::<T>::insert(currency_id, &who, true);
+			Self::deposit_event(Event::AccountFrozen { currency_id, who });
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn thaw_account(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			who: AccountIdOf<T>,
+		) -> DispatchResult {
+			Self::ensure_freezer(origin, currency_id)?;
+
+			FrozenAccounts::<T>::remove(currency_id, &who);
+			Self::deposit_event(Event::AccountThawed { currency_id, who });
+
+			Ok(())
 		}
 	}
 }
@@ -124,6 +467,230 @@ where
 	pub fn total_balance(account: AccountIdOf<T>, asset_id: CurrencyIdOf<T>) -> BalanceOf<T> {
 		<Self as MultiCurrency<AccountIdOf<T>>>::total_balance(asset_id, &account)
 	}
+
+	/// same as `MultiCurrency::ensure_can_withdraw`, accumulating the weight consumed by token
+	/// contracts into `consumed`
+	pub(crate) fn do_ensure_can_withdraw(
+		currency_id: CurrencyId,
+		who: &AccountIdOf<T>,
+		amount: BalanceOf<T>,
+		consumed: &mut Weight,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+
+		match currency_id {
+			CurrencyId::NativeToken(_) =>
+				T::MultiCurrency::ensure_can_withdraw(currency_id, who, amount),
+			CurrencyId::Erc20(addr) => {
+				let asset = T::ConvertIntoAccountId::convert(addr);
+				let balance = Self::contract_access(
+					T::ContractAssets::balance_of(asset, who.clone()),
+					consumed,
+				)?;
+
+				ensure!(balance >= amount, Error::<T>::BalanceTooLow);
+				ensure!(
+					balance.saturating_sub(amount) >=
+						Self::contract_frozen_balance(currency_id, who),
+					Error::<T>::LiquidityRestrictions
+				);
+				Ok(())
+			},
+		}
+	}
+
+	/// same as `MultiCurrency::transfer`, accumulating the weight consumed by token contracts into
+	/// `consumed`
+	pub(crate) fn do_transfer(
+		currency_id: CurrencyId,
+		from: &AccountIdOf<T>,
+		to: &AccountIdOf<T>,
+		amount: BalanceOf<T>,
+		consumed: &mut Weight,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		Self::ensure_not_frozen(currency_id, from)?;
+		match currency_id {
+			CurrencyId::NativeToken(_) =>
+				T::MultiCurrency::transfer(currency_id, from, to, amount)?,
+			CurrencyId::Erc20(addr) => {
+				Self::do_ensure_can_withdraw(currency_id, from, amount, consumed)?;
+				let asset = T::ConvertIntoAccountId::convert(addr);
+
+				Self::contract_access(
+					T::ContractAssets::transfer(asset, from.clone(), to.clone(), amount.into()),
+					consumed,
+				)?;
+			},
+		}
+		Self::deposit_event(Event::Transferred {
+			currency_id,
+			from: from.clone(),
+			to: to.clone(),
+			amount,
+		});
+
+		Ok(())
+	}
+
+	/// transfer with an existence requirement, which only applies to native tokens
+	fn do_transfer_keep_alive(
+		currency_id: CurrencyId,
+		from: &AccountIdOf<T>,
+		to: &AccountIdOf<T>,
+		amount: BalanceOf<T>,
+		keep_alive: bool,
+		consumed: &mut Weight,
+	) -> DispatchResult {
+		match currency_id {
+			CurrencyId::NativeToken(_) => <Self as fungibles::Transfer<AccountIdOf<T>>>::transfer(
+				currency_id,
+				from,
+				to,
+				amount,
+				keep_alive,
+			)
+			.map(|_| ()),
+			CurrencyId::Erc20(_) => Self::do_transfer(currency_id, from, to, amount, consumed),
+		}
+	}
+
+	/// balance which can be transferred out of the account
+	fn do_reducible_balance(
+		currency_id: CurrencyId,
+		who: &AccountIdOf<T>,
+		keep_alive: bool,
+		consumed: &mut Weight,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		match currency_id {
+			CurrencyId::NativeToken(_) => Ok(<T::MultiCurrency as fungibles::Inspect<
+				AccountIdOf<T>,
+			>>::reducible_balance(currency_id, who, keep_alive)),
+			CurrencyId::Erc20(addr) => {
+				let asset = T::ConvertIntoAccountId::convert(addr);
+				let balance = Self::contract_access(
+					T::ContractAssets::balance_of(asset, who.clone()),
+					consumed,
+				)?;
+				Ok(balance.saturating_sub(Self::contract_frozen_balance(currency_id, who)))
+			},
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// upper bound of the weight consumed by token contracts while transferring the currency
+	pub fn contract_weight(currency_id: &CurrencyId) -> Weight {
+		match currency_id {
+			// the balance is read before the transfer
+			CurrencyId::Erc20(_) => T::ContractAssets::max_weight().saturating_mul(2),
+			CurrencyId::NativeToken(_) => 0,
+		}
+	}
+
+	/// upper bound of the weight consumed by a token contract while reading a balance
+	pub fn contract_read_weight(currency_id: &CurrencyId) -> Weight {
+		match currency_id {
+			CurrencyId::Erc20(_) => T::ContractAssets::max_weight(),
+			CurrencyId::NativeToken(_) => 0,
+		}
+	}
+
+	/// account the weight consumed by a token contract and convert its error
+	pub(crate) fn contract_access<R>(
+		access: TokenAccessResult<R>,
+		consumed: &mut Weight,
+	) -> Result<R, DispatchError> {
+		*consumed = consumed.saturating_add(access.weight);
+
+		access.result.map_err(|err| match err {
+			TokenAccessError::Unregistered => Error::<T>::UnregisteredAsset.into(),
+			TokenAccessError::Reverted => Error::<T>::ContractReverted.into(),
+			TokenAccessError::Rejected => Error::<T>::ContractRejected.into(),
+			TokenAccessError::OutOfGas => Error::<T>::ContractOutOfGas.into(),
+			TokenAccessError::DecodeFailed => Error::<T>::InvalidContractResponse.into(),
+			TokenAccessError::Other(err) => err,
+		})
+	}
+
+	/// charge the benchmarked weight of a call along with the weight consumed by token
+	/// contracts, failures before reaching any contract are charged the declared weight
+	fn with_actual_weight(
+		base: Weight,
+		consumed: Weight,
+		result: DispatchResult,
+	) -> DispatchResultWithPostInfo {
+		let post_info = PostDispatchInfo::from(Some(base.saturating_add(consumed)));
+
+		match result {
+			Ok(()) => Ok(post_info),
+			Err(error) if consumed.is_zero() => Err(error.into()),
+			Err(error) => Err(DispatchErrorWithPostInfo { post_info, error }),
+		}
+	}
+
+	/// whether the account is not allowed to move the currency
+	pub fn is_frozen(currency_id: CurrencyId, who: &AccountIdOf<T>) -> bool {
+		FrozenCurrencies::<T>::get(currency_id) || FrozenAccounts::<T>::get(currency_id, who)
+	}
+
+	/// accounts frozen for the currency
+	pub fn frozen_accounts(currency_id: CurrencyId) -> Vec<AccountIdOf<T>> {
+		FrozenAccounts::<T>::iter_key_prefix(currency_id).collect()
+	}
+
+	pub(crate) fn ensure_not_frozen(
+		currency_id: CurrencyId,
+		who: &AccountIdOf<T>,
+	) -> DispatchResult {
+		ensure!(!FrozenCurrencies::<T>::get(currency_id), Error::<T>::CurrencyFrozen);
+		ensure!(!FrozenAccounts::<T>::get(currency_id, who), Error::<T>::AccountFrozen);
+		Ok(())
+	}
+
+	/// either AllowedOrigin or the admin of the currency
+	fn ensure_freezer(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+		let who = match T::AllowedOrigin::try_origin(origin) {
+			Ok(_) => return Ok(()),
+			Err(origin) => ensure_signed(origin)?,
+		};
+
+		ensure!(AssetAdmins::<T>::get(currency_id) == Some(who), DispatchError::BadOrigin);
+		Ok(())
+	}
+
+	fn deposit_slashed(currency_id: CurrencyId, who: &AccountIdOf<T>, amount: BalanceOf<T>) {
+		if !amount.is_zero() {
+			Self::deposit_event(Event::Slashed { currency_id, who: who.clone(), amount });
+		}
+	}
+
+	/// convert an event of this pallet into a plain one, None if the account is not involved
+	pub fn account_event(
+		event: Event<T>,
+		account: &AccountIdOf<T>,
+	) -> Option<CurrencyEvent<AccountIdOf<T>, BalanceOf<T>>> {
+		match event {
+			Event::Transferred { currency_id, from, to, amount }
+				if &from == account || &to == account =>
+				Some(CurrencyEvent::Transferred { currency_id, from, to, amount }),
+			Event::Deposited { currency_id, who, amount } if &who == account =>
+				Some(CurrencyEvent::Deposited { currency_id, who, amount }),
+			Event::Withdrawn { currency_id, who, amount } if &who == account =>
+				Some(CurrencyEvent::Withdrawn { currency_id, who, amount }),
+			Event::Reserved { currency_id, who, amount } if &who == account =>
+				Some(CurrencyEvent::Reserved { currency_id, who, amount }),
+			Event::Unreserved { currency_id, who, amount } if &who == account =>
+				Some(CurrencyEvent::Unreserved { currency_id, who, amount }),
+			Event::Slashed { currency_id, who, amount } if &who == account =>
+				Some(CurrencyEvent::Slashed { currency_id, who, amount }),
+			_ => None,
+		}
+	}
 }
 
 /// ++++++++++++++++++++++++++++++++++++++++
@@ -162,15 +729,36 @@ impl<T: Config> BasicCurrency<AccountIdOf<T>> for Pallet<T> {
 		to: &AccountIdOf<T>,
 		amount: Self::Balance,
 	) -> sp_runtime::DispatchResult {
-		T::MultiCurrency::transfer(T::NativeCurrencyId::get(), from, to, amount)
+		Self::ensure_not_frozen(T::NativeCurrencyId::get(), from)?;
+		T::MultiCurrency::transfer(T::NativeCurrencyId::get(), from, to, amount)?;
+		Self::deposit_event(Event::Transferred {
+			currency_id: T::NativeCurrencyId::get(),
+			from: from.clone(),
+			to: to.clone(),
+			amount,
+		});
+		Ok(())
 	}
 
 	fn deposit(who: &AccountIdOf<T>, amount: Self::Balance) -> sp_runtime::DispatchResult {
-		T::MultiCurrency::deposit(T::NativeCurrencyId::get(), who, amount)
+		T::MultiCurrency::deposit(T::NativeCurrencyId::get(), who, amount)?;
+		Self::deposit_event(Event::Deposited {
+			currency_id: T::NativeCurrencyId::get(),
+			who: who.clone(),
+			amount,
+		});
+		Ok(())
 	}
 
 	fn withdraw(who: &AccountIdOf<T>, amount: Self::Balance) -> sp_runtime::DispatchResult {
-		T::MultiCurrency::withdraw(T::NativeCurrencyId::get(), who, amount)
+		Self::ensure_not_frozen(T::NativeCurrencyId::get(), who)?;
+		T::MultiCurrency::withdraw(T::NativeCurrencyId::get(), who, amount)?;
+		Self::deposit_event(Event::Withdrawn {
+			currency_id: T::NativeCurrencyId::get(),
+			who: who.clone(),
+			amount,
+		});
+		Ok(())
 	}
 
 	fn can_slash(who: &AccountIdOf<T>, value: Self::Balance) -> bool {
@@ -178,7 +766,17 @@ impl<T: Config> BasicCurrency<AccountIdOf<T>> for Pallet<T> {
 	}
 
 	fn slash(who: &AccountIdOf<T>, amount: Self::Balance) -> Self::Balance {
-		T::MultiCurrency::slash(T::NativeCurrencyId::get(), who, amount)
+		let left = T::MultiCurrency::slash(T::NativeCurrencyId::get(), who, amount);
+		Self::deposit_slashed(T::NativeCurrencyId::get(), who, amount.saturating_sub(left));
+		left
+	}
+}
+
+/// only native tokens are covered, since the contract-based tokens held by an account can not be
+/// enumerated
+impl<T: Config> TransferAll<AccountIdOf<T>> for Pallet<T> {
+	fn transfer_all(source: &AccountIdOf<T>, dest: &AccountIdOf<T>) -> DispatchResult {
+		T::MultiCurrency::transfer_all(source, dest)
 	}
 }
 
@@ -219,11 +817,14 @@ impl<T: Config> BasicLockableCurrency<AccountIdOf<T>> for Pallet<T> {
 
 impl<T: Config> BasicReservableCurrency<AccountIdOf<T>> for Pallet<T> {
 	fn can_reserve(who: &AccountIdOf<T>, value: Self::Balance) -> bool {
-		T::MultiCurrency::can_reserve(T::NativeCurrencyId::get(), who, value)
+		!Self::is_frozen(T::NativeCurrencyId::get(), who) &&
+			T::MultiCurrency::can_reserve(T::NativeCurrencyId::get(), who, value)
 	}
 
 	fn slash_reserved(who: &AccountIdOf<T>, value: Self::Balance) -> Self::Balance {
-		T::MultiCurrency::slash_reserved(T::NativeCurrencyId::get(), who, value)
+		let left = T::MultiCurrency::slash_reserved(T::NativeCurrencyId::get(), who, value);
+		Self::deposit_slashed(T::NativeCurrencyId::get(), who, value.saturating_sub(left));
+		left
 	}
 
 	fn reserved_balance(who: &AccountIdOf<T>) -> Self::Balance {
@@ -231,11 +832,24 @@ impl<T: Config> BasicReservableCurrency<AccountIdOf<T>> for Pallet<T> {
 	}
 
 	fn reserve(who: &AccountIdOf<T>, value: Self::Balance) -> sp_runtime::DispatchResult {
-		T::MultiCurrency::reserve(T::NativeCurrencyId::get(), who, value)
+		Self::ensure_not_frozen(T::NativeCurrencyId::get(), who)?;
+		T::MultiCurrency::reserve(T::NativeCurrencyId::get(), who, value)?;
+		Self::deposit_event(Event::Reserved {
+			currency_id: T::NativeCurrencyId::get(),
+			who: who.clone(),
+			amount: value,
+		});
+		Ok(())
 	}
 
 	fn unreserve(who: &AccountIdOf<T>, value: Self::Balance) -> Self::Balance {
-		T::MultiCurrency::unreserve(T::NativeCurrencyId::get(), who, value)
+		let left = T::MultiCurrency::unreserve(T::NativeCurrencyId::get(), who, value);
+		Self::deposit_event(Event::Unreserved {
+			currency_id: T::NativeCurrencyId::get(),
+			who: who.clone(),
+			amount: value.saturating_sub(left),
+		});
+		left
 	}
 
 	fn repatriate_reserved(
@@ -244,6 +858,7 @@ impl<T: Config> BasicReservableCurrency<AccountIdOf<T>> for Pallet<T> {
 		value: Self::Balance,
 		status: orml_traits::BalanceStatus,
 	) -> core::result::Result<Self::Balance, DispatchError> {
+		Self::ensure_not_frozen(T::NativeCurrencyId::get(), slashed)?;
 		T::MultiCurrency::repatriate_reserved(
 			T::NativeCurrencyId::get(),
 			slashed,
@@ -304,18 +919,30 @@ impl<T: Config> fungible::Mutate<AccountIdOf<T>> for Pallet<T> {
 			T::NativeCurrencyId::get(),
 			who,
 			amount,
-		)
+		)?;
+		Self::deposit_event(Event::Deposited {
+			currency_id: T::NativeCurrencyId::get(),
+			who: who.clone(),
+			amount,
+		});
+		Ok(())
 	}
 
 	fn burn_from(
 		who: &AccountIdOf<T>,
 		amount: Self::Balance,
 	) -> Result<Self::Balance, DispatchError> {
-		<T::MultiCurrency as fungibles::Mutate<_>>::burn_from(
+		let burned = <T::MultiCurrency as fungibles::Mutate<_>>::burn_from(
 			T::NativeCurrencyId::get(),
 			who,
 			amount,
-		)
+		)?;
+		Self::deposit_event(Event::Withdrawn {
+			currency_id: T::NativeCurrencyId::get(),
+			who: who.clone(),
+			amount: burned,
+		});
+		Ok(burned)
 	}
 }
 
@@ -326,13 +953,21 @@ impl<T: Config> fungible::Transfer<AccountIdOf<T>> for Pallet<T> {
 		amount: Self::Balance,
 		keep_alive: bool,
 	) -> Result<Self::Balance, DispatchError> {
-		<T::MultiCurrency as fungibles::Transfer<_>>::transfer(
+		Self::ensure_not_frozen(T::NativeCurrencyId::get(), source)?;
+		let transferred = <T::MultiCurrency as fungibles::Transfer<_>>::transfer(
 			T::NativeCurrencyId::get(),
 			source,
 			dest,
 			amount,
 			keep_alive,
-		)
+		)?;
+		Self::deposit_event(Event::Transferred {
+			currency_id: T::NativeCurrencyId::get(),
+			from: source.clone(),
+			to: dest.clone(),
+			amount: transferred,
+		});
+		Ok(transferred)
 	}
 }
 
@@ -376,7 +1011,13 @@ impl<T: Config> fungible::MutateHold<AccountIdOf<T>> for Pallet<T> {
 			T::NativeCurrencyId::get(),
 			who,
 			amount,
-		)
+		)?;
+		Self::deposit_event(Event::Reserved {
+			currency_id: T::NativeCurrencyId::get(),
+			who: who.clone(),
+			amount,
+		});
+		Ok(())
 	}
 
 	fn release(
@@ -384,12 +1025,18 @@ impl<T: Config> fungible::MutateHold<AccountIdOf<T>> for Pallet<T> {
 		amount: Self::Balance,
 		best_effort: bool,
 	) -> Result<Self::Balance, DispatchError> {
-		<T::MultiCurrency as fungibles::MutateHold<_>>::release(
+		let released = <T::MultiCurrency as fungibles::MutateHold<_>>::release(
 			T::NativeCurrencyId::get(),
 			who,
 			amount,
 			best_effort,
-		)
+		)?;
+		Self::deposit_event(Event::Unreserved {
+			currency_id: T::NativeCurrencyId::get(),
+			who: who.clone(),
+			amount: released,
+		});
+		Ok(released)
 	}
 
 	fn transfer_held(
@@ -399,14 +1046,21 @@ impl<T: Config> fungible::MutateHold<AccountIdOf<T>> for Pallet<T> {
 		best_effort: bool,
 		on_held: bool,
 	) -> Result<Self::Balance, DispatchError> {
-		<T::MultiCurrency as fungibles::MutateHold<_>>::transfer_held(
+		let transferred = <T::MultiCurrency as fungibles::MutateHold<_>>::transfer_held(
 			T::NativeCurrencyId::get(),
 			source,
 			dest,
 			amount,
 			best_effort,
 			on_held,
-		)
+		)?;
+		Self::deposit_event(Event::Transferred {
+			currency_id: T::NativeCurrencyId::get(),
+			from: source.clone(),
+			to: dest.clone(),
+			amount: transferred,
+		});
+		Ok(transferred)
 	}
 }
 
@@ -421,7 +1075,10 @@ where
 
 	fn minimum_balance(currency_id: Self::CurrencyId) -> Self::Balance {
 		match currency_id {
-			CurrencyId::Erc20(_) => Default::default(),
+			CurrencyId::Erc20(addr) => {
+				let asset = T::ConvertIntoAccountId::convert(addr);
+				T::ContractAssets::minimum_balance(asset)
+			},
 
 			CurrencyId::NativeToken(_) => <T::MultiCurrency as fungibles::Inspect<
 				AccountIdOf<T>,
@@ -433,7 +1090,7 @@ where
 		match currency_id {
 			CurrencyId::Erc20(addr) => {
 				let asset = T::ConvertIntoAccountId::convert(addr);
-				T::ContractAssets::total_supply(asset).unwrap_or_default()
+				T::ContractAssets::total_supply(asset).result.unwrap_or_default()
 			},
 
 			CurrencyId::NativeToken(_) => <T::MultiCurrency as fungibles::Inspect<
@@ -446,7 +1103,10 @@ where
 		match currency_id {
 			CurrencyId::Erc20(addr) => {
 				let asset = T::ConvertIntoAccountId::convert(addr);
-				T::ContractAssets::balance_of(asset, who.clone()).unwrap_or_default()
+				T::ContractAssets::balance_of(asset, who.clone())
+					.result
+					.unwrap_or_default()
+					.saturating_add(ContractReserves::<T>::get(currency_id, who))
 			},
 
 			CurrencyId::NativeToken(_) => <T::MultiCurrency as fungibles::Inspect<
@@ -460,7 +1120,7 @@ where
 			CurrencyId::NativeToken(_) => T::MultiCurrency::free_balance(currency_id, who),
 			CurrencyId::Erc20(addr) => {
 				let asset = T::ConvertIntoAccountId::convert(addr);
-				T::ContractAssets::balance_of(asset, who.clone()).unwrap_or_default()
+				T::ContractAssets::balance_of(asset, who.clone()).result.unwrap_or_default()
 			},
 		}
 	}
@@ -470,26 +1130,7 @@ where
 		who: &AccountIdOf<T>,
 		amount: Self::Balance,
 	) -> sp_runtime::DispatchResult {
-		if amount.is_zero() {
-			return Ok(())
-		}
-
-		match currency_id {
-			CurrencyId::NativeToken(_) =>
-				T::MultiCurrency::ensure_can_withdraw(currency_id, who, amount),
-			CurrencyId::Erc20(addr) => {
-				// handle zero withdrawl
-				if amount.is_zero() {
-					return Ok(())
-				}
-
-				let asset = T::ConvertIntoAccountId::convert(addr);
-				let balance = T::ContractAssets::balance_of(asset, who.clone()).unwrap_or_default();
-
-				ensure!(balance >= amount, Error::<T>::BalanceTooLow);
-				Ok(())
-			},
-		}
+		Self::do_ensure_can_withdraw(currency_id, who, amount, &mut 0)
 	}
 
 	fn transfer(
@@ -498,19 +1139,7 @@ where
 		to: &AccountIdOf<T>,
 		amount: Self::Balance,
 	) -> sp_runtime::DispatchResult {
-		match currency_id {
-			CurrencyId::NativeToken(_) => T::MultiCurrency::transfer(currency_id, from, to, amount),
-			CurrencyId::Erc20(addr) => {
-				if amount.is_zero() {
-					return Ok(())
-				}
-				let asset = T::ConvertIntoAccountId::convert(addr);
-
-				T::ContractAssets::transfer(asset, from.clone(), to.clone(), amount.into())
-					.map(|_| ())
-					.map_err(|err| err.error)
-			},
-		}
+		Self::do_transfer(currency_id, from, to, amount, &mut 0)
 	}
 
 	fn deposit(
@@ -522,9 +1151,19 @@ where
 			return Ok(())
 		}
 		match currency_id {
-			CurrencyId::NativeToken(_) => T::MultiCurrency::deposit(currency_id, who, amount),
-			CurrencyId::Erc20(_) => Err(Error::<T>::InvalidContractOperation.into()),
+			CurrencyId::NativeToken(_) => T::MultiCurrency::deposit(currency_id, who, amount)?,
+			CurrencyId::Erc20(addr) => {
+				let asset = T::ConvertIntoAccountId::convert(addr);
+
+				Self::contract_access(
+					T::ContractAssets::mint(asset, who.clone(), amount.into()),
+					&mut 0,
+				)?;
+			},
 		}
+		Self::deposit_event(Event::Deposited { currency_id, who: who.clone(), amount });
+
+		Ok(())
 	}
 
 	fn withdraw(
@@ -535,10 +1174,22 @@ where
 		if amount.is_zero() {
 			return Ok(())
 		}
+		Self::ensure_not_frozen(currency_id, who)?;
 		match currency_id {
-			CurrencyId::NativeToken(_) => T::MultiCurrency::withdraw(currency_id, who, amount),
-			CurrencyId::Erc20(_) => Err(Error::<T>::InvalidContractOperation.into()),
+			CurrencyId::NativeToken(_) => T::MultiCurrency::withdraw(currency_id, who, amount)?,
+			CurrencyId::Erc20(addr) => {
+				<Self as MultiCurrency<_>>::ensure_can_withdraw(currency_id, who, amount)?;
+				let asset = T::ConvertIntoAccountId::convert(addr);
+
+				Self::contract_access(
+					T::ContractAssets::burn(asset, who.clone(), amount.into()),
+					&mut 0,
+				)?;
+			},
 		}
+		Self::deposit_event(Event::Withdrawn { currency_id, who: who.clone(), amount });
+
+		Ok(())
 	}
 
 	fn can_slash(
@@ -557,10 +1208,14 @@ where
 		who: &AccountIdOf<T>,
 		amount: Self::Balance,
 	) -> Self::Balance {
-		match currency_id {
+		let left = match currency_id {
 			CurrencyId::NativeToken(_) => T::MultiCurrency::slash(currency_id, who, amount),
-			CurrencyId::Erc20(_) => Default::default(),
-		}
+			// the free balance of contract-based assets can't be slashed
+			CurrencyId::Erc20(_) => amount,
+		};
+		Self::deposit_slashed(currency_id, who, amount.saturating_sub(left));
+
+		left
 	}
 }
 
@@ -600,7 +1255,7 @@ where
 			CurrencyId::NativeToken(_) => <T::MultiCurrency as MultiLockableCurrency<
 				AccountIdOf<T>,
 			>>::set_lock(lock_id, currency_id, who, amount),
-			CurrencyId::Erc20(_) => Err(Error::<T>::InvalidContractOperation.into()),
+			CurrencyId::Erc20(_) => Self::set_contract_lock(lock_id, currency_id, who, amount),
 		}
 	}
 
@@ -614,7 +1269,7 @@ where
 			CurrencyId::NativeToken(_) => <T::MultiCurrency as MultiLockableCurrency<
 				AccountIdOf<T>,
 			>>::extend_lock(lock_id, currency_id, who, amount),
-			CurrencyId::Erc20(_) => Err(Error::<T>::InvalidContractOperation.into()),
+			CurrencyId::Erc20(_) => Self::extend_contract_lock(lock_id, currency_id, who, amount),
 		}
 	}
 
@@ -627,7 +1282,10 @@ where
 			CurrencyId::NativeToken(_) => <T::MultiCurrency as MultiLockableCurrency<
 				AccountIdOf<T>,
 			>>::remove_lock(lock_id, currency_id, who),
-			CurrencyId::Erc20(_) => Err(Error::<T>::InvalidContractOperation.into()),
+			CurrencyId::Erc20(_) => {
+				Self::remove_contract_lock(lock_id, currency_id, who);
+				Ok(())
+			},
 		}
 	}
 }
@@ -641,11 +1299,15 @@ where
 		who: &AccountIdOf<T>,
 		value: Self::Balance,
 	) -> bool {
+		if Self::is_frozen(currency_id, who) {
+			return false
+		}
 		match currency_id {
 			CurrencyId::NativeToken(_) => <T::MultiCurrency as MultiReservableCurrency<
 				AccountIdOf<T>,
 			>>::can_reserve(currency_id, who, value),
-			CurrencyId::Erc20(_) => false,
+			CurrencyId::Erc20(_) =>
+				<Self as MultiCurrency<_>>::ensure_can_withdraw(currency_id, who, value).is_ok(),
 		}
 	}
 
@@ -654,12 +1316,16 @@ where
 		who: &AccountIdOf<T>,
 		value: Self::Balance,
 	) -> Self::Balance {
-		match currency_id {
+		let left = match currency_id {
 			CurrencyId::NativeToken(_) => <T::MultiCurrency as MultiReservableCurrency<
 				AccountIdOf<T>,
 			>>::slash_reserved(currency_id, who, value),
-			CurrencyId::Erc20(_) => Default::default(),
-		}
+			CurrencyId::Erc20(addr) =>
+				Self::slash_reserved_contract_asset(currency_id, addr, who, value),
+		};
+		Self::deposit_slashed(currency_id, who, value.saturating_sub(left));
+
+		left
 	}
 
 	fn reserved_balance(currency_id: Self::CurrencyId, who: &AccountIdOf<T>) -> Self::Balance {
@@ -667,7 +1333,7 @@ where
 			CurrencyId::NativeToken(_) => <T::MultiCurrency as MultiReservableCurrency<
 				AccountIdOf<T>,
 			>>::reserved_balance(currency_id, who),
-			CurrencyId::Erc20(_) => Default::default(),
+			CurrencyId::Erc20(_) => ContractReserves::<T>::get(currency_id, who),
 		}
 	}
 
@@ -676,12 +1342,19 @@ where
 		who: &AccountIdOf<T>,
 		value: Self::Balance,
 	) -> sp_runtime::DispatchResult {
+		if value.is_zero() {
+			return Ok(())
+		}
+		Self::ensure_not_frozen(currency_id, who)?;
 		match currency_id {
 			CurrencyId::NativeToken(_) => <T::MultiCurrency as MultiReservableCurrency<
 				AccountIdOf<T>,
-			>>::reserve(currency_id, who, value),
-			CurrencyId::Erc20(_) => Err(Error::<T>::InvalidContractOperation.into()),
+			>>::reserve(currency_id, who, value)?,
+			CurrencyId::Erc20(addr) => Self::reserve_contract_asset(currency_id, addr, who, value)?,
 		}
+		Self::deposit_event(Event::Reserved { currency_id, who: who.clone(), amount: value });
+
+		Ok(())
 	}
 
 	fn unreserve(
@@ -689,15 +1362,23 @@ where
 		who: &AccountIdOf<T>,
 		value: Self::Balance,
 	) -> Self::Balance {
-		match currency_id {
+		if value.is_zero() {
+			return value
+		}
+		let left = match currency_id {
 			CurrencyId::NativeToken(_) => <T::MultiCurrency as MultiReservableCurrency<
 				AccountIdOf<T>,
 			>>::unreserve(currency_id, who, value),
-			CurrencyId::Erc20(_) => {
-				log::debug!("not amount will be unreserved for contract based assets");
-				Default::default()
-			},
-		}
+			CurrencyId::Erc20(addr) =>
+				Self::unreserve_contract_asset(currency_id, addr, who, value),
+		};
+		Self::deposit_event(Event::Unreserved {
+			currency_id,
+			who: who.clone(),
+			amount: value.saturating_sub(left),
+		});
+
+		left
 	}
 
 	fn repatriate_reserved(
@@ -707,14 +1388,30 @@ where
 		value: Self::Balance,
 		status: orml_traits::BalanceStatus,
 	) -> core::result::Result<Self::Balance, DispatchError> {
-		match currency_id {
+		Self::ensure_not_frozen(currency_id, slashed)?;
+		let left = match currency_id {
 			CurrencyId::NativeToken(_) => <T::MultiCurrency as MultiReservableCurrency<
 				AccountIdOf<T>,
 			>>::repatriate_reserved(
 				currency_id, slashed, beneficiary, value, status
-			),
-			CurrencyId::Erc20(_) => Err(Error::<T>::InvalidContractOperation.into()),
-		}
+			)?,
+			CurrencyId::Erc20(addr) => Self::repatriate_reserved_contract_asset(
+				currency_id,
+				addr,
+				slashed,
+				beneficiary,
+				value,
+				status,
+			)?,
+		};
+		Self::deposit_event(Event::Transferred {
+			currency_id,
+			from: slashed.clone(),
+			to: beneficiary.clone(),
+			amount: value.saturating_sub(left),
+		});
+
+		Ok(left)
 	}
 }
 
@@ -755,7 +1452,8 @@ where
 	) -> Self::Balance {
 		match asset {
 			CurrencyId::Erc20(_) =>
-				<Self as MultiCurrency<AccountIdOf<T>>>::free_balance(asset, who),
+				<Self as MultiCurrency<AccountIdOf<T>>>::free_balance(asset, who)
+					.saturating_sub(Self::contract_frozen_balance(asset, who)),
 			_ => <T::MultiCurrency as fungibles::Inspect<AccountIdOf<T>>>::reducible_balance(
 				asset, who, keep_alive,
 			),
@@ -823,10 +1521,7 @@ where
 		who: &AccountIdOf<T>,
 		amount: Self::Balance,
 	) -> DispatchResult {
-		match asset {
-			CurrencyId::Erc20(_) => <Self as MultiCurrency<_>>::deposit(asset, who, amount),
-			_ => <T::MultiCurrency as MultiCurrency<_>>::deposit(asset, who, amount),
-		}
+		<Self as MultiCurrency<_>>::deposit(asset, who, amount)
 	}
 
 	fn burn_from(
@@ -837,7 +1532,16 @@ where
 		match asset {
 			CurrencyId::Erc20(_) =>
 				<Self as MultiCurrency<_>>::withdraw(asset, who, amount).map(|_| amount),
-			_ => <T::MultiCurrency as fungibles::Mutate<_>>::burn_from(asset, who, amount),
+			_ => {
+				let burned =
+					<T::MultiCurrency as fungibles::Mutate<_>>::burn_from(asset, who, amount)?;
+				Self::deposit_event(Event::Withdrawn {
+					currency_id: asset,
+					who: who.clone(),
+					amount: burned,
+				});
+				Ok(burned)
+			},
 		}
 	}
 }
@@ -860,9 +1564,19 @@ where
 		match asset {
 			CurrencyId::Erc20(_) =>
 				<Self as MultiCurrency<_>>::transfer(asset, source, dest, amount).map(|_| amount),
-			_ => <T::MultiCurrency as fungibles::Transfer<_>>::transfer(
-				asset, source, dest, amount, keep_alive,
-			),
+			_ => {
+				Self::ensure_not_frozen(asset, source)?;
+				let transferred = <T::MultiCurrency as fungibles::Transfer<_>>::transfer(
+					asset, source, dest, amount, keep_alive,
+				)?;
+				Self::deposit_event(Event::Transferred {
+					currency_id: asset,
+					from: source.clone(),
+					to: dest.clone(),
+					amount: transferred,
+				});
+				Ok(transferred)
+			},
 		}
 	}
 }
@@ -896,8 +1610,17 @@ where
 {
 	fn hold(asset: Self::AssetId, who: &AccountIdOf<T>, amount: Self::Balance) -> DispatchResult {
 		match asset {
-			CurrencyId::Erc20(_) => Err(Error::<T>::InvalidContractOperation.into()),
-			_ => <T::MultiCurrency as fungibles::MutateHold<_>>::hold(asset, who, amount),
+			CurrencyId::Erc20(_) =>
+				<Self as MultiReservableCurrency<_>>::reserve(asset, who, amount),
+			_ => {
+				<T::MultiCurrency as fungibles::MutateHold<_>>::hold(asset, who, amount)?;
+				Self::deposit_event(Event::Reserved {
+					currency_id: asset,
+					who: who.clone(),
+					amount,
+				});
+				Ok(())
+			},
 		}
 	}
 
@@ -908,13 +1631,30 @@ where
 		best_effort: bool,
 	) -> Result<Self::Balance, DispatchError> {
 		match asset {
-			CurrencyId::Erc20(_) => Err(Error::<T>::InvalidContractOperation.into()),
-			_ => <T::MultiCurrency as fungibles::MutateHold<_>>::release(
-				asset,
-				who,
-				amount,
-				best_effort,
-			),
+			CurrencyId::Erc20(_) => {
+				if !best_effort {
+					ensure!(
+						ContractReserves::<T>::get(asset, who) >= amount,
+						Error::<T>::ReserveTooLow
+					);
+				}
+				let left = <Self as MultiReservableCurrency<_>>::unreserve(asset, who, amount);
+				Ok(amount.saturating_sub(left))
+			},
+			_ => {
+				let released = <T::MultiCurrency as fungibles::MutateHold<_>>::release(
+					asset,
+					who,
+					amount,
+					best_effort,
+				)?;
+				Self::deposit_event(Event::Unreserved {
+					currency_id: asset,
+					who: who.clone(),
+					amount: released,
+				});
+				Ok(released)
+			},
 		}
 	}
 
@@ -927,15 +1667,40 @@ where
 		on_hold: bool,
 	) -> Result<Self::Balance, DispatchError> {
 		match asset {
-			CurrencyId::Erc20(_) => Err(Error::<T>::InvalidContractOperation.into()),
-			_ => <T::MultiCurrency as fungibles::MutateHold<_>>::transfer_held(
-				asset,
-				source,
-				dest,
-				amount,
-				best_effort,
-				on_hold,
-			),
+			CurrencyId::Erc20(_) => {
+				if !best_effort {
+					ensure!(
+						ContractReserves::<T>::get(asset, source) >= amount,
+						Error::<T>::ReserveTooLow
+					);
+				}
+				let status = if on_hold {
+					orml_traits::BalanceStatus::Reserved
+				} else {
+					orml_traits::BalanceStatus::Free
+				};
+				let left = <Self as MultiReservableCurrency<_>>::repatriate_reserved(
+					asset, source, dest, amount, status,
+				)?;
+				Ok(amount.saturating_sub(left))
+			},
+			_ => {
+				let transferred = <T::MultiCurrency as fungibles::MutateHold<_>>::transfer_held(
+					asset,
+					source,
+					dest,
+					amount,
+					best_effort,
+					on_hold,
+				)?;
+				Self::deposit_event(Event::Transferred {
+					currency_id: asset,
+					from: source.clone(),
+					to: dest.clone(),
+					amount: transferred,
+				});
+				Ok(transferred)
+			},
 		}
 	}
 }
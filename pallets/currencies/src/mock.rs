@@ -169,16 +169,28 @@ impl pallet_contracts::Config for Runtime {
 parameter_types! {
 	pub const PId: PalletId = PalletId(*b"tkn/reg_");
 	pub const MaxGas: u64 = 200_000_000_000;
+	pub const MaxListedTokens: u32 = 3;
 	pub const DebugFlag: bool = true;
+	pub const RegistrationDeposit: Balance = 1000;
+	pub const ChallengePeriod: BlockNumber = 10;
 }
 
 impl pallet_contract_asset_registry::Config for Runtime {
+	type Event = Event;
 	type AllowedOrigin = EnsureRoot<AccountId>;
 
+	type RegistrationDeposit = RegistrationDeposit;
+
+	type ChallengePeriod = ChallengePeriod;
+
+	type OnSlash = ();
+
 	type PalletId = PId;
 
 	type MaxGas = MaxGas;
 
+	type MaxListedTokens = MaxListedTokens;
+
 	type ContractDebugFlag = DebugFlag;
 
 	type WeightInfo = ();
@@ -225,6 +237,10 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub const NativeCurrencyId: CurrencyId = CurrencyId::NativeToken(TokenId::Laguna);
+	pub const CurrenciesPalletId: PalletId = PalletId(*b"cur/escr");
+	pub const MaxBatchTransfers: u32 = 4;
+	pub const MaxBatchContractTransfers: u32 = 2;
+	pub const SlashReceiver: AccountId = TREASURY;
 }
 
 impl Config for Runtime {
@@ -234,6 +250,14 @@ impl Config for Runtime {
 	type MultiCurrency = Tokens;
 	type ContractAssets = ContractTokenRegistry;
 	type ConvertIntoAccountId = AccountConvert;
+	type PalletId = CurrenciesPalletId;
+	type SlashReceiver = SlashReceiver;
+	type MaxLocks = ConstU32<2>;
+	type MaxBatchTransfers = MaxBatchTransfers;
+	type MaxBatchContractTransfers = MaxBatchContractTransfers;
+	type AllowedOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+	type Event = Event;
 }
 
 pub struct AccountConvert;
@@ -265,6 +289,8 @@ construct_runtime!(
 
 pub const ALICE: AccountId = AccountId::new([1u8; 32]);
 pub const BOB: AccountId = AccountId::new([2u8; 32]);
+pub const EVE: AccountId = AccountId::new([3u8; 32]);
+pub const TREASURY: AccountId = AccountId::new([4u8; 32]);
 
 #[derive(Default)]
 pub struct ExtBuilder {
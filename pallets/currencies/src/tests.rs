@@ -1,12 +1,15 @@
 use crate::{adapters::CurrencyAdapter, mock::*, AccountIdOf};
 use codec::Encode;
 use frame_support::{
-	assert_err, assert_ok, parameter_types,
+	assert_err, assert_noop, assert_ok, parameter_types,
+	sp_runtime::DispatchError,
 	traits::{fungible, fungibles},
+	weights::GetDispatchInfo,
+	BoundedVec,
 };
 
-use orml_traits::MultiCurrency;
-use primitives::{AccountId, CurrencyId, TokenId};
+use orml_traits::{BalanceStatus, MultiCurrency, MultiLockableCurrency, MultiReservableCurrency};
+use primitives::{AccountId, CurrencyEvent, CurrencyId, TokenId};
 use sp_core::{Bytes, U256};
 use std::{str::FromStr, sync::Mutex};
 use traits::currencies::TokenAccess;
@@ -54,9 +57,15 @@ where
 				None
 			}
 		})
-		.expect("unable to find deployed contract");
+		.expect("unable to find deployed contract")
+		.clone();
 
-	deployed.clone()
+	// the token code is approved by governance before it can be registered
+	let code_hash =
+		pallet_contracts::Pallet::<Runtime>::code_hash(&deployed).expect("contract should exist");
+	assert_ok!(ContractTokenRegistry::approve_code_hash(Origin::root(), code_hash));
+
+	deployed
 }
 
 #[test]
@@ -99,10 +108,12 @@ fn test_total_supply_erc20() {
 			assert_ok!(ContractTokenRegistry::register_asset(
 				Origin::root(),
 				deployed.clone(),
-				true
+				true,
+				false,
+				None
 			));
 
-			assert_eq!(ContractTokenRegistry::balance_of(deployed.clone(), ALICE), Some(UNIT));
+			assert_eq!(ContractTokenRegistry::balance_of(deployed.clone(), ALICE).result, Ok(UNIT));
 			assert_eq!(
 				<Currencies as MultiCurrency<AccountIdOf<Runtime>>>::free_balance(cid, &ALICE),
 				UNIT
@@ -110,20 +121,20 @@ fn test_total_supply_erc20() {
 
 			assert_err!(
 				<Currencies as MultiCurrency<AccountIdOf<Runtime>>>::deposit(cid, &ALICE, UNIT),
-				crate::Error::<Runtime>::InvalidContractOperation
+				pallet_contract_asset_registry::Error::<Runtime>::NotMintable
 			);
 
 			assert_err!(
 				<Currencies as MultiCurrency<AccountIdOf<Runtime>>>::withdraw(cid, &ALICE, UNIT),
-				crate::Error::<Runtime>::InvalidContractOperation
+				pallet_contract_asset_registry::Error::<Runtime>::NotMintable
 			);
 
 			assert_ok!(<Currencies as MultiCurrency<AccountIdOf<Runtime>>>::transfer(
 				cid, &ALICE, &BOB, UNIT
 			),);
 
-			assert_eq!(ContractTokenRegistry::balance_of(deployed.clone(), ALICE), Some(0));
-			assert_eq!(ContractTokenRegistry::balance_of(deployed, BOB), Some(UNIT));
+			assert_eq!(ContractTokenRegistry::balance_of(deployed.clone(), ALICE).result, Ok(0));
+			assert_eq!(ContractTokenRegistry::balance_of(deployed, BOB).result, Ok(UNIT));
 		});
 }
 
@@ -373,7 +384,9 @@ fn test_adapter_erc20() {
 			assert_ok!(ContractTokenRegistry::register_asset(
 				Origin::root(),
 				deployed.clone(),
-				true
+				true,
+				false,
+				None
 			));
 			let cid = CurrencyId::Erc20(*deployed.as_ref());
 
@@ -402,3 +415,589 @@ fn test_adapter_erc20() {
 			assert_eq!(<NativeTokenAdapter as fungible::Inspect<_>>::balance(&BOB), 1000);
 		});
 }
+
+#[test]
+fn test_reserve_erc20() {
+	let init_amount = UNIT;
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, NativeCurrencyId::get(), init_amount),
+			(BOB, NativeCurrencyId::get(), init_amount),
+		])
+		.build()
+		.execute_with(|| {
+			let deployed = create_token(ALICE, "ABC", "ABC", UNIT);
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+			let cid = CurrencyId::Erc20(*deployed.as_ref());
+			let escrow = Currencies::escrow_account();
+
+			assert!(<Currencies as MultiReservableCurrency<_>>::can_reserve(cid, &ALICE, 1000));
+			assert_ok!(<Currencies as MultiReservableCurrency<_>>::reserve(cid, &ALICE, 1000));
+
+			assert_eq!(
+				ContractTokenRegistry::balance_of(deployed.clone(), escrow.clone()).result,
+				Ok(1000)
+			);
+			assert_eq!(
+				<Currencies as MultiReservableCurrency<_>>::reserved_balance(cid, &ALICE),
+				1000
+			);
+			assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(cid, &ALICE), UNIT - 1000);
+			assert_eq!(<Currencies as MultiCurrency<_>>::total_balance(cid, &ALICE), UNIT);
+
+			assert_eq!(<Currencies as MultiReservableCurrency<_>>::unreserve(cid, &ALICE, 400), 0);
+			assert_eq!(
+				<Currencies as MultiReservableCurrency<_>>::reserved_balance(cid, &ALICE),
+				600
+			);
+			assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(cid, &ALICE), UNIT - 600);
+
+			// only the reserved amount is moved
+			assert_eq!(
+				<Currencies as MultiReservableCurrency<_>>::repatriate_reserved(
+					cid,
+					&ALICE,
+					&BOB,
+					1000,
+					BalanceStatus::Free
+				),
+				Ok(400)
+			);
+			assert_eq!(
+				<Currencies as MultiReservableCurrency<_>>::reserved_balance(cid, &ALICE),
+				0
+			);
+			assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(cid, &BOB), 600);
+			assert_eq!(ContractTokenRegistry::balance_of(deployed, escrow).result, Ok(0));
+		});
+}
+
+#[test]
+fn test_hold_erc20() {
+	let init_amount = UNIT;
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, NativeCurrencyId::get(), init_amount),
+			(BOB, NativeCurrencyId::get(), init_amount),
+		])
+		.build()
+		.execute_with(|| {
+			let deployed = create_token(ALICE, "ABC", "ABC", UNIT);
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+			let cid = CurrencyId::Erc20(*deployed.as_ref());
+
+			assert_ok!(<Currencies as fungibles::MutateHold<_>>::hold(cid, &ALICE, 1000));
+			assert_eq!(
+				<Currencies as fungibles::InspectHold<_>>::balance_on_hold(cid, &ALICE),
+				1000
+			);
+
+			assert_err!(
+				<Currencies as fungibles::MutateHold<_>>::release(cid, &ALICE, 2000, false),
+				crate::Error::<Runtime>::ReserveTooLow
+			);
+
+			assert_eq!(
+				<Currencies as fungibles::MutateHold<_>>::transfer_held(
+					cid, &ALICE, &BOB, 300, false, true
+				),
+				Ok(300)
+			);
+			assert_eq!(<Currencies as fungibles::InspectHold<_>>::balance_on_hold(cid, &BOB), 300);
+			assert_eq!(
+				<Currencies as fungibles::InspectHold<_>>::balance_on_hold(cid, &ALICE),
+				700
+			);
+
+			assert_eq!(
+				<Currencies as fungibles::MutateHold<_>>::release(cid, &ALICE, 2000, true),
+				Ok(700)
+			);
+			assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(cid, &ALICE), UNIT - 300);
+
+			// slashed amount is moved out of escrow
+			assert_eq!(
+				<Currencies as MultiReservableCurrency<_>>::slash_reserved(cid, &BOB, 500),
+				200
+			);
+			assert_eq!(<Currencies as MultiCurrency<_>>::total_balance(cid, &BOB), 0);
+			assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(cid, &TREASURY), 300);
+			assert_eq!(
+				ContractTokenRegistry::balance_of(deployed, Currencies::escrow_account()).result,
+				Ok(0)
+			);
+		});
+}
+
+#[test]
+fn test_lock_erc20() {
+	let init_amount = UNIT;
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, NativeCurrencyId::get(), init_amount),
+			(BOB, NativeCurrencyId::get(), init_amount),
+		])
+		.build()
+		.execute_with(|| {
+			let deployed = create_token(ALICE, "ABC", "ABC", UNIT);
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+			let cid = CurrencyId::Erc20(*deployed.as_ref());
+
+			assert_ok!(<Currencies as MultiLockableCurrency<_>>::set_lock(
+				*b"lock____",
+				cid,
+				&ALICE,
+				UNIT - 1000
+			));
+			assert_ok!(<Currencies as MultiLockableCurrency<_>>::extend_lock(
+				*b"lock____",
+				cid,
+				&ALICE,
+				10
+			));
+			assert_eq!(Currencies::contract_frozen_balance(cid, &ALICE), UNIT - 1000);
+
+			assert_ok!(<Currencies as MultiLockableCurrency<_>>::set_lock(
+				*b"other___",
+				cid,
+				&ALICE,
+				10
+			));
+			assert_err!(
+				<Currencies as MultiLockableCurrency<_>>::set_lock(*b"third___", cid, &ALICE, 10),
+				crate::Error::<Runtime>::MaxLocksExceeded
+			);
+
+			assert_err!(
+				<Currencies as MultiCurrency<_>>::transfer(cid, &ALICE, &BOB, 1001),
+				crate::Error::<Runtime>::LiquidityRestrictions
+			);
+			assert!(!<Currencies as MultiReservableCurrency<_>>::can_reserve(cid, &ALICE, 1001));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(cid, &ALICE, &BOB, 1000));
+
+			assert_ok!(<Currencies as MultiLockableCurrency<_>>::remove_lock(
+				*b"lock____",
+				cid,
+				&ALICE
+			));
+			assert_ok!(<Currencies as MultiLockableCurrency<_>>::remove_lock(
+				*b"other___",
+				cid,
+				&ALICE
+			));
+			assert_eq!(Currencies::contract_locks(cid, &ALICE).len(), 0);
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(cid, &ALICE, &BOB, 1000));
+		});
+}
+
+#[test]
+fn test_transfer_keep_alive_and_all() {
+	let init_amount = UNIT;
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, NativeCurrencyId::get(), init_amount),
+			(BOB, NativeCurrencyId::get(), init_amount),
+		])
+		.build()
+		.execute_with(|| {
+			let native = NativeCurrencyId::get();
+
+			assert_ok!(Currencies::transfer_keep_alive(Origin::signed(ALICE), BOB, native, 1000));
+			assert_eq!(Currencies::free_balance(ALICE, native), UNIT - 1000);
+			assert_eq!(Currencies::free_balance(BOB, native), UNIT + 1000);
+
+			assert_ok!(Currencies::transfer_all(Origin::signed(ALICE), BOB, native, false));
+			assert_eq!(Currencies::free_balance(ALICE, native), 0);
+			assert_eq!(Currencies::free_balance(BOB, native), 2 * UNIT);
+
+			let deployed = create_token(BOB, "ABC", "ABC", UNIT);
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+			let cid = CurrencyId::Erc20(*deployed.as_ref());
+
+			assert_ok!(Currencies::transfer_keep_alive(Origin::signed(BOB), ALICE, cid, 1000));
+			assert_eq!(Currencies::free_balance(ALICE, cid), 1000);
+
+			assert_ok!(Currencies::transfer_all(Origin::signed(BOB), ALICE, cid, true));
+			assert_eq!(Currencies::free_balance(ALICE, cid), UNIT);
+			assert_eq!(Currencies::free_balance(BOB, cid), 0);
+		});
+}
+
+#[test]
+fn test_transfer_batch() {
+	let init_amount = UNIT;
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, NativeCurrencyId::get(), init_amount),
+			(BOB, NativeCurrencyId::get(), init_amount),
+		])
+		.build()
+		.execute_with(|| {
+			let native = NativeCurrencyId::get();
+			let deployed = create_token(ALICE, "ABC", "ABC", UNIT);
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+			let cid = CurrencyId::Erc20(*deployed.as_ref());
+
+			let transfers: BoundedVec<_, MaxBatchTransfers> =
+				vec![(BOB, native, 1000), (BOB, cid, 2000), (EVE, cid, 3000)]
+					.try_into()
+					.unwrap();
+			assert_ok!(Currencies::transfer_batch(Origin::signed(ALICE), transfers));
+
+			assert_eq!(Currencies::free_balance(BOB, native), UNIT + 1000);
+			assert_eq!(Currencies::free_balance(BOB, cid), 2000);
+			assert_eq!(Currencies::free_balance(EVE, cid), 3000);
+			assert_eq!(Currencies::free_balance(ALICE, cid), UNIT - 5000);
+
+			// the whole batch is reverted if any of the transfers fails
+			let transfers: BoundedVec<_, MaxBatchTransfers> =
+				vec![(BOB, cid, 1000), (EVE, native, 2 * UNIT)].try_into().unwrap();
+			let err = Currencies::transfer_batch(Origin::signed(ALICE), transfers).unwrap_err();
+			assert_eq!(err.error, orml_tokens::Error::<Runtime>::BalanceTooLow.into());
+			// the weight consumed by the token contract is still charged
+			assert!(err.post_info.actual_weight.is_some());
+			assert_eq!(Currencies::free_balance(BOB, cid), 2000);
+
+			// contract-based transfers are bounded on their own
+			let transfers: BoundedVec<_, MaxBatchTransfers> =
+				vec![(BOB, cid, 1000), (EVE, cid, 1000), (BOB, native, 1000), (EVE, cid, 1000)]
+					.try_into()
+					.unwrap();
+			assert_noop!(
+				Currencies::transfer_batch(Origin::signed(ALICE), transfers),
+				crate::Error::<Runtime>::TooManyContractTransfers
+			);
+		});
+}
+
+#[test]
+fn test_force_transfer() {
+	let init_amount = UNIT;
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, NativeCurrencyId::get(), init_amount),
+			(BOB, NativeCurrencyId::get(), init_amount),
+		])
+		.build()
+		.execute_with(|| {
+			let native = NativeCurrencyId::get();
+
+			assert_noop!(
+				Currencies::force_transfer(Origin::signed(ALICE), BOB, ALICE, native, 1000),
+				DispatchError::BadOrigin
+			);
+
+			assert_ok!(Currencies::force_transfer(Origin::root(), BOB, ALICE, native, 1000));
+			assert_eq!(Currencies::free_balance(ALICE, native), UNIT + 1000);
+			assert_eq!(Currencies::free_balance(BOB, native), UNIT - 1000);
+		});
+}
+
+#[test]
+fn test_unified_events() {
+	let init_amount = UNIT;
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, NativeCurrencyId::get(), init_amount),
+			(BOB, NativeCurrencyId::get(), init_amount),
+		])
+		.build()
+		.execute_with(|| {
+			let native = NativeCurrencyId::get();
+			let deployed = create_token(ALICE, "ABC", "ABC", UNIT);
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+			let cid = CurrencyId::Erc20(*deployed.as_ref());
+
+			assert_ok!(Currencies::transfer(Origin::signed(ALICE), BOB, native, 1000));
+			System::assert_last_event(Event::Currencies(crate::Event::Transferred {
+				currency_id: native,
+				from: ALICE,
+				to: BOB,
+				amount: 1000,
+			}));
+
+			assert_ok!(Currencies::transfer(Origin::signed(ALICE), BOB, cid, 2000));
+			System::assert_last_event(Event::Currencies(crate::Event::Transferred {
+				currency_id: cid,
+				from: ALICE,
+				to: BOB,
+				amount: 2000,
+			}));
+
+			assert_ok!(<Currencies as MultiReservableCurrency<_>>::reserve(cid, &BOB, 500));
+			System::assert_last_event(Event::Currencies(crate::Event::Reserved {
+				currency_id: cid,
+				who: BOB,
+				amount: 500,
+			}));
+
+			assert_ok!(<Currencies as MultiCurrency<_>>::deposit(native, &EVE, 300));
+			System::assert_last_event(Event::Currencies(crate::Event::Deposited {
+				currency_id: native,
+				who: EVE,
+				amount: 300,
+			}));
+
+			assert_eq!(
+				<Currencies as MultiReservableCurrency<_>>::slash_reserved(cid, &BOB, 100),
+				0
+			);
+			System::assert_last_event(Event::Currencies(crate::Event::Slashed {
+				currency_id: cid,
+				who: BOB,
+				amount: 100,
+			}));
+
+			// the free balance of contract-based assets is left untouched
+			assert_eq!(<Currencies as MultiCurrency<_>>::slash(cid, &BOB, 100), 100);
+			assert_eq!(<Currencies as MultiCurrency<_>>::slash(native, &BOB, 100), 0);
+			System::assert_last_event(Event::Currencies(crate::Event::Slashed {
+				currency_id: native,
+				who: BOB,
+				amount: 100,
+			}));
+
+			let account_events = |who: AccountId| {
+				System::events()
+					.into_iter()
+					.filter_map(|record| match record.event {
+						Event::Currencies(event) => Currencies::account_event(event, &who),
+						_ => None,
+					})
+					.collect::<Vec<_>>()
+			};
+
+			assert_eq!(
+				account_events(BOB),
+				vec![
+					CurrencyEvent::Transferred {
+						currency_id: native,
+						from: ALICE,
+						to: BOB,
+						amount: 1000
+					},
+					CurrencyEvent::Transferred {
+						currency_id: cid,
+						from: ALICE,
+						to: BOB,
+						amount: 2000
+					},
+					CurrencyEvent::Reserved { currency_id: cid, who: BOB, amount: 500 },
+					CurrencyEvent::Slashed { currency_id: cid, who: BOB, amount: 100 },
+					CurrencyEvent::Slashed { currency_id: native, who: BOB, amount: 100 },
+				]
+			);
+			assert_eq!(
+				account_events(EVE),
+				vec![CurrencyEvent::Deposited { currency_id: native, who: EVE, amount: 300 }]
+			);
+		});
+}
+
+#[test]
+fn test_freeze_currency_and_account() {
+	let init_amount = UNIT;
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, NativeCurrencyId::get(), init_amount),
+			(BOB, NativeCurrencyId::get(), init_amount),
+		])
+		.build()
+		.execute_with(|| {
+			let native = NativeCurrencyId::get();
+			let deployed = create_token(ALICE, "ABC", "ABC", UNIT);
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+			let cid = CurrencyId::Erc20(*deployed.as_ref());
+
+			// only root or the asset admin is allowed
+			assert_noop!(
+				Currencies::freeze_account(Origin::signed(BOB), cid, ALICE),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(Currencies::set_asset_admin(Origin::root(), cid, Some(BOB)));
+			assert_ok!(Currencies::freeze_account(Origin::signed(BOB), cid, ALICE));
+			System::assert_last_event(Event::Currencies(crate::Event::AccountFrozen {
+				currency_id: cid,
+				who: ALICE,
+			}));
+			assert!(Currencies::is_frozen(cid, &ALICE));
+			assert_eq!(Currencies::frozen_accounts(cid), vec![ALICE]);
+
+			assert_noop!(
+				Currencies::transfer(Origin::signed(ALICE), BOB, cid, 1000),
+				crate::Error::<Runtime>::AccountFrozen
+			);
+			assert_noop!(
+				<Currencies as fungibles::Transfer<_>>::transfer(cid, &ALICE, &BOB, 1000, false),
+				crate::Error::<Runtime>::AccountFrozen
+			);
+			// frozen balances can not be moved into reserves either
+			assert!(!<Currencies as MultiReservableCurrency<_>>::can_reserve(cid, &ALICE, 1000));
+			assert_noop!(
+				<Currencies as MultiReservableCurrency<_>>::reserve(cid, &ALICE, 1000),
+				crate::Error::<Runtime>::AccountFrozen
+			);
+			// other currencies are unaffected
+			assert_ok!(Currencies::transfer(Origin::signed(ALICE), BOB, native, 1000));
+
+			assert_ok!(Currencies::thaw_account(Origin::signed(BOB), cid, ALICE));
+			assert_ok!(Currencies::transfer(Origin::signed(ALICE), BOB, cid, 1000));
+
+			// the asset admin of one currency can not freeze another one
+			assert_noop!(
+				Currencies::freeze_currency(Origin::signed(BOB), native),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(<Currencies as MultiReservableCurrency<_>>::reserve(native, &BOB, 1000));
+			assert_ok!(Currencies::freeze_currency(Origin::root(), native));
+			System::assert_last_event(Event::Currencies(crate::Event::CurrencyFrozen {
+				currency_id: native,
+			}));
+			assert_noop!(
+				Currencies::transfer(Origin::signed(BOB), ALICE, native, 1000),
+				crate::Error::<Runtime>::CurrencyFrozen
+			);
+			assert_noop!(
+				<Currencies as MultiCurrency<_>>::withdraw(native, &BOB, 1000),
+				crate::Error::<Runtime>::CurrencyFrozen
+			);
+			assert_noop!(
+				<Currencies as MultiReservableCurrency<_>>::repatriate_reserved(
+					native,
+					&BOB,
+					&ALICE,
+					1000,
+					BalanceStatus::Free
+				),
+				crate::Error::<Runtime>::CurrencyFrozen
+			);
+
+			assert_ok!(Currencies::thaw_currency(Origin::root(), native));
+			assert_ok!(Currencies::transfer(Origin::signed(BOB), ALICE, native, 1000));
+		});
+}
+
+#[test]
+fn test_transfer_erc20_weight() {
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, NativeCurrencyId::get(), UNIT),
+			(BOB, NativeCurrencyId::get(), UNIT),
+		])
+		.build()
+		.execute_with(|| {
+			let deployed = create_token(ALICE, "ABC", "ABC", UNIT);
+			let cid = CurrencyId::Erc20(*deployed.as_ref());
+
+			assert_ok!(ContractTokenRegistry::register_asset(
+				Origin::root(),
+				deployed.clone(),
+				true,
+				false,
+				None
+			));
+
+			let base = <() as crate::weights::WeightInfo>::transfer();
+			let declared =
+				crate::Call::<Runtime>::transfer { to: BOB, currency_id: cid, balance: 1000 }
+					.get_dispatch_info()
+					.weight;
+			assert_eq!(declared, base + 2 * MaxGas::get());
+
+			// only the gas consumed by the token contract is charged
+			let actual = Currencies::transfer(Origin::signed(ALICE), BOB, cid, 1000)
+				.expect("transfer should succeed")
+				.actual_weight
+				.expect("actual weight should be reported");
+			assert!(actual > base && actual < declared);
+
+			// the balance read is charged when the transfer fails
+			let err = Currencies::transfer(Origin::signed(BOB), ALICE, cid, UNIT).unwrap_err();
+			assert_eq!(err.error, crate::Error::<Runtime>::BalanceTooLow.into());
+			assert!(err.post_info.actual_weight.unwrap() > base);
+
+			let declared = crate::Call::<Runtime>::transfer_keep_alive {
+				to: BOB,
+				currency_id: cid,
+				balance: 1000,
+			}
+			.get_dispatch_info()
+			.weight;
+			assert_eq!(
+				declared,
+				<() as crate::weights::WeightInfo>::transfer_keep_alive() + 2 * MaxGas::get()
+			);
+			let actual = Currencies::transfer_keep_alive(Origin::signed(ALICE), BOB, cid, 1000)
+				.expect("transfer should succeed")
+				.actual_weight
+				.expect("actual weight should be reported");
+			assert!(actual < declared);
+
+			// the transferable balance is read once more
+			let declared = crate::Call::<Runtime>::transfer_all {
+				to: ALICE,
+				currency_id: cid,
+				keep_alive: true,
+			}
+			.get_dispatch_info()
+			.weight;
+			assert_eq!(
+				declared,
+				<() as crate::weights::WeightInfo>::transfer_all() + 3 * MaxGas::get()
+			);
+			let actual = Currencies::transfer_all(Origin::signed(BOB), ALICE, cid, true)
+				.expect("transfer should succeed")
+				.actual_weight
+				.expect("actual weight should be reported");
+			assert!(actual < declared);
+			assert_eq!(Currencies::free_balance(BOB, cid), 0);
+
+			assert_ok!(ContractTokenRegistry::unregister_asset(Origin::root(), deployed));
+			assert_noop!(
+				Currencies::transfer(Origin::signed(ALICE), BOB, cid, 1000),
+				crate::Error::<Runtime>::UnregisteredAsset
+			);
+		});
+}
@@ -0,0 +1,47 @@
+use frame_support::weights::Weight;
+
+pub trait WeightInfo {
+	fn transfer() -> Weight;
+
+	fn transfer_keep_alive() -> Weight;
+
+	fn transfer_all() -> Weight;
+
+	fn transfer_batch(n: u32) -> Weight;
+
+	fn force_transfer() -> Weight;
+
+	fn set_asset_admin() -> Weight;
+
+	fn freeze() -> Weight;
+}
+
+impl WeightInfo for () {
+	fn transfer() -> Weight {
+		100_000_u64
+	}
+
+	fn transfer_keep_alive() -> Weight {
+		100_000_u64
+	}
+
+	fn transfer_all() -> Weight {
+		100_000_u64
+	}
+
+	fn transfer_batch(n: u32) -> Weight {
+		100_000_u64.saturating_mul(n as Weight)
+	}
+
+	fn force_transfer() -> Weight {
+		100_000_u64
+	}
+
+	fn set_asset_admin() -> Weight {
+		100_000_u64
+	}
+
+	fn freeze() -> Weight {
+		100_000_u64
+	}
+}
@@ -4,19 +4,34 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::Codec;
+use codec::{Codec, Decode, Encode};
 use ethereum::{
 	BlockV2 as EthereumBlock, ReceiptV3 as EthereumReceipt, TransactionV2 as EthereumTransaction,
 };
 use fp_rpc::TransactionStatus;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 use sp_core::{H160, H256, U256};
-use sp_runtime::{traits::Block as BlockT, DispatchError};
+use sp_runtime::{traits::Block as BlockT, DispatchError, RuntimeDebug};
 use sp_std::vec::Vec;
 
 pub type ConesensusDigest = ([u8; 4], Vec<u8>);
 
+/// origin of a contract deployment
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum ContractDeployer<AccountId> {
+	/// source of an eth transaction, deploying from its mapped account
+	Eth(H160),
+	Substrate(AccountId),
+}
+
 sp_api::decl_runtime_apis! {
+	/// version 2 adds `predict_contract_address`
+	#[api_version(2)]
 	pub trait EvmCompatApi<AccountId, Balance>
 	where
 		AccountId: Codec,
@@ -35,6 +50,10 @@ sp_api::decl_runtime_apis! {
 
 		fn check_contract_is_evm_compat(contract_addr: AccountId) -> Option<H160>;
 
+		/// address of the contract instantiated from `code_hash` and `salt`, both as AccountId and
+		/// H160
+		fn predict_contract_address(deployer: ContractDeployer<AccountId>, code_hash: H256, salt: Vec<u8>) -> (AccountId, H160);
+
 		/// get chain_id
 		fn chain_id() -> u64;
 
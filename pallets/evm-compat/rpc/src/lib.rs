@@ -7,15 +7,21 @@ use std::{marker::PhantomData, sync::Arc};
 
 use codec::Codec;
 
-use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::CallError};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject, METHOD_NOT_FOUND_CODE},
+};
 
-pub use pallet_evm_compat_rpc_runtime_api::EvmCompatApi as EvmCompatApiRuntimeApi;
+pub use pallet_evm_compat_rpc_runtime_api::{
+	ContractDeployer, EvmCompatApi as EvmCompatApiRuntimeApi,
+};
 
 use sc_client_api::client::BlockBackend;
-use sp_api::{BlockId, ProvideRuntimeApi};
+use sp_api::{ApiExt, BlockId, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{
-	app_crypto::sp_core::H160,
+	app_crypto::sp_core::{Bytes, H160, H256},
 	traits::{Block as BlockT, Header as HeaderT},
 };
 
@@ -40,6 +46,16 @@ pub trait EvmCompatApi<BlockHash, BlockNumber, AccountId, Balance> {
 		contract_addr: AccountId,
 		at: Option<BlockHash>,
 	) -> RpcResult<Option<H160>>;
+
+	/// address of the contract `deployer` would instantiate from `code_hash` and `salt`
+	#[method(name = "evmCompat_predict_contract_address")]
+	fn predict_contract_address(
+		&self,
+		deployer: ContractDeployer<AccountId>,
+		code_hash: H256,
+		salt: Bytes,
+		at: Option<BlockHash>,
+	) -> RpcResult<(AccountId, H160)>;
 }
 
 /// export ethereum compatible data structures, later consumed by either the relayer, indexer or
@@ -118,4 +134,33 @@ where
 		api.check_contract_is_evm_compat(&at, contract_addr)
 			.map_err(|e| CallError::from_std_error(e).into())
 	}
+
+	fn predict_contract_address(
+		&self,
+		deployer: ContractDeployer<AccountId>,
+		code_hash: H256,
+		salt: Bytes,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<(AccountId, H160)> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		// runtimes older than version 2 don't expose the prediction
+		let supported = api
+			.has_api_with::<dyn EvmCompatApiRuntimeApi<Block, AccountId, Balance>, _>(&at, |v| {
+				v >= 2
+			})
+			.map_err(CallError::from_std_error)?;
+		if !supported {
+			return Err(CallError::Custom(ErrorObject::owned(
+				METHOD_NOT_FOUND_CODE,
+				"predict_contract_address is not supported by the runtime",
+				None::<()>,
+			))
+			.into())
+		}
+
+		api.predict_contract_address(&at, deployer, code_hash, salt.to_vec())
+			.map_err(|e| CallError::from_std_error(e).into())
+	}
 }
@@ -399,4 +399,27 @@ where
 		.flatten()
 		.map(|v| <Keccak256 as HashT>::hash(&v[..]))
 	}
+
+	/// address of the contract `deployer` would instantiate from `code_hash` and `salt`, along
+	/// with its H160 form
+	pub fn predict_contract_address(
+		deployer: &AccountIdOf<T>,
+		code_hash: &<T as frame_system::Config>::Hash,
+		salt: &[u8],
+	) -> (AccountIdOf<T>, H160) {
+		let addr = pallet_contracts::Pallet::<T>::contract_address(deployer, code_hash, salt);
+		let evm_address = H160::from_slice(&addr.as_ref()[12..]);
+
+		(addr, evm_address)
+	}
+
+	/// eth transactions deploy from the account mapped from their source, the salt being the
+	/// last part of the create input
+	pub fn predict_eth_contract_address(
+		source: H160,
+		code_hash: &<T as frame_system::Config>::Hash,
+		salt: &[u8],
+	) -> (AccountIdOf<T>, H160) {
+		Self::predict_contract_address(&Self::to_mapped_account(source), code_hash, salt)
+	}
 }
@@ -262,6 +262,57 @@ fn test_create() {
 		});
 }
 
+#[test]
+fn test_predict_contract_address() {
+	let pair = ecdsa::Pair::from_seed_slice(&RAWSEED).unwrap();
+
+	let source = H160(pair.public().to_eth_address().unwrap());
+
+	let dev_acc = EvmCompat::to_mapped_account(source);
+
+	ExtBuilder::default()
+		.balances(vec![(dev_acc.clone(), 2 << 64)])
+		.build()
+		.execute_with(|| {
+			let chain_id = ChainId::get();
+
+			let blob = std::fs::read(
+				"../../runtime/integration-tests/contracts-data/ink/basic/dist/basic.wasm",
+			)
+			.unwrap();
+
+			let codehash = <<Runtime as frame_system::Config>::Hashing as Hash>::hash(&blob[..]);
+			let salt = b"create2".to_vec();
+
+			let (addr, evm_address) =
+				EvmCompat::predict_eth_contract_address(source, &codehash, &salt);
+
+			assert_eq!(EvmCompat::account_from_contract_addr(evm_address), addr);
+			assert_eq!(
+				(addr.clone(), evm_address),
+				EvmCompat::predict_contract_address(&dev_acc, &codehash, &salt)
+			);
+
+			let selector = Bytes::from_str("0xed4b9d1b").unwrap();
+
+			// same as a plain create, only salted
+			let mut eth_raw_call =
+				dummy_contract_create(chain_id, vec![], selector.to_vec(), 1_u32.into());
+			eth_raw_call.input = (blob, selector.to_vec(), salt).encode();
+
+			let eth_signed =
+				LegacyTxMsg(eth_raw_call).sign_with_chain_id(&pair.seed().into(), chain_id);
+
+			let call = crate::Call::<Runtime>::transact { t: eth_signed };
+			let info = call.check_self_contained().unwrap().unwrap();
+
+			assert_ok!(Call::EvmCompat(call).apply_self_contained(info).unwrap());
+
+			// the contract lands at the predicted address
+			assert!(!Balances::reserved_balance(addr).is_zero());
+		});
+}
+
 #[test]
 fn test_transfer() {
 	let pair = ecdsa::Pair::from_seed_slice(&RAWSEED).unwrap();
@@ -15,7 +15,7 @@ use frame_system::pallet_prelude::*;
 
 use orml_traits::MultiCurrency;
 
-use traits::fee::{Eligibility, FeeAssetHealth, FeeSource, InvalidFeeSource};
+use traits::fee::{Eligibility, EligibilityError, FeeAssetHealth, FeeSource, InvalidFeeSource};
 
 pub use pallet::*;
 use weights::WeightInfo;
@@ -142,7 +142,10 @@ where
 		T::HealthStatus::health_status(id)
 			.map_err(|_| InvalidFeeSource::Inactive)
 			.and_then(|_| {
-				T::Eligibility::eligible(who, id).map_err(|_| InvalidFeeSource::Inactive)
+				T::Eligibility::eligible(who, id).map_err(|e| match e {
+					EligibilityError::Frozen => InvalidFeeSource::Frozen,
+					EligibilityError::NotAllowed => InvalidFeeSource::Inactive,
+				})
 			})?;
 
 		log::debug!(target: "fee_enablement::fee_source", "{:?} accepted", id);
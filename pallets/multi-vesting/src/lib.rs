@@ -0,0 +1,455 @@
+//! ## pallet-multi-vesting
+//!
+//! This pallet allows balances of any currency to be released gradually.
+//!
+//! A vesting schedule releases `total` linearly from `start` to `start + duration`, nothing is
+//! released before `start + cliff`. A schedule with `cliff` equal to `duration` is a pure cliff
+//! schedule. The unvested amount of native tokens is kept with a `MultiLockableCurrency` lock.
+//! Contract-based tokens are not aware of the locks set by the runtime, so their unvested amount
+//! is held by the pallet account instead and paid out as it vests.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{
+		traits::{AccountIdConversion, AtLeast32BitUnsigned, Saturating, Zero},
+		Perbill,
+	},
+	sp_std::{collections::btree_map::BTreeMap, prelude::*},
+	PalletId,
+};
+use frame_system::pallet_prelude::*;
+use orml_traits::{LockIdentifier, MultiCurrency, MultiLockableCurrency};
+pub use pallet::*;
+use primitives::CurrencyId;
+use weights::WeightInfo;
+
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub const VESTING_LOCK_ID: LockIdentifier = *b"lgn/vest";
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+
+pub type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<AccountIdOf<T>>>::Balance;
+
+pub type VestingScheduleOf<T> =
+	VestingSchedule<<T as frame_system::Config>::BlockNumber, BalanceOf<T>>;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VestingSchedule<BlockNumber, Balance> {
+	pub start: BlockNumber,
+	/// blocks after start before anything is released
+	pub cliff: BlockNumber,
+	/// blocks after start when everything is released
+	pub duration: BlockNumber,
+	pub total: Balance,
+}
+
+impl<BlockNumber, Balance> VestingSchedule<BlockNumber, Balance>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+	Balance: AtLeast32BitUnsigned + Copy,
+{
+	pub fn is_valid(&self) -> bool {
+		!self.total.is_zero() && !self.duration.is_zero() && self.cliff <= self.duration
+	}
+
+	pub fn end(&self) -> BlockNumber {
+		self.start.saturating_add(self.duration)
+	}
+
+	pub fn cliff_end(&self) -> BlockNumber {
+		self.start.saturating_add(self.cliff)
+	}
+
+	/// amount still locked at the given block
+	pub fn locked_at(&self, now: BlockNumber) -> Balance {
+		if now < self.cliff_end() {
+			return self.total
+		}
+		if now >= self.end() {
+			return Zero::zero()
+		}
+
+		let vested = Perbill::from_rational(now.saturating_sub(self.start), self.duration)
+			.mul_floor(self.total);
+		self.total.saturating_sub(vested)
+	}
+}
+
+#[frame_support::pallet]
+mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// currency system holding the vested balances
+		type MultiCurrency: MultiLockableCurrency<
+			AccountIdOf<Self>,
+			CurrencyId = CurrencyId,
+			Moment = Self::BlockNumber,
+		>;
+
+		/// the min amount of a vested transfer
+		#[pallet::constant]
+		type MinVestedTransfer: Get<BalanceOf<Self>>;
+
+		/// max number of schedules of an account in a currency
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+
+		/// account holding the unvested contract-based tokens
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_schedules)]
+	pub type VestingSchedules<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		CurrencyId,
+		BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules>,
+		ValueQuery,
+	>;
+
+	/// unvested contract-based tokens held by the pallet account for an account
+	#[pallet::storage]
+	#[pallet::getter(fn escrowed_balance)]
+	pub type EscrowedBalances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		CurrencyId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		VestingScheduleAdded {
+			from: AccountIdOf<T>,
+			to: AccountIdOf<T>,
+			currency_id: CurrencyId,
+			schedule: VestingScheduleOf<T>,
+		},
+		Vested {
+			who: AccountIdOf<T>,
+			currency_id: CurrencyId,
+			locked: BalanceOf<T>,
+		},
+		VestingSchedulesMerged {
+			who: AccountIdOf<T>,
+			currency_id: CurrencyId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		InvalidSchedule,
+		AmountLow,
+		MaxVestingSchedulesExceeded,
+		ScheduleIndexOutOfBounds,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// release the vested amount of the caller
+		#[pallet::weight(T::WeightInfo::vest())]
+		pub fn vest(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::update_lock(&who, currency_id)
+		}
+
+		#[pallet::weight(T::WeightInfo::vest())]
+		pub fn vest_other(
+			origin: OriginFor<T>,
+			target: AccountIdOf<T>,
+			currency_id: CurrencyId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::update_lock(&target, currency_id)
+		}
+
+		/// transfer `schedule.total` to the target and lock it under the schedule, contract-based
+		/// tokens are held by the pallet account until vested
+		#[pallet::weight(T::WeightInfo::vested_transfer())]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			target: AccountIdOf<T>,
+			currency_id: CurrencyId,
+			schedule: VestingScheduleOf<T>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			ensure!(schedule.is_valid(), Error::<T>::InvalidSchedule);
+			ensure!(schedule.total >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+
+			Self::hold(&from, &target, currency_id, schedule.total)?;
+			Self::add_schedule(&target, currency_id, schedule.clone())?;
+
+			Self::deposit_event(Event::<T>::VestingScheduleAdded {
+				from,
+				to: target,
+				currency_id,
+				schedule,
+			});
+
+			Ok(())
+		}
+
+		/// merge two schedules into one, which releases the remaining locked amount of both
+		/// from now until the later end of them
+		#[pallet::weight(T::WeightInfo::merge_schedules())]
+		pub fn merge_schedules(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			schedule1_index: u32,
+			schedule2_index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if schedule1_index == schedule2_index {
+				return Ok(())
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+
+			VestingSchedules::<T>::try_mutate(&who, currency_id, |schedules| -> DispatchResult {
+				let (first, second) = (
+					schedule1_index.min(schedule2_index) as usize,
+					schedule1_index.max(schedule2_index) as usize,
+				);
+				ensure!(second < schedules.len(), Error::<T>::ScheduleIndexOutOfBounds);
+
+				// remove the later one first, so the index of the former stays the same
+				let schedule2 = schedules.remove(second);
+				let schedule1 = schedules.remove(first);
+
+				if let Some(merged) = Self::merge(now, schedule1, schedule2) {
+					schedules
+						.try_push(merged)
+						.map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)?;
+				}
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::VestingSchedulesMerged {
+				who: who.clone(),
+				currency_id,
+			});
+
+			Self::update_lock(&who, currency_id)
+		}
+	}
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// (who, currency_id, start, cliff, duration, total)
+		pub vesting: Vec<(
+			AccountIdOf<T>,
+			CurrencyId,
+			T::BlockNumber,
+			T::BlockNumber,
+			T::BlockNumber,
+			BalanceOf<T>,
+		)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { vesting: vec![] }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			// an account may be given several schedules of the same currency
+			let mut totals = BTreeMap::<(AccountIdOf<T>, CurrencyId), BalanceOf<T>>::new();
+			let schedules = self
+				.vesting
+				.iter()
+				.map(|(who, currency_id, start, cliff, duration, total)| {
+					let schedule = VestingSchedule {
+						start: *start,
+						cliff: *cliff,
+						duration: *duration,
+						total: *total,
+					};
+					assert!(schedule.is_valid(), "invalid vesting schedule");
+
+					let vested = totals.entry((who.clone(), *currency_id)).or_default();
+					*vested = vested.saturating_add(*total);
+
+					(who, *currency_id, schedule)
+				})
+				.collect::<Vec<_>>();
+
+			for ((who, currency_id), total) in &totals {
+				assert!(
+					T::MultiCurrency::free_balance(*currency_id, who) >= *total,
+					"vested amount exceeds free balance"
+				);
+				Pallet::<T>::hold(who, who, *currency_id, *total)
+					.expect("unable to hold vested amount");
+			}
+
+			for (who, currency_id, schedule) in schedules {
+				Pallet::<T>::add_schedule(who, currency_id, schedule)
+					.expect("unable to add vesting schedule");
+			}
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// account holding the unvested contract-based tokens
+	pub fn account_id() -> AccountIdOf<T> {
+		T::PalletId::get().try_into_account().expect("Invalid PalletId")
+	}
+
+	/// whether the unvested amount is held by the pallet rather than locked
+	pub fn is_escrowed(currency_id: CurrencyId) -> bool {
+		matches!(currency_id, CurrencyId::Erc20(_))
+	}
+
+	/// amount still locked by all schedules of an account
+	pub fn locked_balance(who: &AccountIdOf<T>, currency_id: CurrencyId) -> BalanceOf<T> {
+		let now = frame_system::Pallet::<T>::block_number();
+
+		VestingSchedules::<T>::get(who, currency_id)
+			.iter()
+			.fold(Zero::zero(), |acc: BalanceOf<T>, schedule| {
+				acc.saturating_add(schedule.locked_at(now))
+			})
+	}
+
+	fn add_schedule(
+		who: &AccountIdOf<T>,
+		currency_id: CurrencyId,
+		schedule: VestingScheduleOf<T>,
+	) -> DispatchResult {
+		VestingSchedules::<T>::try_mutate(who, currency_id, |schedules| {
+			schedules
+				.try_push(schedule)
+				.map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)
+		})?;
+
+		Self::update_lock(who, currency_id)
+	}
+
+	/// move an amount about to be vested from `from` to `who`, or to the pallet account for
+	/// contract-based tokens
+	fn hold(
+		from: &AccountIdOf<T>,
+		who: &AccountIdOf<T>,
+		currency_id: CurrencyId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if !Self::is_escrowed(currency_id) {
+			// the amount is locked where it is when vested at genesis
+			if from == who {
+				return Ok(())
+			}
+			return T::MultiCurrency::transfer(currency_id, from, who, amount)
+		}
+
+		T::MultiCurrency::transfer(currency_id, from, &Self::account_id(), amount)?;
+		EscrowedBalances::<T>::mutate(who, currency_id, |escrowed| {
+			*escrowed = escrowed.saturating_add(amount)
+		});
+		Ok(())
+	}
+
+	/// pay out the escrowed amount exceeding what is still locked
+	fn release(
+		who: &AccountIdOf<T>,
+		currency_id: CurrencyId,
+		locked: BalanceOf<T>,
+	) -> DispatchResult {
+		let escrowed = EscrowedBalances::<T>::get(who, currency_id);
+		let released = escrowed.saturating_sub(locked);
+		if !released.is_zero() {
+			T::MultiCurrency::transfer(currency_id, &Self::account_id(), who, released)?;
+		}
+
+		if locked.is_zero() {
+			EscrowedBalances::<T>::remove(who, currency_id);
+		} else {
+			EscrowedBalances::<T>::insert(who, currency_id, escrowed.min(locked));
+		}
+		Ok(())
+	}
+
+	/// drop finished schedules and lock the remaining amount
+	fn update_lock(who: &AccountIdOf<T>, currency_id: CurrencyId) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+
+		VestingSchedules::<T>::mutate_exists(who, currency_id, |maybe_schedules| {
+			if let Some(schedules) = maybe_schedules {
+				schedules.retain(|schedule| !schedule.locked_at(now).is_zero());
+				if schedules.is_empty() {
+					*maybe_schedules = None;
+				}
+			}
+		});
+
+		let locked = Self::locked_balance(who, currency_id);
+		if Self::is_escrowed(currency_id) {
+			Self::release(who, currency_id, locked)?;
+		} else if locked.is_zero() {
+			T::MultiCurrency::remove_lock(VESTING_LOCK_ID, currency_id, who)?;
+		} else {
+			T::MultiCurrency::set_lock(VESTING_LOCK_ID, currency_id, who, locked)?;
+		}
+
+		Self::deposit_event(Event::<T>::Vested { who: who.clone(), currency_id, locked });
+
+		Ok(())
+	}
+
+	fn merge(
+		now: T::BlockNumber,
+		schedule1: VestingScheduleOf<T>,
+		schedule2: VestingScheduleOf<T>,
+	) -> Option<VestingScheduleOf<T>> {
+		let total = schedule1.locked_at(now).saturating_add(schedule2.locked_at(now));
+		let end = schedule1.end().max(schedule2.end());
+
+		if total.is_zero() || end <= now {
+			return None
+		}
+
+		let cliff_end = schedule1.cliff_end().max(schedule2.cliff_end()).max(now);
+
+		Some(VestingSchedule {
+			start: now,
+			cliff: cliff_end.saturating_sub(now),
+			duration: end.saturating_sub(now),
+			total,
+		})
+	}
+}
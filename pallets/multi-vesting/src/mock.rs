@@ -0,0 +1,282 @@
+use super::*;
+
+use frame_support::{
+	construct_runtime, parameter_types,
+	sp_runtime::traits::{BlakeTwo256, IdentityLookup},
+	traits::{Contains, Everything},
+	PalletId,
+};
+
+use primitives::{AccountId, Amount, Balance, BlockNumber, CurrencyId, Header, Index, TokenId};
+use sp_core::H256;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+parameter_types! {
+	pub const BlockHashCount: BlockNumber = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = Everything;
+
+	type BlockWeights = ();
+
+	type BlockLength = ();
+
+	type Origin = Origin;
+
+	type Call = Call;
+
+	type Index = Index;
+
+	type BlockNumber = BlockNumber;
+
+	type Hash = H256;
+
+	type Hashing = BlakeTwo256;
+
+	type AccountId = AccountId;
+
+	type Lookup = IdentityLookup<Self::AccountId>;
+
+	type Header = Header;
+
+	type Event = Event;
+
+	type BlockHashCount = BlockHashCount;
+
+	type DbWeight = ();
+
+	type Version = ();
+
+	type PalletInfo = PalletInfo;
+
+	type AccountData = orml_tokens::AccountData<Balance>;
+
+	type OnNewAccount = ();
+
+	type OnKilledAccount = ();
+
+	type SystemWeightInfo = ();
+
+	type SS58Prefix = ();
+
+	type OnSetCode = ();
+
+	type MaxConsumers = ConstU32<1>;
+}
+
+pub struct DustRemovalWhitelist;
+
+impl Contains<AccountId> for DustRemovalWhitelist {
+	fn contains(_t: &AccountId) -> bool {
+		false
+	}
+}
+
+orml_traits::parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Balance::min_value()
+	};
+}
+
+pub type ReserveIdentifier = [u8; 8];
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+
+	type Balance = Balance;
+
+	type Amount = Amount;
+
+	type CurrencyId = CurrencyId;
+
+	type WeightInfo = ();
+
+	type ExistentialDeposits = ExistentialDeposits;
+
+	type OnDust = ();
+
+	type MaxLocks = ConstU32<2>;
+
+	type DustRemovalWhitelist = DustRemovalWhitelist;
+
+	type MaxReserves = ConstU32<2>;
+
+	type ReserveIdentifier = ReserveIdentifier;
+
+	type OnNewTokenAccount = ();
+
+	type OnKilledTokenAccount = ();
+}
+
+/// orml tokens, except Erc20 tokens which can't be locked, like contract-based tokens
+pub struct Currencies;
+
+impl MultiCurrency<AccountId> for Currencies {
+	type CurrencyId = CurrencyId;
+	type Balance = Balance;
+
+	fn minimum_balance(currency_id: CurrencyId) -> Balance {
+		<Tokens as MultiCurrency<_>>::minimum_balance(currency_id)
+	}
+
+	fn total_issuance(currency_id: CurrencyId) -> Balance {
+		<Tokens as MultiCurrency<_>>::total_issuance(currency_id)
+	}
+
+	fn total_balance(currency_id: CurrencyId, who: &AccountId) -> Balance {
+		<Tokens as MultiCurrency<_>>::total_balance(currency_id, who)
+	}
+
+	fn free_balance(currency_id: CurrencyId, who: &AccountId) -> Balance {
+		<Tokens as MultiCurrency<_>>::free_balance(currency_id, who)
+	}
+
+	fn ensure_can_withdraw(
+		currency_id: CurrencyId,
+		who: &AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		<Tokens as MultiCurrency<_>>::ensure_can_withdraw(currency_id, who, amount)
+	}
+
+	fn transfer(
+		currency_id: CurrencyId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		<Tokens as MultiCurrency<_>>::transfer(currency_id, from, to, amount)
+	}
+
+	fn deposit(currency_id: CurrencyId, who: &AccountId, amount: Balance) -> DispatchResult {
+		<Tokens as MultiCurrency<_>>::deposit(currency_id, who, amount)
+	}
+
+	fn withdraw(currency_id: CurrencyId, who: &AccountId, amount: Balance) -> DispatchResult {
+		<Tokens as MultiCurrency<_>>::withdraw(currency_id, who, amount)
+	}
+
+	fn can_slash(currency_id: CurrencyId, who: &AccountId, value: Balance) -> bool {
+		<Tokens as MultiCurrency<_>>::can_slash(currency_id, who, value)
+	}
+
+	fn slash(currency_id: CurrencyId, who: &AccountId, amount: Balance) -> Balance {
+		<Tokens as MultiCurrency<_>>::slash(currency_id, who, amount)
+	}
+}
+
+impl MultiLockableCurrency<AccountId> for Currencies {
+	type Moment = BlockNumber;
+
+	fn set_lock(
+		lock_id: LockIdentifier,
+		currency_id: CurrencyId,
+		who: &AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		ensure!(!matches!(currency_id, CurrencyId::Erc20(_)), DispatchError::Other("NotLockable"));
+		<Tokens as MultiLockableCurrency<_>>::set_lock(lock_id, currency_id, who, amount)
+	}
+
+	fn extend_lock(
+		lock_id: LockIdentifier,
+		currency_id: CurrencyId,
+		who: &AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		ensure!(!matches!(currency_id, CurrencyId::Erc20(_)), DispatchError::Other("NotLockable"));
+		<Tokens as MultiLockableCurrency<_>>::extend_lock(lock_id, currency_id, who, amount)
+	}
+
+	fn remove_lock(
+		lock_id: LockIdentifier,
+		currency_id: CurrencyId,
+		who: &AccountId,
+	) -> DispatchResult {
+		ensure!(!matches!(currency_id, CurrencyId::Erc20(_)), DispatchError::Other("NotLockable"));
+		<Tokens as MultiLockableCurrency<_>>::remove_lock(lock_id, currency_id, who)
+	}
+}
+
+parameter_types! {
+	pub const MinVestedTransfer: Balance = 10;
+	pub const VestingPalletId: PalletId = PalletId(*b"lgn/vest");
+}
+
+impl Config for Runtime {
+	type Event = Event;
+
+	type MultiCurrency = Currencies;
+
+	type MinVestedTransfer = MinVestedTransfer;
+
+	type MaxVestingSchedules = ConstU32<2>;
+
+	type PalletId = VestingPalletId;
+
+	type WeightInfo = ();
+}
+
+construct_runtime!(
+
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		Vesting: crate,
+	}
+);
+
+pub const ALICE: AccountId = AccountId::new([1u8; 32]);
+pub const BOB: AccountId = AccountId::new([2u8; 32]);
+
+pub const NATIVE: CurrencyId = CurrencyId::NativeToken(TokenId::Laguna);
+pub const FEE: CurrencyId = CurrencyId::NativeToken(TokenId::FeeToken);
+pub const ERC20: CurrencyId = CurrencyId::Erc20([9u8; 32]);
+
+#[derive(Default)]
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, CurrencyId, Balance)>,
+	vesting: Vec<(AccountId, CurrencyId, BlockNumber, BlockNumber, BlockNumber, Balance)>,
+}
+
+impl ExtBuilder {
+	pub fn balances(mut self, balances: Vec<(AccountId, CurrencyId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub fn vesting(
+		mut self,
+		vesting: Vec<(AccountId, CurrencyId, BlockNumber, BlockNumber, BlockNumber, Balance)>,
+	) -> Self {
+		self.vesting = vesting;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		// construct test storage for the mock runtime
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> { balances: self.balances }
+			.assimilate_storage(&mut t)
+			.unwrap();
+
+		GenesisBuild::<Runtime>::assimilate_storage(
+			&crate::GenesisConfig { vesting: self.vesting },
+			&mut t,
+		)
+		.expect("unable to build genesis");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+
+		ext
+	}
+}
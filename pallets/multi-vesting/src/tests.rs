@@ -0,0 +1,183 @@
+use super::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn test_genesis_linear_vesting() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, NATIVE, 1000)])
+		.vesting(vec![(ALICE, NATIVE, 0, 0, 100, 1000)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(Vesting::locked_balance(&ALICE, NATIVE), 990);
+			assert_eq!(Tokens::locks(&ALICE, NATIVE)[0].amount, 1000);
+
+			assert_ok!(Vesting::vest(Origin::signed(ALICE), NATIVE));
+			assert_eq!(Tokens::locks(&ALICE, NATIVE)[0].amount, 990);
+			assert_noop!(
+				<Tokens as MultiCurrency<_>>::transfer(NATIVE, &ALICE, &BOB, 11),
+				orml_tokens::Error::<Runtime>::LiquidityRestrictions
+			);
+
+			System::set_block_number(50);
+			assert_ok!(Vesting::vest_other(Origin::signed(BOB), ALICE, NATIVE));
+			assert_eq!(Tokens::locks(&ALICE, NATIVE)[0].amount, 500);
+			assert_ok!(<Tokens as MultiCurrency<_>>::transfer(NATIVE, &ALICE, &BOB, 500));
+
+			System::set_block_number(100);
+			assert_ok!(Vesting::vest(Origin::signed(ALICE), NATIVE));
+			assert!(Tokens::locks(&ALICE, NATIVE).is_empty());
+			assert!(Vesting::vesting_schedules(&ALICE, NATIVE).is_empty());
+		});
+}
+
+#[test]
+fn test_cliff_vesting() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, FEE, 1000)])
+		.build()
+		.execute_with(|| {
+			let schedule = VestingSchedule { start: 1, cliff: 10, duration: 20, total: 100 };
+			assert_ok!(Vesting::vested_transfer(Origin::signed(ALICE), BOB, FEE, schedule.clone()));
+			System::assert_has_event(Event::Vesting(crate::Event::VestingScheduleAdded {
+				from: ALICE,
+				to: BOB,
+				currency_id: FEE,
+				schedule,
+			}));
+			assert_eq!(Tokens::free_balance(FEE, &BOB), 100);
+
+			System::set_block_number(10);
+			assert_eq!(Vesting::locked_balance(&BOB, FEE), 100);
+
+			System::set_block_number(11);
+			assert_eq!(Vesting::locked_balance(&BOB, FEE), 50);
+			assert_ok!(Vesting::vest(Origin::signed(BOB), FEE));
+			assert_eq!(Tokens::locks(&BOB, FEE)[0].amount, 50);
+
+			System::set_block_number(21);
+			assert_ok!(Vesting::vest(Origin::signed(BOB), FEE));
+			assert!(Tokens::locks(&BOB, FEE).is_empty());
+		});
+}
+
+#[test]
+fn test_vested_transfer_checks() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, NATIVE, 1000)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Vesting::vested_transfer(
+					Origin::signed(ALICE),
+					BOB,
+					NATIVE,
+					VestingSchedule { start: 1, cliff: 20, duration: 10, total: 100 }
+				),
+				Error::<Runtime>::InvalidSchedule
+			);
+
+			assert_noop!(
+				Vesting::vested_transfer(
+					Origin::signed(ALICE),
+					BOB,
+					NATIVE,
+					VestingSchedule { start: 1, cliff: 0, duration: 10, total: 9 }
+				),
+				Error::<Runtime>::AmountLow
+			);
+
+			let schedule = VestingSchedule { start: 1, cliff: 0, duration: 10, total: 100 };
+			assert_ok!(Vesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				NATIVE,
+				schedule.clone()
+			));
+			assert_ok!(Vesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				NATIVE,
+				schedule.clone()
+			));
+			assert_noop!(
+				Vesting::vested_transfer(Origin::signed(ALICE), BOB, NATIVE, schedule),
+				Error::<Runtime>::MaxVestingSchedulesExceeded
+			);
+		});
+}
+
+#[test]
+fn test_merge_schedules() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, NATIVE, 1000)])
+		.vesting(vec![(ALICE, NATIVE, 0, 0, 10, 100), (ALICE, NATIVE, 0, 30, 40, 200)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(5);
+			assert_eq!(Vesting::locked_balance(&ALICE, NATIVE), 250);
+
+			assert_noop!(
+				Vesting::merge_schedules(Origin::signed(ALICE), NATIVE, 0, 2),
+				Error::<Runtime>::ScheduleIndexOutOfBounds
+			);
+
+			assert_ok!(Vesting::merge_schedules(Origin::signed(ALICE), NATIVE, 0, 1));
+			assert_eq!(
+				Vesting::vesting_schedules(&ALICE, NATIVE).into_inner(),
+				vec![VestingSchedule { start: 5, cliff: 25, duration: 35, total: 250 }]
+			);
+			assert_eq!(Vesting::locked_balance(&ALICE, NATIVE), 250);
+			assert_eq!(Tokens::locks(&ALICE, NATIVE)[0].amount, 250);
+		});
+}
+
+#[test]
+#[should_panic(expected = "vested amount exceeds free balance")]
+fn test_genesis_exceeding_schedules() {
+	// each schedule is covered by the balance, but not both of them
+	ExtBuilder::default()
+		.balances(vec![(ALICE, NATIVE, 1000)])
+		.vesting(vec![(ALICE, NATIVE, 0, 0, 10, 600), (ALICE, NATIVE, 0, 0, 10, 600)])
+		.build();
+}
+
+#[test]
+fn test_erc20_escrow() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, ERC20, 1000)])
+		.vesting(vec![(ALICE, ERC20, 0, 0, 100, 500)])
+		.build()
+		.execute_with(|| {
+			let escrow = Vesting::account_id();
+
+			// the unvested amount is held by the pallet rather than locked
+			assert!(Tokens::locks(&ALICE, ERC20).is_empty());
+			assert_eq!(Tokens::free_balance(ERC20, &ALICE), 500);
+			assert_eq!(Tokens::free_balance(ERC20, &escrow), 500);
+			assert_eq!(Vesting::escrowed_balance(&ALICE, ERC20), 500);
+
+			let schedule = VestingSchedule { start: 1, cliff: 0, duration: 10, total: 100 };
+			assert_ok!(Vesting::vested_transfer(Origin::signed(ALICE), BOB, ERC20, schedule));
+			assert_eq!(Tokens::free_balance(ERC20, &ALICE), 400);
+			assert_eq!(Tokens::free_balance(ERC20, &BOB), 0);
+			assert_eq!(Tokens::free_balance(ERC20, &escrow), 600);
+			assert_eq!(Vesting::escrowed_balance(&BOB, ERC20), 100);
+
+			System::set_block_number(6);
+			assert_ok!(Vesting::vest(Origin::signed(BOB), ERC20));
+			assert_eq!(Tokens::free_balance(ERC20, &BOB), 50);
+			assert_eq!(Vesting::escrowed_balance(&BOB, ERC20), 50);
+
+			assert_ok!(Vesting::vest(Origin::signed(ALICE), ERC20));
+			assert_eq!(Tokens::free_balance(ERC20, &ALICE), 430);
+			assert_eq!(Vesting::escrowed_balance(&ALICE, ERC20), 470);
+
+			System::set_block_number(11);
+			assert_ok!(Vesting::vest_other(Origin::signed(ALICE), BOB, ERC20));
+			assert_eq!(Tokens::free_balance(ERC20, &BOB), 100);
+			assert_eq!(Vesting::escrowed_balance(&BOB, ERC20), 0);
+			assert!(Vesting::vesting_schedules(&BOB, ERC20).is_empty());
+			assert_eq!(Tokens::free_balance(ERC20, &escrow), 470);
+		});
+}
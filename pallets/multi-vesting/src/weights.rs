@@ -0,0 +1,23 @@
+use frame_support::weights::Weight;
+
+pub trait WeightInfo {
+	fn vest() -> Weight;
+
+	fn vested_transfer() -> Weight;
+
+	fn merge_schedules() -> Weight;
+}
+
+impl WeightInfo for () {
+	fn vest() -> Weight {
+		1000_u64
+	}
+
+	fn vested_transfer() -> Weight {
+		1000_u64
+	}
+
+	fn merge_schedules() -> Weight {
+		1000_u64
+	}
+}
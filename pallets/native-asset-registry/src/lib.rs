@@ -0,0 +1,309 @@
+//! ## pallet-native-asset-registry
+//!
+//! This pallet allows governance to issue native tokens beyond the built-in `TokenId` variants.
+//!
+//! Tokens issued here are represented as `TokenId::Custom(id)`. Their balances are kept by the
+//! underlying multi-currency system, while metadata such as name, symbol, decimals and existential
+//! deposit lives in this pallet, so adding a token no longer requires a runtime upgrade.
+//!
+//! The existential deposits of the built-in tokens are kept here as well, so governance is able to
+//! adjust them on-chain. Used as the `OnDust` handler of orml_tokens, this pallet sweeps the dust
+//! of reaped accounts to `DustReceiver`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, sp_std::prelude::*};
+use frame_system::pallet_prelude::*;
+use orml_traits::{MultiCurrency, OnDust};
+pub use pallet::*;
+use primitives::{AssetMetadata, CurrencyId, TokenId, TokenMetadata};
+use weights::WeightInfo;
+
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+
+pub type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<AccountIdOf<T>>>::Balance;
+
+pub type AssetMetadataOf<T> =
+	AssetMetadata<BalanceOf<T>, BoundedVec<u8, <T as Config>::StringLimit>>;
+
+#[frame_support::pallet]
+mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// origin allowed to create tokens and manage their supply
+		type AllowedOrigin: EnsureOrigin<Self::Origin>;
+
+		/// multi-token system holding the balances of issued tokens
+		type MultiCurrency: MultiCurrency<AccountIdOf<Self>, CurrencyId = CurrencyId>;
+
+		/// max length of the name and symbol of a token
+		#[pallet::constant]
+		type StringLimit: Get<u32>;
+
+		/// account receiving the dust of reaped accounts, e.g. the treasury
+		type DustReceiver: Get<AccountIdOf<Self>>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// id 0 and 1 are taken by the built-in tokens whenever a token is referred to by a plain u32,
+	/// e.g. by the chain extension
+	#[pallet::type_value]
+	pub fn DefaultNextTokenId() -> u32 {
+		2
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_token_id)]
+	pub type NextTokenId<T: Config> = StorageValue<_, u32, ValueQuery, DefaultNextTokenId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_metadata)]
+	pub type Metadata<T: Config> = StorageMap<_, Twox64Concat, u32, AssetMetadataOf<T>>;
+
+	/// existential deposits of the built-in tokens, issued tokens keep theirs in the metadata
+	#[pallet::storage]
+	pub type ExistentialDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		AssetCreated { token_id: u32 },
+		AssetUpdated { token_id: u32 },
+		Minted { token_id: u32, to: AccountIdOf<T>, amount: BalanceOf<T> },
+		Burned { token_id: u32, from: AccountIdOf<T>, amount: BalanceOf<T> },
+		ExistentialDepositSet { currency_id: CurrencyId, existential_deposit: BalanceOf<T> },
+		DustSwept { currency_id: CurrencyId, who: AccountIdOf<T>, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		BadMetadata,
+		AssetNotFound,
+		NoAvailableTokenId,
+		NotNativeAsset,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::weight(T::WeightInfo::create_asset())]
+		pub fn create_asset(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			existential_deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			T::AllowedOrigin::ensure_origin(origin)?;
+
+			let metadata = Self::bounded_metadata(name, symbol, decimals, existential_deposit)?;
+
+			let token_id = NextTokenId::<T>::try_mutate(|next| -> Result<u32, DispatchError> {
+				let current = *next;
+				*next = next.checked_add(1).ok_or(Error::<T>::NoAvailableTokenId)?;
+				Ok(current)
+			})?;
+
+			Metadata::<T>::insert(token_id, metadata);
+			Self::deposit_event(Event::<T>::AssetCreated { token_id });
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::update_asset())]
+		pub fn update_asset(
+			origin: OriginFor<T>,
+			token_id: u32,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			existential_deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			T::AllowedOrigin::ensure_origin(origin)?;
+
+			let metadata = Self::bounded_metadata(name, symbol, decimals, existential_deposit)?;
+
+			Metadata::<T>::try_mutate(token_id, |val| -> DispatchResult {
+				let current = val.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+				*current = metadata;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::AssetUpdated { token_id });
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub fn mint(
+			origin: OriginFor<T>,
+			token_id: u32,
+			to: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::AllowedOrigin::ensure_origin(origin)?;
+			ensure!(Metadata::<T>::contains_key(token_id), Error::<T>::AssetNotFound);
+
+			T::MultiCurrency::deposit(Self::currency_id(token_id), &to, amount)?;
+			Self::deposit_event(Event::<T>::Minted { token_id, to, amount });
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn(
+			origin: OriginFor<T>,
+			token_id: u32,
+			from: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::AllowedOrigin::ensure_origin(origin)?;
+			ensure!(Metadata::<T>::contains_key(token_id), Error::<T>::AssetNotFound);
+
+			T::MultiCurrency::withdraw(Self::currency_id(token_id), &from, amount)?;
+			Self::deposit_event(Event::<T>::Burned { token_id, from, amount });
+
+			Ok(())
+		}
+
+		/// update the existential deposit of a native token, accounts below it are reaped on
+		/// their next balance change
+		#[pallet::weight(T::WeightInfo::set_existential_deposit())]
+		pub fn set_existential_deposit(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			existential_deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			T::AllowedOrigin::ensure_origin(origin)?;
+
+			match currency_id {
+				CurrencyId::NativeToken(TokenId::Custom(token_id)) =>
+					Metadata::<T>::try_mutate(token_id, |val| -> DispatchResult {
+						let current = val.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+						current.existential_deposit = existential_deposit;
+						Ok(())
+					})?,
+				CurrencyId::NativeToken(_) =>
+					ExistentialDeposits::<T>::insert(currency_id, existential_deposit),
+				CurrencyId::Erc20(_) => return Err(Error::<T>::NotNativeAsset.into()),
+			}
+			Self::deposit_event(Event::<T>::ExistentialDepositSet {
+				currency_id,
+				existential_deposit,
+			});
+
+			Ok(())
+		}
+	}
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// (token_id, name, symbol, decimals, existential_deposit)
+		pub assets: Vec<(u32, Vec<u8>, Vec<u8>, u8, BalanceOf<T>)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { assets: vec![] }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (token_id, name, symbol, decimals, existential_deposit) in &self.assets {
+				let metadata = Pallet::<T>::bounded_metadata(
+					name.clone(),
+					symbol.clone(),
+					*decimals,
+					*existential_deposit,
+				)
+				.expect("invalid asset metadata");
+
+				Metadata::<T>::insert(token_id, metadata);
+				NextTokenId::<T>::mutate(|next| *next = (*next).max(token_id.saturating_add(1)));
+			}
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn currency_id(token_id: u32) -> CurrencyId {
+		CurrencyId::NativeToken(TokenId::Custom(token_id))
+	}
+
+	/// existential deposit of a native token, None if the token is not registered
+	pub fn existential_deposit(currency_id: CurrencyId) -> Option<BalanceOf<T>> {
+		match currency_id {
+			CurrencyId::NativeToken(TokenId::Custom(token_id)) =>
+				Metadata::<T>::get(token_id).map(|metadata| metadata.existential_deposit),
+			CurrencyId::NativeToken(_) => Some(ExistentialDeposits::<T>::get(currency_id)),
+			CurrencyId::Erc20(_) => None,
+		}
+	}
+
+	/// all tokens issued through this registry
+	pub fn custom_assets() -> Vec<CurrencyId> {
+		Metadata::<T>::iter_keys().map(Self::currency_id).collect::<Vec<_>>()
+	}
+
+	/// metadata of a native token, built-in tokens are resolved from their static definition
+	pub fn metadata(currency_id: CurrencyId) -> Option<AssetMetadata<BalanceOf<T>, Vec<u8>>> {
+		match currency_id {
+			CurrencyId::NativeToken(TokenId::Custom(token_id)) =>
+				Metadata::<T>::get(token_id).map(|metadata| AssetMetadata {
+					name: metadata.name.into_inner(),
+					symbol: metadata.symbol.into_inner(),
+					decimals: metadata.decimals,
+					existential_deposit: metadata.existential_deposit,
+				}),
+			CurrencyId::NativeToken(_) => Some(AssetMetadata {
+				name: currency_id.name()?.as_bytes().to_vec(),
+				symbol: currency_id.symbol()?.as_bytes().to_vec(),
+				decimals: currency_id.decimals()?,
+				existential_deposit: T::MultiCurrency::minimum_balance(currency_id),
+			}),
+			CurrencyId::Erc20(_) => None,
+		}
+	}
+
+	fn bounded_metadata(
+		name: Vec<u8>,
+		symbol: Vec<u8>,
+		decimals: u8,
+		existential_deposit: BalanceOf<T>,
+	) -> Result<AssetMetadataOf<T>, DispatchError> {
+		Ok(AssetMetadata {
+			name: name.try_into().map_err(|_| Error::<T>::BadMetadata)?,
+			symbol: symbol.try_into().map_err(|_| Error::<T>::BadMetadata)?,
+			decimals,
+			existential_deposit,
+		})
+	}
+}
+
+impl<T: Config> OnDust<AccountIdOf<T>, CurrencyId, BalanceOf<T>> for Pallet<T> {
+	fn on_dust(who: &AccountIdOf<T>, currency_id: CurrencyId, amount: BalanceOf<T>) {
+		match T::MultiCurrency::transfer(currency_id, who, &T::DustReceiver::get(), amount) {
+			Ok(_) =>
+				Self::deposit_event(Event::<T>::DustSwept { currency_id, who: who.clone(), amount }),
+			Err(err) => log::warn!("unable to sweep dust of {:?}: {:?}", currency_id, err),
+		}
+	}
+}
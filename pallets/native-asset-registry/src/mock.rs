@@ -0,0 +1,175 @@
+use super::*;
+
+use frame_support::{
+	construct_runtime, parameter_types,
+	sp_runtime::traits::{BlakeTwo256, IdentityLookup},
+	traits::{Contains, Everything},
+};
+
+use frame_system::EnsureRoot;
+use primitives::{AccountId, Amount, Balance, BlockNumber, CurrencyId, Header, Index};
+use sp_core::H256;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+parameter_types! {
+	pub const BlockHashCount: BlockNumber = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = Everything;
+
+	type BlockWeights = ();
+
+	type BlockLength = ();
+
+	type Origin = Origin;
+
+	type Call = Call;
+
+	type Index = Index;
+
+	type BlockNumber = BlockNumber;
+
+	type Hash = H256;
+
+	type Hashing = BlakeTwo256;
+
+	type AccountId = AccountId;
+
+	type Lookup = IdentityLookup<Self::AccountId>;
+
+	type Header = Header;
+
+	type Event = Event;
+
+	type BlockHashCount = BlockHashCount;
+
+	type DbWeight = ();
+
+	type Version = ();
+
+	type PalletInfo = PalletInfo;
+
+	type AccountData = orml_tokens::AccountData<Balance>;
+
+	type OnNewAccount = ();
+
+	type OnKilledAccount = ();
+
+	type SystemWeightInfo = ();
+
+	type SS58Prefix = ();
+
+	type OnSetCode = ();
+
+	type MaxConsumers = ConstU32<1>;
+}
+
+parameter_types! {
+	pub const DustReceiver: AccountId = TREASURY;
+}
+
+pub struct DustRemovalWhitelist;
+
+impl Contains<AccountId> for DustRemovalWhitelist {
+	fn contains(who: &AccountId) -> bool {
+		*who == DustReceiver::get()
+	}
+}
+
+orml_traits::parameter_type_with_key! {
+	pub ExistentialDeposits: |currency_id: CurrencyId| -> Balance {
+		NativeAssetRegistry::existential_deposit(*currency_id).unwrap_or(Balance::max_value())
+	};
+}
+
+pub type ReserveIdentifier = [u8; 8];
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+
+	type Balance = Balance;
+
+	type Amount = Amount;
+
+	type CurrencyId = CurrencyId;
+
+	type WeightInfo = ();
+
+	type ExistentialDeposits = ExistentialDeposits;
+
+	type OnDust = NativeAssetRegistry;
+
+	type MaxLocks = ();
+
+	type DustRemovalWhitelist = DustRemovalWhitelist;
+
+	type MaxReserves = ConstU32<2>;
+
+	type ReserveIdentifier = ReserveIdentifier;
+
+	type OnNewTokenAccount = ();
+
+	type OnKilledTokenAccount = ();
+}
+
+impl Config for Runtime {
+	type Event = Event;
+
+	type AllowedOrigin = EnsureRoot<AccountId>;
+
+	type MultiCurrency = Tokens;
+
+	type StringLimit = ConstU32<8>;
+
+	type DustReceiver = DustReceiver;
+
+	type WeightInfo = ();
+}
+
+construct_runtime!(
+
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		NativeAssetRegistry: crate,
+	}
+);
+
+pub const ALICE: AccountId = AccountId::new([1u8; 32]);
+pub const BOB: AccountId = AccountId::new([2u8; 32]);
+pub const TREASURY: AccountId = AccountId::new([9u8; 32]);
+
+#[derive(Default)]
+pub struct ExtBuilder {
+	assets: Vec<(u32, Vec<u8>, Vec<u8>, u8, Balance)>,
+}
+
+impl ExtBuilder {
+	pub fn assets(mut self, assets: Vec<(u32, Vec<u8>, Vec<u8>, u8, Balance)>) -> Self {
+		self.assets = assets;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		// construct test storage for the mock runtime
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+
+		GenesisBuild::<Runtime>::assimilate_storage(
+			&crate::GenesisConfig { assets: self.assets },
+			&mut t,
+		)
+		.expect("unable to build genesis");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+
+		ext
+	}
+}
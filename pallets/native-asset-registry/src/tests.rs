@@ -0,0 +1,237 @@
+use super::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use primitives::{CurrencyId, TokenId};
+
+#[test]
+fn test_create_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(NativeAssetRegistry::next_token_id(), 2);
+
+		assert_ok!(NativeAssetRegistry::create_asset(
+			Origin::root(),
+			b"Partner".to_vec(),
+			b"PTN".to_vec(),
+			12,
+			10
+		));
+
+		assert_eq!(NativeAssetRegistry::next_token_id(), 3);
+		assert_eq!(
+			NativeAssetRegistry::metadata(CurrencyId::NativeToken(TokenId::Custom(2))),
+			Some(AssetMetadata {
+				name: b"Partner".to_vec(),
+				symbol: b"PTN".to_vec(),
+				decimals: 12,
+				existential_deposit: 10,
+			})
+		);
+		assert_eq!(
+			NativeAssetRegistry::custom_assets(),
+			vec![CurrencyId::NativeToken(TokenId::Custom(2))]
+		);
+
+		// name exceeding StringLimit is rejected
+		assert_noop!(
+			NativeAssetRegistry::create_asset(
+				Origin::root(),
+				b"Partner Token".to_vec(),
+				b"PTN".to_vec(),
+				12,
+				10
+			),
+			Error::<Runtime>::BadMetadata
+		);
+
+		assert_noop!(
+			NativeAssetRegistry::create_asset(
+				Origin::signed(ALICE),
+				b"Partner".to_vec(),
+				b"PTN".to_vec(),
+				12,
+				10
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn test_update_asset() {
+	ExtBuilder::default()
+		.assets(vec![(2, b"Partner".to_vec(), b"PTN".to_vec(), 12, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(NativeAssetRegistry::update_asset(
+				Origin::root(),
+				2,
+				b"Partner".to_vec(),
+				b"PTN2".to_vec(),
+				18,
+				1
+			));
+
+			let metadata = NativeAssetRegistry::asset_metadata(2).expect("asset should exist");
+			assert_eq!(metadata.symbol.into_inner(), b"PTN2".to_vec());
+			assert_eq!(metadata.decimals, 18);
+			assert_eq!(
+				NativeAssetRegistry::existential_deposit(CurrencyId::NativeToken(TokenId::Custom(
+					2
+				))),
+				Some(1)
+			);
+
+			assert_noop!(
+				NativeAssetRegistry::update_asset(
+					Origin::root(),
+					3,
+					b"Partner".to_vec(),
+					b"PTN".to_vec(),
+					18,
+					1
+				),
+				Error::<Runtime>::AssetNotFound
+			);
+		});
+}
+
+#[test]
+fn test_mint_and_burn() {
+	let token = CurrencyId::NativeToken(TokenId::Custom(2));
+	ExtBuilder::default()
+		.assets(vec![(2, b"Partner".to_vec(), b"PTN".to_vec(), 12, 10)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(NativeAssetRegistry::next_token_id(), 3);
+
+			assert_ok!(NativeAssetRegistry::mint(Origin::root(), 2, ALICE, 1000));
+			assert_eq!(Tokens::free_balance(token, &ALICE), 1000);
+			assert_eq!(Tokens::total_issuance(token), 1000);
+
+			// amount below the registered existential deposit can't create an account
+			assert!(NativeAssetRegistry::mint(Origin::root(), 2, BOB, 5).is_err());
+
+			assert_ok!(NativeAssetRegistry::burn(Origin::root(), 2, ALICE, 400));
+			assert_eq!(Tokens::free_balance(token, &ALICE), 600);
+			assert_eq!(Tokens::total_issuance(token), 600);
+
+			assert_noop!(
+				NativeAssetRegistry::mint(Origin::root(), 3, ALICE, 1000),
+				Error::<Runtime>::AssetNotFound
+			);
+			assert_noop!(
+				NativeAssetRegistry::mint(Origin::signed(ALICE), 2, ALICE, 1000),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn test_builtin_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = NativeAssetRegistry::metadata(CurrencyId::NativeToken(TokenId::Laguna))
+			.expect("built-in token should have metadata");
+
+		assert_eq!(metadata.symbol, b"LAGUNA".to_vec());
+		assert_eq!(metadata.decimals, 18);
+
+		assert_eq!(
+			NativeAssetRegistry::metadata(CurrencyId::NativeToken(TokenId::Custom(5))),
+			None
+		);
+	});
+}
+
+#[test]
+fn test_set_existential_deposit() {
+	let laguna = CurrencyId::NativeToken(TokenId::Laguna);
+	ExtBuilder::default()
+		.assets(vec![(2, b"Partner".to_vec(), b"PTN".to_vec(), 12, 10)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(NativeAssetRegistry::existential_deposit(laguna), Some(0));
+
+			assert_ok!(NativeAssetRegistry::set_existential_deposit(Origin::root(), laguna, 100));
+			System::assert_last_event(Event::NativeAssetRegistry(
+				crate::Event::ExistentialDepositSet {
+					currency_id: laguna,
+					existential_deposit: 100,
+				},
+			));
+			assert_eq!(NativeAssetRegistry::existential_deposit(laguna), Some(100));
+			assert_eq!(Tokens::minimum_balance(laguna), 100);
+
+			let token = CurrencyId::NativeToken(TokenId::Custom(2));
+			assert_ok!(NativeAssetRegistry::set_existential_deposit(Origin::root(), token, 20));
+			assert_eq!(NativeAssetRegistry::asset_metadata(2).unwrap().existential_deposit, 20);
+
+			assert_noop!(
+				NativeAssetRegistry::set_existential_deposit(
+					Origin::root(),
+					CurrencyId::NativeToken(TokenId::Custom(3)),
+					20
+				),
+				Error::<Runtime>::AssetNotFound
+			);
+			assert_noop!(
+				NativeAssetRegistry::set_existential_deposit(
+					Origin::root(),
+					CurrencyId::Erc20([0u8; 32]),
+					20
+				),
+				Error::<Runtime>::NotNativeAsset
+			);
+			assert_noop!(
+				NativeAssetRegistry::set_existential_deposit(Origin::signed(ALICE), laguna, 1),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn test_dust_swept() {
+	let laguna = CurrencyId::NativeToken(TokenId::Laguna);
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NativeAssetRegistry::set_existential_deposit(Origin::root(), laguna, 100));
+		assert_ok!(Tokens::deposit(laguna, &TREASURY, 1000));
+		assert_ok!(Tokens::deposit(laguna, &ALICE, 1000));
+
+		// the remaining 50 is below the existential deposit
+		assert_ok!(Tokens::transfer(laguna, &ALICE, &BOB, 950));
+		System::assert_has_event(Event::NativeAssetRegistry(crate::Event::DustSwept {
+			currency_id: laguna,
+			who: ALICE,
+			amount: 50,
+		}));
+
+		assert_eq!(Tokens::free_balance(laguna, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(laguna, &TREASURY), 1050);
+		assert_eq!(Tokens::total_issuance(laguna), 2000);
+	});
+}
+
+#[test]
+fn test_dust_swept_to_unfunded_receiver() {
+	let laguna = CurrencyId::NativeToken(TokenId::Laguna);
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NativeAssetRegistry::set_existential_deposit(Origin::root(), laguna, 100));
+		assert_ok!(Tokens::deposit(laguna, &ALICE, 1000));
+		assert_eq!(Tokens::free_balance(laguna, &TREASURY), 0);
+
+		// the receiver is whitelisted, so it takes dust below the existential deposit
+		assert_ok!(Tokens::transfer(laguna, &ALICE, &BOB, 950));
+		System::assert_has_event(Event::NativeAssetRegistry(crate::Event::DustSwept {
+			currency_id: laguna,
+			who: ALICE,
+			amount: 50,
+		}));
+
+		assert_eq!(Tokens::free_balance(laguna, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(laguna, &TREASURY), 50);
+		assert_eq!(Tokens::total_issuance(laguna), 1000);
+
+		// and is never reaped itself
+		assert_ok!(Tokens::transfer(laguna, &TREASURY, &BOB, 20));
+		assert_eq!(Tokens::free_balance(laguna, &TREASURY), 30);
+	});
+}
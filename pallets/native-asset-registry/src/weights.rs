@@ -0,0 +1,35 @@
+use frame_support::weights::Weight;
+
+pub trait WeightInfo {
+	fn create_asset() -> Weight;
+
+	fn update_asset() -> Weight;
+
+	fn mint() -> Weight;
+
+	fn burn() -> Weight;
+
+	fn set_existential_deposit() -> Weight;
+}
+
+impl WeightInfo for () {
+	fn create_asset() -> Weight {
+		1000_u64
+	}
+
+	fn update_asset() -> Weight {
+		1000_u64
+	}
+
+	fn mint() -> Weight {
+		1000_u64
+	}
+
+	fn burn() -> Weight {
+		1000_u64
+	}
+
+	fn set_existential_deposit() -> Weight {
+		1000_u64
+	}
+}
@@ -1,8 +1,14 @@
 //! If a contract is being deployed from the pallet-system-contract-deployer then
 //! the salt is expected to contain a 32-byte encoded value of the destined address
-//! otherwise the DefaultAddressGenerator provided in the pallet-contract is used
+//! otherwise the DefaultAddressGenerator provided in the pallet-contract is used, moving the
+//! address out of `ReservedAddresses` if needed
 
-use frame_support::{pallet_prelude::Decode, sp_runtime, traits::Get};
+use frame_support::{
+	pallet_prelude::Decode,
+	sp_runtime,
+	sp_std::{marker::PhantomData, prelude::*},
+	traits::{Contains, Get},
+};
 use pallet_contracts::{AddressGenerator, DefaultAddressGenerator};
 use sp_core::crypto::UncheckedFrom;
 use sp_runtime::{traits::AccountIdConversion, AccountId32};
@@ -32,10 +38,33 @@ where
 				.expect("Cannot create an AccountId from the given salt")
 		}
 
-		<DefaultAddressGenerator as AddressGenerator<T>>::generate_address(
-			deploying_address,
-			code_hash,
-			salt,
-		)
+		let mut salt = salt.to_vec();
+		loop {
+			let address = <DefaultAddressGenerator as AddressGenerator<T>>::generate_address(
+				deploying_address,
+				code_hash,
+				&salt,
+			);
+			if !T::ReservedAddresses::contains(&address) {
+				return address
+			}
+			salt.push(0);
+		}
+	}
+}
+
+/// the first `N` sequential addresses, starting from 0x01, as assigned to system contracts
+/// deployed without a destined address
+pub struct SequentialAddresses<N>(PhantomData<N>);
+
+impl<N: Get<u32>, AccountId: AsRef<[u8]>> Contains<AccountId> for SequentialAddresses<N> {
+	fn contains(address: &AccountId) -> bool {
+		let raw = address.as_ref();
+		if raw.len() != 32 || raw[..28].iter().any(|b| *b != 0) {
+			return false
+		}
+
+		let index = u32::from_be_bytes(raw[28..].try_into().expect("slice of 4 bytes"));
+		(1..=N::get()).contains(&index)
 	}
 }
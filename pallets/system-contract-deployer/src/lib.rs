@@ -2,6 +2,20 @@
 //!
 //! This pallet allows system contracts to be deployed at fixed addresses.
 //! It is tightly-coupled with the pallet-contract and exposes privileged extrinsics
+//!
+//! System contracts can be upgraded in place by `UpgradeOrigin`, which swaps their code hash while
+//! preserving their address and storage, optionally calling a migration method of the new code.
+//! The code hashes replaced by upgrades are kept on-chain.
+//!
+//! Addresses matching `ReservedAddresses` are only reachable by system contracts, user
+//! deployments which would land in them are moved out of the range by the address generator.
+//! A system contract can be revoked, which removes its privileges without touching the contract.
+//!
+//! Privileged chain extension functions are unlocked by `Capability`, which are granted to each
+//! system contract individually by `DeployOrigin`. Dispatching runtime calls and scheduling them
+//! are not offered to contracts yet: a dispatched call could reenter the contracts pallet from
+//! within a contract execution, their capabilities are to be added along with the functions
+//! checking them.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -14,17 +28,49 @@ mod mock;
 mod tests;
 
 mod fixed_address;
-pub use fixed_address::CustomAddressGenerator;
+pub use fixed_address::{CustomAddressGenerator, SequentialAddresses};
+
+pub mod migrations;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// privilege of a system contract, checked by the chain extension functions it unlocks
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Capability {
+	/// move native tokens on behalf of any account
+	NativeTransferFrom,
+	/// be recognized as whitelisted by other contracts
+	Whitelisted,
+	/// set the default fee source of any account
+	FeePreference,
+}
+
+/// number of `Capability` variants, bounds the capabilities held by a contract
+pub const MAX_CAPABILITIES: u64 = 3;
 
 #[frame_support::pallet]
 pub mod pallet {
+	use super::{Capability, MAX_CAPABILITIES};
 	use codec::HasCompact;
-	use frame_support::{pallet_prelude::*, sp_runtime, sp_std, traits::Currency, PalletId};
+	use frame_support::{
+		dispatch::DispatchErrorWithPostInfo,
+		pallet_prelude::*,
+		sp_runtime, sp_std,
+		traits::{Contains, Currency},
+		PalletId,
+	};
 	use frame_system::{pallet_prelude::*, RawOrigin};
 	use pallet_contracts::weights::WeightInfo;
+	use pallet_contracts_primitives::Code;
 	use sp_core::crypto::UncheckedFrom;
 	use sp_runtime::{
-		traits::{AccountIdConversion, Hash},
+		traits::{AccountIdConversion, Hash, StaticLookup},
 		AccountId32,
 	};
 	use sp_std::{fmt::Debug, vec::Vec};
@@ -41,10 +87,27 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
+
+		/// origin allowed to deploy, revoke system contracts and manage their code
+		type DeployOrigin: EnsureOrigin<Self::Origin>;
+
+		/// origin allowed to upgrade the code of system contracts
+		type UpgradeOrigin: EnsureOrigin<Self::Origin>;
+
+		/// addresses user contracts can't be deployed at
+		type ReservedAddresses: Contains<Self::AccountId>;
+
+		/// number of replaced code hashes kept for each system contract
+		#[pallet::constant]
+		type MaxCodeHashHistory: Get<u32>;
 	}
 
+	/// capabilities were introduced by version 1
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -52,15 +115,63 @@ pub mod pallet {
 	pub type SystemContracts<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
 
+	/// capabilities granted to each system contract
+	#[pallet::storage]
+	pub type Capabilities<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		Capability,
+		bool,
+		ValueQuery,
+	>;
+
 	// Stores the next possible sequential address value in integer form
 	#[pallet::storage]
 	pub type NextAddress<T: Config> = StorageValue<_, u32>;
 
+	/// code hashes replaced by upgrades along with the block of the upgrade, oldest first
+	#[pallet::storage]
+	#[pallet::getter(fn code_hash_history)]
+	pub type CodeHashHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(T::BlockNumber, CodeHash<T>), T::MaxCodeHashHistory>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// [contract_address]
 		Created(T::AccountId),
+		Upgraded {
+			addr: T::AccountId,
+			old: CodeHash<T>,
+			new: CodeHash<T>,
+		},
+		/// the contract is no longer a system contract
+		Revoked {
+			addr: T::AccountId,
+		},
+		CapabilityGranted {
+			addr: T::AccountId,
+			capability: Capability,
+		},
+		CapabilityRevoked {
+			addr: T::AccountId,
+			capability: Capability,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		NotSystemContract,
+		/// the migration method of the new code reverted or failed
+		MigrationFailed,
+		CapabilityNotGranted,
 	}
 
 	#[pallet::call]
@@ -87,7 +198,7 @@ pub mod pallet {
 			data: Vec<u8>,
 			destined_address: Option<[u8; 32]>,
 		) -> DispatchResultWithPostInfo {
-			ensure_root(origin)?;
+			T::DeployOrigin::ensure_origin(origin)?;
 			let destined_address =
 				destined_address.unwrap_or_else(|| Self::get_next_available_bytes());
 
@@ -135,7 +246,7 @@ pub mod pallet {
 			data: Vec<u8>,
 			destined_address: Option<[u8; 32]>,
 		) -> DispatchResultWithPostInfo {
-			ensure_root(origin)?;
+			T::DeployOrigin::ensure_origin(origin)?;
 			let destined_address =
 				destined_address.unwrap_or_else(|| Self::get_next_available_bytes());
 
@@ -163,6 +274,127 @@ pub mod pallet {
 			output
 		}
 
+		/// Replace the code of a system contract with the previously uploaded `code_hash`, keeping
+		/// its address and storage.
+		///
+		/// If `migrate_data` is provided the contract is called with it right after the swap, the
+		/// upgrade is reverted if the migration fails.
+		#[pallet::weight(
+			T::WeightInfo::set_code()
+			.saturating_add(T::WeightInfo::call())
+			.saturating_add(*gas_limit)
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+		)]
+		pub fn upgrade_system_contract(
+			origin: OriginFor<T>,
+			addr: T::AccountId,
+			code_hash: CodeHash<T>,
+			migrate_data: Option<Vec<u8>>,
+			#[pallet::compact] gas_limit: Weight,
+		) -> DispatchResultWithPostInfo {
+			T::UpgradeOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_system_contract(addr.clone()), Error::<T>::NotSystemContract);
+
+			let old = pallet_contracts::Pallet::<T>::code_hash(&addr)
+				.ok_or(Error::<T>::NotSystemContract)?;
+
+			pallet_contracts::Pallet::<T>::set_code(
+				RawOrigin::Root.into(),
+				T::Lookup::unlookup(addr.clone()),
+				code_hash,
+			)?;
+
+			let mut weight =
+				T::WeightInfo::set_code().saturating_add(T::DbWeight::get().reads_writes(2, 1));
+
+			if let Some(data) = migrate_data {
+				let output = pallet_contracts::Pallet::<T>::bare_call(
+					T::PalletId::get().try_into_account().expect("Invalid PalletId"),
+					addr.clone(),
+					Default::default(),
+					gas_limit,
+					None,
+					data,
+					false,
+				);
+				weight = weight
+					.saturating_add(T::WeightInfo::call())
+					.saturating_add(output.gas_consumed);
+
+				ensure!(
+					output.result.map(|v| !v.did_revert()).unwrap_or_default(),
+					DispatchErrorWithPostInfo {
+						post_info: Some(weight).into(),
+						error: Error::<T>::MigrationFailed.into(),
+					}
+				);
+			}
+
+			CodeHashHistory::<T>::mutate(&addr, |history| {
+				// the oldest entry is dropped once the history is full
+				if history.len() as u32 >= T::MaxCodeHashHistory::get() {
+					history.remove(0);
+				}
+				let _ = history.try_push((frame_system::Pallet::<T>::block_number(), old));
+			});
+
+			Self::deposit_event(Event::<T>::Upgraded { addr, old, new: code_hash });
+			Ok(Some(weight).into())
+		}
+
+		/// Remove the system contract flag of `addr`, revoking its privileges.
+		///
+		/// The contract itself is left untouched, each capability it held is revoked.
+		#[pallet::weight(
+			T::DbWeight::get().reads_writes(1 + MAX_CAPABILITIES, 1 + MAX_CAPABILITIES)
+		)]
+		pub fn revoke_system_contract(origin: OriginFor<T>, addr: T::AccountId) -> DispatchResult {
+			T::DeployOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_system_contract(addr.clone()), Error::<T>::NotSystemContract);
+
+			SystemContracts::<T>::remove(&addr);
+			for (capability, _) in Capabilities::<T>::drain_prefix(&addr) {
+				Self::deposit_event(Event::<T>::CapabilityRevoked {
+					addr: addr.clone(),
+					capability,
+				});
+			}
+
+			Self::deposit_event(Event::<T>::Revoked { addr });
+			Ok(())
+		}
+
+		/// Grant a privileged chain extension `capability` to a system contract.
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn grant_capability(
+			origin: OriginFor<T>,
+			addr: T::AccountId,
+			capability: Capability,
+		) -> DispatchResult {
+			T::DeployOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_system_contract(addr.clone()), Error::<T>::NotSystemContract);
+
+			Capabilities::<T>::insert(&addr, capability, true);
+
+			Self::deposit_event(Event::<T>::CapabilityGranted { addr, capability });
+			Ok(())
+		}
+
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn revoke_capability(
+			origin: OriginFor<T>,
+			addr: T::AccountId,
+			capability: Capability,
+		) -> DispatchResult {
+			T::DeployOrigin::ensure_origin(origin)?;
+			ensure!(Capabilities::<T>::get(&addr, capability), Error::<T>::CapabilityNotGranted);
+
+			Capabilities::<T>::remove(&addr, capability);
+
+			Self::deposit_event(Event::<T>::CapabilityRevoked { addr, capability });
+			Ok(())
+		}
+
 		/// Upload new `code` without instantiating a contract from it.
 		#[pallet::weight(T::WeightInfo::upload_code(code.len() as u32))]
 		pub fn upload_code(
@@ -170,7 +402,7 @@ pub mod pallet {
 			code: Vec<u8>,
 			storage_deposit_limit: Option<<BalanceOf<T> as codec::HasCompact>::Type>,
 		) -> DispatchResult {
-			ensure_root(origin)?;
+			T::DeployOrigin::ensure_origin(origin)?;
 
 			pallet_contracts::Pallet::<T>::upload_code(
 				RawOrigin::Signed(T::PalletId::get().try_into_account().expect("Invalid PalletId"))
@@ -189,7 +421,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			code_hash: CodeHash<T>,
 		) -> DispatchResultWithPostInfo {
-			ensure_root(origin)?;
+			T::DeployOrigin::ensure_origin(origin)?;
 
 			pallet_contracts::Pallet::<T>::remove_code(
 				RawOrigin::Signed(T::PalletId::get().try_into_account().expect("Invalid PalletId"))
@@ -205,6 +437,15 @@ pub mod pallet {
 			SystemContracts::<T>::iter_keys().collect()
 		}
 
+		/// whether `addr` is a system contract holding the capability
+		pub fn has_capability(addr: &T::AccountId, capability: Capability) -> bool {
+			Self::is_system_contract(addr.clone()) && Capabilities::<T>::get(addr, capability)
+		}
+
+		pub fn capabilities(addr: &T::AccountId) -> Vec<Capability> {
+			Capabilities::<T>::iter_key_prefix(addr).collect()
+		}
+
 		// Helper function used to find the bytes of the next available sequential address
 		fn get_next_available_bytes() -> [u8; 32] {
 			let mut counter = NextAddress::<T>::get().unwrap_or(1);
@@ -214,7 +455,10 @@ pub mod pallet {
 				hex::decode_to_slice(hex, &mut byte).unwrap();
 				let addr = AccountId32::from(byte);
 				let addr = T::AccountId::decode(&mut addr.as_ref()).unwrap();
-				if !Self::is_system_contract(addr.clone()) {
+				// revoked system contracts keep their address
+				if !Self::is_system_contract(addr.clone()) &&
+					pallet_contracts::Pallet::<T>::code_hash(&addr).is_none()
+				{
 					return byte
 				}
 				counter += 1;
@@ -229,11 +473,15 @@ pub mod pallet {
 		}
 	}
 
+	/// system contracts instantiated at genesis, `addr[i]` is the destined address of a contract
+	/// built from `code[i]` with the constructor `data[i]` and granted `capabilities[i]`
 	#[pallet::genesis_config]
 	pub struct GenesisConfig {
 		pub addr: Vec<[u8; 32]>,
 		pub code: Vec<Vec<u8>>,
 		pub data: Vec<Vec<u8>>,
+		/// may be shorter than `addr`, missing entries grant no capability
+		pub capabilities: Vec<Vec<Capability>>,
 		pub gas_limit: Weight,
 	}
 
@@ -244,6 +492,7 @@ pub mod pallet {
 				addr: Default::default(),
 				code: Default::default(),
 				data: Default::default(),
+				capabilities: Default::default(),
 				gas_limit: Default::default(),
 			}
 		}
@@ -256,18 +505,37 @@ pub mod pallet {
 	{
 		fn build(&self) {
 			assert_eq!(self.addr.len(), self.code.len());
-			let sz = self.addr.len();
+			assert_eq!(self.addr.len(), self.data.len());
 
 			// The first available destined address is set to 0x01
 			NextAddress::<T>::put(1);
 
-			for i in 0..sz {
-				pallet_contracts::Pallet::<T>::bare_upload_code(
-					T::PalletId::get().try_into_account().expect("Invalid PalletId"),
-					self.code[i].clone(),
+			// the deployer is expected to be endowed to pay for the storage deposits
+			let deployer: AccountIdOf<T> =
+				T::PalletId::get().try_into_account().expect("Invalid PalletId");
+
+			for (i, ((addr, code), data)) in
+				self.addr.iter().zip(&self.code).zip(&self.data).enumerate()
+			{
+				let output = pallet_contracts::Pallet::<T>::bare_instantiate(
+					deployer.clone(),
+					Default::default(),
+					self.gas_limit,
 					None,
+					Code::Upload(code.clone().into()),
+					data.clone(),
+					addr.to_vec(),
+					false,
 				)
-				.expect("Code not uploaded");
+				.result
+				.expect("System contract not instantiated");
+				assert!(!output.result.did_revert(), "System contract constructor reverted");
+
+				// the final address is derived from the destined one by the address generator
+				SystemContracts::<T>::insert(&output.account_id, true);
+				for capability in self.capabilities.get(i).into_iter().flatten() {
+					Capabilities::<T>::insert(&output.account_id, capability, true);
+				}
 			}
 		}
 	}
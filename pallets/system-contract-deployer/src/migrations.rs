@@ -0,0 +1,33 @@
+//! Storage migrations of the pallet
+
+use crate::{Capabilities, Capability, Config, Pallet, SystemContracts};
+use frame_support::{
+	sp_std::marker::PhantomData,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+
+/// System contracts deployed before capabilities were introduced could move native tokens on
+/// behalf of any account, they are granted `NativeTransferFrom` to keep working.
+pub mod v1 {
+	use super::*;
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut migrated = 0u64;
+			for addr in SystemContracts::<T>::iter_keys() {
+				Capabilities::<T>::insert(&addr, Capability::NativeTransferFrom, true);
+				migrated += 1;
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(migrated + 1, migrated + 1)
+		}
+	}
+}
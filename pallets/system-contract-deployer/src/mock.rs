@@ -14,8 +14,9 @@ use frame_support::{
 	pallet_prelude::{ConstU32, Weight},
 	PalletId,
 };
+use frame_system::EnsureRoot;
 use pallet_contracts::{weights::WeightInfo, DefaultContractAccessWeight};
-use pallet_system_contract_deployer::CustomAddressGenerator;
+use pallet_system_contract_deployer::{CustomAddressGenerator, SequentialAddresses};
 use pallet_transaction_payment::CurrencyAdapter;
 use primitives::{AccountId, Balance, BlockNumber, Hash, Header, Index};
 use sp_runtime::Perbill;
@@ -161,6 +162,10 @@ parameter_types! {
 impl pallet_system_contract_deployer::Config for Test {
 	type Event = Event;
 	type PalletId = PId;
+	type DeployOrigin = EnsureRoot<AccountId>;
+	type UpgradeOrigin = EnsureRoot<AccountId>;
+	type ReservedAddresses = SequentialAddresses<ConstU32<1024>>;
+	type MaxCodeHashHistory = ConstU32<16>;
 }
 
 construct_runtime!(
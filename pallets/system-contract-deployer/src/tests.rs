@@ -1,5 +1,8 @@
 use super::*;
-use frame_support::{assert_err, assert_ok, error, sp_runtime};
+use frame_support::{
+	assert_err, assert_ok, error, sp_runtime,
+	traits::{Contains, GenesisBuild, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
 use mock::{
 	Call, Event, ExtBuilder, Origin, Sudo, System, SystemContractDeployer, Test, ALICE, UNIT,
 };
@@ -155,3 +158,390 @@ fn test_only_root_access() {
 		);
 	})
 }
+
+#[test]
+fn test_genesis_instantiation() {
+	let deploying_key = <Test as crate::Config>::PalletId::get()
+		.try_into_account()
+		.expect("Invalid PalletId");
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (deploying_key, UNIT)])
+		.build()
+		.execute_with(|| {
+			let blob = std::fs::read(
+				"../../runtime/integration-tests/contracts-data/ink/basic/dist/basic.wasm",
+			)
+			.expect("cound not find wasm blob");
+
+			let sel_constructor = Bytes::from_str("0xed4b9d1b")
+				.map(|v| v.to_vec())
+				.expect("unable to parse hex string");
+
+			GenesisBuild::<Test>::build(&GenesisConfig {
+				addr: vec![[0x22; 32]],
+				code: vec![blob],
+				data: vec![sel_constructor],
+				capabilities: vec![vec![Capability::Whitelisted]],
+				gas_limit: MAX_GAS,
+			});
+
+			let contract = AccountId32::from([0x22; 32]);
+			assert!(SystemContractDeployer::is_system_contract(contract.clone()));
+			assert!(pallet_contracts::Pallet::<Test>::code_hash(&contract).is_some());
+			assert_eq!(
+				SystemContractDeployer::capabilities(&contract),
+				vec![Capability::Whitelisted]
+			);
+			assert_eq!(
+				SystemContractDeployer::get_next_available_address(),
+				AccountId32::from_str(&format!("{:064x}", 1)).unwrap()
+			);
+		})
+}
+
+#[test]
+fn test_upgrade_system_contract() {
+	let deploying_key = <Test as crate::Config>::PalletId::get()
+		.try_into_account()
+		.expect("Invalid PalletId");
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (deploying_key, UNIT)])
+		.sudo(ALICE)
+		.build()
+		.execute_with(|| {
+			let blob = std::fs::read(
+				"../../runtime/integration-tests/contracts-data/ink/basic/dist/basic.wasm",
+			)
+			.expect("cound not find wasm blob");
+			let new_blob = std::fs::read(
+				"../../runtime/integration-tests/contracts-data/ink/native_fungible_token/dist/native_fungible_token.wasm",
+			)
+			.expect("cound not find wasm blob");
+
+			let sel_constructor = Bytes::from_str("0xed4b9d1b")
+				.map(|v| v.to_vec())
+				.expect("unable to parse hex string");
+
+			assert_ok!(SystemContractDeployer::instantiate_with_code(
+				Origin::root(),
+				0,
+				MAX_GAS,
+				None,
+				blob,
+				sel_constructor,
+				Some([0x11; 32]),
+			));
+			let contract = AccountId32::from([0x11; 32]);
+			let old = pallet_contracts::Pallet::<Test>::code_hash(&contract).unwrap();
+
+			let new = pallet_contracts::Pallet::<Test>::bare_upload_code(ALICE, new_blob, None)
+				.unwrap()
+				.code_hash;
+
+			assert_err!(
+				SystemContractDeployer::upgrade_system_contract(
+					Origin::signed(ALICE),
+					contract.clone(),
+					new,
+					None,
+					MAX_GAS
+				),
+				error::BadOrigin
+			);
+			assert_err!(
+				SystemContractDeployer::upgrade_system_contract(
+					Origin::root(),
+					ALICE,
+					new,
+					None,
+					MAX_GAS
+				),
+				Error::<Test>::NotSystemContract
+			);
+
+			// the swap is reverted along with a failed migration
+			assert!(SystemContractDeployer::upgrade_system_contract(
+				Origin::root(),
+				contract.clone(),
+				new,
+				Some(vec![0xff; 4]),
+				MAX_GAS
+			)
+			.is_err());
+			assert_eq!(pallet_contracts::Pallet::<Test>::code_hash(&contract), Some(old));
+
+			assert_ok!(SystemContractDeployer::upgrade_system_contract(
+				Origin::root(),
+				contract.clone(),
+				new,
+				None,
+				MAX_GAS
+			));
+			assert_eq!(pallet_contracts::Pallet::<Test>::code_hash(&contract), Some(new));
+			assert_eq!(SystemContractDeployer::code_hash_history(&contract).to_vec(), vec![(1, old)]);
+			System::assert_last_event(Event::SystemContractDeployer(crate::Event::Upgraded {
+				addr: contract,
+				old,
+				new,
+			}));
+		})
+}
+
+#[test]
+fn test_revoke_system_contract() {
+	let deploying_key = <Test as crate::Config>::PalletId::get()
+		.try_into_account()
+		.expect("Invalid PalletId");
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (deploying_key, UNIT)])
+		.sudo(ALICE)
+		.build()
+		.execute_with(|| {
+			let blob = std::fs::read(
+				"../../runtime/integration-tests/contracts-data/ink/basic/dist/basic.wasm",
+			)
+			.expect("cound not find wasm blob");
+
+			let sel_constructor = Bytes::from_str("0xed4b9d1b")
+				.map(|v| v.to_vec())
+				.expect("unable to parse hex string");
+
+			assert_ok!(SystemContractDeployer::instantiate_with_code(
+				Origin::root(),
+				0,
+				MAX_GAS,
+				None,
+				blob,
+				sel_constructor,
+				Some([0x11; 32]),
+			));
+			let contract = AccountId32::from([0x11; 32]);
+
+			assert_err!(
+				SystemContractDeployer::revoke_system_contract(
+					Origin::signed(ALICE),
+					contract.clone()
+				),
+				error::BadOrigin
+			);
+			assert_ok!(SystemContractDeployer::revoke_system_contract(
+				Origin::root(),
+				contract.clone()
+			));
+			assert!(!SystemContractDeployer::is_system_contract(contract.clone()));
+			// the contract itself is kept
+			assert!(pallet_contracts::Pallet::<Test>::code_hash(&contract).is_some());
+			System::assert_last_event(Event::SystemContractDeployer(crate::Event::Revoked {
+				addr: contract.clone(),
+			}));
+
+			assert_err!(
+				SystemContractDeployer::revoke_system_contract(Origin::root(), contract),
+				Error::<Test>::NotSystemContract
+			);
+		})
+}
+
+#[test]
+fn test_next_address_after_revoke() {
+	let deploying_key = <Test as crate::Config>::PalletId::get()
+		.try_into_account()
+		.expect("Invalid PalletId");
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (deploying_key, UNIT)])
+		.sudo(ALICE)
+		.build()
+		.execute_with(|| {
+			let blob = std::fs::read(
+				"../../runtime/integration-tests/contracts-data/ink/basic/dist/basic.wasm",
+			)
+			.expect("cound not find wasm blob");
+
+			let sel_constructor = Bytes::from_str("0xed4b9d1b")
+				.map(|v| v.to_vec())
+				.expect("unable to parse hex string");
+
+			let first = AccountId32::from_str(&format!("{:064x}", 1)).unwrap();
+			assert_eq!(SystemContractDeployer::get_next_available_address(), first);
+			assert_ok!(SystemContractDeployer::instantiate_with_code(
+				Origin::root(),
+				0,
+				MAX_GAS,
+				None,
+				blob,
+				sel_constructor,
+				None,
+			));
+			assert!(SystemContractDeployer::is_system_contract(first.clone()));
+
+			assert_ok!(SystemContractDeployer::revoke_system_contract(
+				Origin::root(),
+				first.clone()
+			));
+			// the revoked contract still occupies its address
+			assert!(pallet_contracts::Pallet::<Test>::code_hash(&first).is_some());
+			assert_eq!(
+				SystemContractDeployer::get_next_available_address(),
+				AccountId32::from_str(&format!("{:064x}", 2)).unwrap()
+			);
+		})
+}
+
+#[test]
+fn test_reserved_addresses() {
+	type Reserved = <Test as crate::Config>::ReservedAddresses;
+
+	assert!(Reserved::contains(&AccountId32::from_str(&format!("{:064x}", 1)).unwrap()));
+	assert!(Reserved::contains(&AccountId32::from_str(&format!("{:064x}", 1024)).unwrap()));
+	assert!(!Reserved::contains(&AccountId32::from_str(&format!("{:064x}", 0)).unwrap()));
+	assert!(!Reserved::contains(&AccountId32::from_str(&format!("{:064x}", 1025)).unwrap()));
+	assert!(!Reserved::contains(&AccountId32::from([0x11; 32])));
+}
+
+#[test]
+fn test_capabilities() {
+	let deploying_key = <Test as crate::Config>::PalletId::get()
+		.try_into_account()
+		.expect("Invalid PalletId");
+	ExtBuilder::default()
+		.balances(vec![(ALICE, UNIT), (deploying_key, UNIT)])
+		.sudo(ALICE)
+		.build()
+		.execute_with(|| {
+			let blob = std::fs::read(
+				"../../runtime/integration-tests/contracts-data/ink/basic/dist/basic.wasm",
+			)
+			.expect("cound not find wasm blob");
+
+			let sel_constructor = Bytes::from_str("0xed4b9d1b")
+				.map(|v| v.to_vec())
+				.expect("unable to parse hex string");
+
+			assert_ok!(SystemContractDeployer::instantiate_with_code(
+				Origin::root(),
+				0,
+				MAX_GAS,
+				None,
+				blob,
+				sel_constructor,
+				Some([0x11; 32]),
+			));
+			let contract = AccountId32::from([0x11; 32]);
+
+			// system contracts hold no capability by default
+			assert!(!SystemContractDeployer::has_capability(
+				&contract,
+				Capability::NativeTransferFrom
+			));
+			assert_err!(
+				SystemContractDeployer::grant_capability(
+					Origin::root(),
+					ALICE,
+					Capability::NativeTransferFrom
+				),
+				Error::<Test>::NotSystemContract
+			);
+			assert_err!(
+				SystemContractDeployer::grant_capability(
+					Origin::signed(ALICE),
+					contract.clone(),
+					Capability::NativeTransferFrom
+				),
+				error::BadOrigin
+			);
+
+			assert_ok!(SystemContractDeployer::grant_capability(
+				Origin::root(),
+				contract.clone(),
+				Capability::NativeTransferFrom
+			));
+			assert!(SystemContractDeployer::has_capability(
+				&contract,
+				Capability::NativeTransferFrom
+			));
+			assert!(!SystemContractDeployer::has_capability(&contract, Capability::Whitelisted));
+			assert_ok!(SystemContractDeployer::grant_capability(
+				Origin::root(),
+				contract.clone(),
+				Capability::Whitelisted
+			));
+			System::assert_last_event(Event::SystemContractDeployer(
+				crate::Event::CapabilityGranted {
+					addr: contract.clone(),
+					capability: Capability::Whitelisted,
+				},
+			));
+
+			assert_ok!(SystemContractDeployer::revoke_capability(
+				Origin::root(),
+				contract.clone(),
+				Capability::NativeTransferFrom
+			));
+			assert!(!SystemContractDeployer::has_capability(
+				&contract,
+				Capability::NativeTransferFrom
+			));
+			assert_err!(
+				SystemContractDeployer::revoke_capability(
+					Origin::root(),
+					contract.clone(),
+					Capability::NativeTransferFrom
+				),
+				Error::<Test>::CapabilityNotGranted
+			);
+
+			// revoking the system contract revokes each of its capabilities
+			assert_ok!(SystemContractDeployer::grant_capability(
+				Origin::root(),
+				contract.clone(),
+				Capability::NativeTransferFrom
+			));
+			System::reset_events();
+			assert_ok!(SystemContractDeployer::revoke_system_contract(
+				Origin::root(),
+				contract.clone()
+			));
+			assert!(SystemContractDeployer::capabilities(&contract).is_empty());
+
+			let mut revoked: Vec<_> = System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					Event::SystemContractDeployer(crate::Event::CapabilityRevoked {
+						addr,
+						capability,
+					}) if addr == contract => Some(capability),
+					_ => None,
+				})
+				.collect();
+			revoked.sort_by_key(|capability| *capability as u8);
+			assert_eq!(revoked, vec![Capability::NativeTransferFrom, Capability::Whitelisted]);
+			System::assert_last_event(Event::SystemContractDeployer(crate::Event::Revoked {
+				addr: contract.clone(),
+			}));
+		})
+}
+
+#[test]
+fn test_migrate_to_v1() {
+	ExtBuilder::default().build().execute_with(|| {
+		let contract = AccountId32::from([0x11; 32]);
+		StorageVersion::new(0).put::<SystemContractDeployer>();
+		SystemContracts::<Test>::insert(&contract, true);
+
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(SystemContractDeployer::on_chain_storage_version(), 1);
+		assert_eq!(
+			SystemContractDeployer::capabilities(&contract),
+			vec![Capability::NativeTransferFrom]
+		);
+
+		// the migration only runs once
+		assert_ok!(SystemContractDeployer::revoke_capability(
+			Origin::root(),
+			contract.clone(),
+			Capability::NativeTransferFrom
+		));
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert!(SystemContractDeployer::capabilities(&contract).is_empty());
+	})
+}
@@ -1,36 +1,95 @@
-use frame_support::dispatch::DispatchResultWithPostInfo;
+use codec::{Decode, Encode};
+use frame_support::{sp_runtime::DispatchError, sp_std::vec::Vec, weights::Weight, RuntimeDebug};
+use scale_info::TypeInfo;
 use sp_core::U256;
 
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
-// TODO: distinguish between erc20 token and ink based contracts
+/// reasons for an access to a token contract to fail
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, Encode, Decode)]
+pub enum TokenAccessError {
+	/// the asset is not registered or has been suspended
+	Unregistered,
+	/// the contract reverted the call
+	Reverted,
+	/// the contract returned an error or `false` without reverting the call
+	Rejected,
+	/// the contract ran out of the gas it was given
+	OutOfGas,
+	/// the data returned by the contract can not be decoded
+	DecodeFailed,
+	/// any other error raised while dispatching the call
+	Other(DispatchError),
+}
+
+impl From<DispatchError> for TokenAccessError {
+	fn from(err: DispatchError) -> Self {
+		TokenAccessError::Other(err)
+	}
+}
+
+/// result of an access to a token contract, along with the weight consumed by the contract
+/// whether it succeeded or not
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct TokenAccessResult<R> {
+	pub weight: Weight,
+	pub result: Result<R, TokenAccessError>,
+}
+
+impl<R> TokenAccessResult<R> {
+	pub fn new(weight: Weight, result: Result<R, TokenAccessError>) -> Self {
+		Self { weight, result }
+	}
+
+	/// map the successful result, the weight is kept as is
+	pub fn map<U>(self, f: impl FnOnce(R) -> U) -> TokenAccessResult<U> {
+		TokenAccessResult::new(self.weight, self.result.map(f))
+	}
+}
+
 /// interface to provide access to erc20 based token contract
 pub trait TokenAccess<T: frame_system::Config> {
 	type Balance;
 
-	fn total_supply(asset_address: AccountIdOf<T>) -> Option<Self::Balance>;
+	/// the minimum balance configured for the asset
+	fn minimum_balance(asset_address: AccountIdOf<T>) -> Self::Balance;
+
+	/// upper bound of the weight consumed by a single access to a token contract
+	fn max_weight() -> Weight;
 
-	fn balance_of(asset_address: AccountIdOf<T>, who: AccountIdOf<T>) -> Option<Self::Balance>;
+	fn name(asset_address: AccountIdOf<T>) -> TokenAccessResult<Vec<u8>>;
+
+	fn symbol(asset_address: AccountIdOf<T>) -> TokenAccessResult<Vec<u8>>;
+
+	/// decimals recorded when the asset was registered, the contract is not called
+	fn decimals(asset_address: AccountIdOf<T>) -> TokenAccessResult<u8>;
+
+	fn total_supply(asset_address: AccountIdOf<T>) -> TokenAccessResult<Self::Balance>;
+
+	fn balance_of(
+		asset_address: AccountIdOf<T>,
+		who: AccountIdOf<T>,
+	) -> TokenAccessResult<Self::Balance>;
 
 	fn transfer(
 		asset_address: AccountIdOf<T>,
 		who: AccountIdOf<T>,
 		to: AccountIdOf<T>,
 		amount: U256,
-	) -> DispatchResultWithPostInfo;
+	) -> TokenAccessResult<()>;
 
 	fn allowance(
 		asset_address: AccountIdOf<T>,
 		owner: AccountIdOf<T>,
 		spender: AccountIdOf<T>,
-	) -> Option<Self::Balance>;
+	) -> TokenAccessResult<Self::Balance>;
 
 	fn approve(
 		asset_address: AccountIdOf<T>,
 		owner: AccountIdOf<T>,
 		spender: AccountIdOf<T>,
 		amount: U256,
-	) -> DispatchResultWithPostInfo;
+	) -> TokenAccessResult<()>;
 
 	fn transfer_from(
 		asset_address: AccountIdOf<T>,
@@ -38,5 +97,50 @@ pub trait TokenAccess<T: frame_system::Config> {
 		from: AccountIdOf<T>,
 		to: AccountIdOf<T>,
 		amount: U256,
-	) -> DispatchResultWithPostInfo;
+	) -> TokenAccessResult<()>;
+
+	/// mint new tokens, only available for assets registered as mintable
+	fn mint(
+		asset_address: AccountIdOf<T>,
+		to: AccountIdOf<T>,
+		amount: U256,
+	) -> TokenAccessResult<()>;
+
+	/// burn existing tokens, only available for assets registered as mintable
+	fn burn(
+		asset_address: AccountIdOf<T>,
+		from: AccountIdOf<T>,
+		amount: U256,
+	) -> TokenAccessResult<()>;
+}
+
+/// interface to provide access to erc721 based token collections
+pub trait NonFungibleAccess<T: frame_system::Config> {
+	/// None if the token doesn't exist, for standards which don't revert in that case
+	fn owner_of(
+		collection: AccountIdOf<T>,
+		token_id: U256,
+	) -> TokenAccessResult<Option<AccountIdOf<T>>>;
+
+	/// move the token on behalf of `who`, which is either the owner or an approved operator
+	fn transfer_from(
+		collection: AccountIdOf<T>,
+		who: AccountIdOf<T>,
+		from: AccountIdOf<T>,
+		to: AccountIdOf<T>,
+		token_id: U256,
+	) -> TokenAccessResult<()>;
+
+	fn approve(
+		collection: AccountIdOf<T>,
+		owner: AccountIdOf<T>,
+		spender: AccountIdOf<T>,
+		token_id: U256,
+	) -> TokenAccessResult<()>;
+
+	fn token_uri(collection: AccountIdOf<T>, token_id: U256) -> TokenAccessResult<Vec<u8>>;
+
+	/// tokens owned by the account, only available for enumerable collections
+	fn tokens_of(collection: AccountIdOf<T>, owner: AccountIdOf<T>)
+		-> TokenAccessResult<Vec<U256>>;
 }
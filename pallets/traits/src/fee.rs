@@ -9,6 +9,7 @@ use scale_info::TypeInfo;
 pub enum InvalidFeeSource {
 	Inactive,
 	Unlisted,
+	Frozen,
 }
 
 #[derive(Debug)]
@@ -96,6 +97,8 @@ pub trait FeeAssetHealth {
 
 pub enum EligibilityError {
 	NotAllowed,
+	/// the account or the whole asset is frozen
+	Frozen,
 }
 
 pub trait Eligibility {
@@ -12,10 +12,13 @@
 //!
 //! ## native token id: `TokenId`
 //!
-//! `TokenId` defines Token issued on the platform for various use cases.
+//! `TokenId` defines Token issued on the platform for various use cases. Besides the built-in
+//! tokens, governance is able to issue additional tokens identified by `TokenId::Custom`, their
+//! metadata is stored on-chain instead of being hard-coded here.
 
 use codec::MaxEncodedLen;
-use sp_core::{Decode, Encode, RuntimeDebug};
+use sp_core::{Decode, Encode, RuntimeDebug, H160, U256};
+use sp_std::vec::Vec;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -59,47 +62,149 @@ pub enum CurrencyId {
 pub enum TokenId {
 	Laguna, // Native token of the laguna-chain
 	FeeToken,
+	Custom(u32), // Token issued by governance through the native asset registry
+}
+
+/// interface implemented by a contract-based token
+#[derive(
+	Encode, Decode, RuntimeDebug, Copy, Clone, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TokenStandard {
+	/// solidity erc20 tokens compiled with solang, ink tokens exposing the same selectors, e.g.
+	/// native_fungible_token, are covered as well
+	#[default]
+	Erc20,
+	/// ink tokens following the PSP22 standard
+	Psp22,
+}
+
+/// interface implemented by a contract-based non-fungible token collection
+#[derive(
+	Encode, Decode, RuntimeDebug, Copy, Clone, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum NftStandard {
+	/// solidity erc721 collections compiled with solang
+	#[default]
+	Erc721,
+	/// ink collections following the PSP34 standard, token ids are expected to be `Id::U128`
+	Psp34,
+}
+
+/// tokens held by an account in a single non-fungible collection
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CollectionTokens<AccountId> {
+	pub collection: AccountId,
+	pub tokens: Vec<U256>,
+}
+
+/// on-chain description of an asset, `Text` is left generic so that storage can use a bounded
+/// representation while apis return plain bytes.
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetMetadata<Balance, Text> {
+	pub name: Text,
+	pub symbol: Text,
+	pub decimals: u8,
+	pub existential_deposit: Balance,
+}
+
+/// whether a registered contract asset can currently be used
+#[derive(Encode, Decode, RuntimeDebug, Copy, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AssetStatus {
+	Enabled,
+	Suspended,
+}
+
+/// description of a registered contract asset, as listed by wallets and explorers
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ContractAssetInfo<AccountId, Balance, Hash> {
+	pub address: AccountId,
+	/// address of the contract seen from evm tooling, if it was deployed through evm-compat
+	pub evm_address: Option<H160>,
+	pub status: AssetStatus,
+	/// registered by governance or promoted after its challenge period
+	pub verified: bool,
+	pub standard: TokenStandard,
+	/// None if the name or symbol can't be read from the contract
+	pub metadata: Option<AssetMetadata<Balance, Vec<u8>>>,
+	pub code_hash: Option<Hash>,
+}
+
+/// balances held by an account in a single currency, with the metadata of the currency attached
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetBalance<Balance, Text> {
+	pub currency_id: CurrencyId,
+	pub free: Balance,
+	pub reserved: Balance,
+	pub frozen: Balance,
+	/// (lock id, amount)
+	pub locks: Vec<([u8; 8], Balance)>,
+	pub metadata: Option<AssetMetadata<Balance, Text>>,
+}
+
+/// balance movement of a currency, regardless of whether it's a native or contract-based one
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CurrencyEvent<AccountId, Balance> {
+	Transferred { currency_id: CurrencyId, from: AccountId, to: AccountId, amount: Balance },
+	Deposited { currency_id: CurrencyId, who: AccountId, amount: Balance },
+	Withdrawn { currency_id: CurrencyId, who: AccountId, amount: Balance },
+	Reserved { currency_id: CurrencyId, who: AccountId, amount: Balance },
+	Unreserved { currency_id: CurrencyId, who: AccountId, amount: Balance },
+	Slashed { currency_id: CurrencyId, who: AccountId, amount: Balance },
 }
 
 /// metadata about a issued token, provide additional info about token issued on substrate to evm
+///
+/// only built-in tokens have static metadata, `None` is returned for `TokenId::Custom` tokens,
+/// which should be looked up from the native asset registry instead, and for contract-based tokens.
 pub trait TokenMetadata {
-	fn symbol(&self) -> &'static str;
+	fn symbol(&self) -> Option<&'static str>;
 
-	fn name(&self) -> &'static str;
+	fn name(&self) -> Option<&'static str>;
 
-	fn decimals(&self) -> u8;
+	fn decimals(&self) -> Option<u8>;
 
 	fn is_native(&self) -> bool;
 }
 
 impl TokenMetadata for CurrencyId {
-	fn symbol(&self) -> &'static str {
+	fn symbol(&self) -> Option<&'static str> {
 		match self {
 			CurrencyId::NativeToken(token) => match token {
-				TokenId::Laguna => "LAGUNA",
-				TokenId::FeeToken => "HFEE",
+				TokenId::Laguna => Some("LAGUNA"),
+				TokenId::FeeToken => Some("HFEE"),
+				TokenId::Custom(_) => None,
 			},
-			CurrencyId::Erc20(_) => todo!(),
+			CurrencyId::Erc20(_) => None,
 		}
 	}
 
-	fn name(&self) -> &'static str {
+	fn name(&self) -> Option<&'static str> {
 		match self {
 			CurrencyId::NativeToken(token) => match token {
-				TokenId::Laguna => "LAGUNA",
-				TokenId::FeeToken => "LAGUNA fee",
+				TokenId::Laguna => Some("LAGUNA"),
+				TokenId::FeeToken => Some("LAGUNA fee"),
+				TokenId::Custom(_) => None,
 			},
-			CurrencyId::Erc20(_) => todo!(),
+			CurrencyId::Erc20(_) => None,
 		}
 	}
 
-	fn decimals(&self) -> u8 {
+	fn decimals(&self) -> Option<u8> {
 		match self {
 			CurrencyId::NativeToken(token) => match token {
-				TokenId::Laguna => 18,
-				TokenId::FeeToken => 18,
+				TokenId::Laguna => Some(18),
+				TokenId::FeeToken => Some(18),
+				TokenId::Custom(_) => None,
 			},
-			CurrencyId::Erc20(_) => todo!(),
+			CurrencyId::Erc20(_) => None,
 		}
 	}
 
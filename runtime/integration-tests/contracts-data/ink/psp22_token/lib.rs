@@ -0,0 +1,204 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod psp22_token {
+
+	use ink_prelude::{string::String, vec::Vec};
+	use ink_storage::{traits::SpreadAllocate, Mapping};
+
+	/// errors of the PSP22 standard
+	#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+	#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+	pub enum PSP22Error {
+		Custom(String),
+		InsufficientBalance,
+		InsufficientAllowance,
+		ZeroRecipientAddress,
+		ZeroSenderAddress,
+		SafeTransferCheckFailed(String),
+	}
+
+	/// `Result<(), PSP22Error>` as encoded by the standard, wrapped so ink! doesn't revert the
+	/// call on errors, failed calls return them without reverting
+	#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+	#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+	pub struct Ack(Result<(), PSP22Error>);
+
+	impl From<Result<(), PSP22Error>> for Ack {
+		fn from(result: Result<(), PSP22Error>) -> Self {
+			Ack(result)
+		}
+	}
+
+	#[ink(storage)]
+	#[derive(SpreadAllocate)]
+	pub struct Psp22Token {
+		total_supply: Balance,
+		balances: Mapping<AccountId, Balance>,
+		allowances: Mapping<(AccountId, AccountId), Balance>,
+		name: String,
+		symbol: String,
+		decimals: u8,
+		/// account allowed to mint and burn tokens
+		minter: AccountId,
+	}
+
+	/// Event emitted when a token transfer occurs.
+	#[ink(event)]
+	pub struct Transfer {
+		#[ink(topic)]
+		from: Option<AccountId>,
+		#[ink(topic)]
+		to: Option<AccountId>,
+		value: Balance,
+	}
+
+	/// Event emitted when an approval occurs that `spender` is allowed to withdraw
+	/// up to the amount of `value` tokens from `owner`.
+	#[ink(event)]
+	pub struct Approval {
+		#[ink(topic)]
+		owner: AccountId,
+		#[ink(topic)]
+		spender: AccountId,
+		value: Balance,
+	}
+
+	impl Psp22Token {
+		/// Creates a token with `total_supply` held by the caller
+		#[ink(constructor, selector = 0x9bae9d5e)]
+		pub fn new(
+			total_supply: Balance,
+			name: String,
+			symbol: String,
+			decimals: u8,
+			minter: AccountId,
+		) -> Self {
+			ink_lang::utils::initialize_contract(|contract: &mut Self| {
+				contract.name = name;
+				contract.symbol = symbol;
+				contract.decimals = decimals;
+				contract.minter = minter;
+				contract.mint_to(Self::env().caller(), total_supply);
+			})
+		}
+
+		#[ink(message, selector = 0x162df8c2)]
+		pub fn total_supply(&self) -> Balance {
+			self.total_supply
+		}
+
+		#[ink(message, selector = 0x6568382f)]
+		pub fn balance_of(&self, owner: AccountId) -> Balance {
+			self.balances.get(owner).unwrap_or_default()
+		}
+
+		#[ink(message, selector = 0x4d47d921)]
+		pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+			self.allowances.get((owner, spender)).unwrap_or_default()
+		}
+
+		#[ink(message, selector = 0xdb20f9f5)]
+		pub fn transfer(&mut self, to: AccountId, value: Balance, _data: Vec<u8>) -> Ack {
+			self.transfer_from_to(self.env().caller(), to, value).into()
+		}
+
+		#[ink(message, selector = 0x54b3c76e)]
+		pub fn transfer_from(
+			&mut self,
+			from: AccountId,
+			to: AccountId,
+			value: Balance,
+			_data: Vec<u8>,
+		) -> Ack {
+			let caller = self.env().caller();
+			let allowance = self.allowance(from, caller);
+			if allowance < value {
+				return Err(PSP22Error::InsufficientAllowance).into()
+			}
+			let result = self.transfer_from_to(from, to, value);
+			if result.is_ok() {
+				self.allowances.insert((&from, &caller), &(allowance - value));
+			}
+			result.into()
+		}
+
+		#[ink(message, selector = 0xb20f1bbd)]
+		pub fn approve(&mut self, spender: AccountId, value: Balance) -> Ack {
+			let owner = self.env().caller();
+			self.allowances.insert((&owner, &spender), &value);
+			self.env().emit_event(Approval { owner, spender, value });
+			Ok(()).into()
+		}
+
+		#[ink(message, selector = 0x3d261bd4)]
+		pub fn token_name(&self) -> Option<String> {
+			Some(self.name.clone())
+		}
+
+		#[ink(message, selector = 0x34205be5)]
+		pub fn token_symbol(&self) -> Option<String> {
+			Some(self.symbol.clone())
+		}
+
+		#[ink(message, selector = 0x7271b782)]
+		pub fn token_decimals(&self) -> u8 {
+			self.decimals
+		}
+
+		#[ink(message, selector = 0xfc3c75d4)]
+		pub fn mint(&mut self, account: AccountId, amount: Balance) -> Ack {
+			self.ensure_minter().map(|_| self.mint_to(account, amount)).into()
+		}
+
+		#[ink(message, selector = 0x7a9da510)]
+		pub fn burn(&mut self, account: AccountId, amount: Balance) -> Ack {
+			self.burn_from(account, amount).into()
+		}
+
+		fn burn_from(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+			self.ensure_minter()?;
+			let balance = self.balance_of(account);
+			if balance < amount {
+				return Err(PSP22Error::InsufficientBalance)
+			}
+			self.balances.insert(&account, &(balance - amount));
+			self.total_supply -= amount;
+			self.env().emit_event(Transfer { from: Some(account), to: None, value: amount });
+			Ok(())
+		}
+
+		fn ensure_minter(&self) -> Result<(), PSP22Error> {
+			if self.env().caller() != self.minter {
+				return Err(PSP22Error::Custom(String::from("NotMinter")))
+			}
+			Ok(())
+		}
+
+		fn mint_to(&mut self, account: AccountId, amount: Balance) {
+			let balance = self.balance_of(account);
+			self.balances.insert(&account, &(balance + amount));
+			self.total_supply += amount;
+			self.env().emit_event(Transfer { from: None, to: Some(account), value: amount });
+		}
+
+		fn transfer_from_to(
+			&mut self,
+			from: AccountId,
+			to: AccountId,
+			value: Balance,
+		) -> Result<(), PSP22Error> {
+			let from_balance = self.balance_of(from);
+			if from_balance < value {
+				return Err(PSP22Error::InsufficientBalance)
+			}
+			self.balances.insert(&from, &(from_balance - value));
+			let to_balance = self.balance_of(to);
+			self.balances.insert(&to, &(to_balance + value));
+			self.env().emit_event(Transfer { from: Some(from), to: Some(to), value });
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,227 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod psp34_collection {
+
+	use ink_prelude::{string::String, vec::Vec};
+	use ink_storage::{
+		traits::{PackedLayout, SpreadAllocate, SpreadLayout},
+		Mapping,
+	};
+
+	/// token identifier of PSP34
+	#[derive(
+		Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode, SpreadLayout, PackedLayout,
+	)]
+	#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout))]
+	pub enum Id {
+		U8(u8),
+		U16(u16),
+		U32(u32),
+		U64(u64),
+		U128(u128),
+		Bytes(Vec<u8>),
+	}
+
+	/// errors of the PSP34 standard
+	#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+	#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+	pub enum PSP34Error {
+		Custom(String),
+		SelfApprove,
+		NotApproved,
+		TokenExists,
+		TokenNotExists,
+		SafeTransferCheckFailed(String),
+	}
+
+	/// `Result<(), PSP34Error>` as encoded by the standard, wrapped so ink! doesn't revert the
+	/// call on errors, failed calls return them without reverting
+	#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+	#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+	pub struct Ack(Result<(), PSP34Error>);
+
+	impl From<Result<(), PSP34Error>> for Ack {
+		fn from(result: Result<(), PSP34Error>) -> Self {
+			Ack(result)
+		}
+	}
+
+	#[ink(storage)]
+	#[derive(SpreadAllocate)]
+	pub struct Psp34Collection {
+		total_supply: u128,
+		owners: Mapping<Id, AccountId>,
+		/// number of tokens held by an account
+		balances: Mapping<AccountId, u32>,
+		/// tokens of an account, by index
+		owned_tokens: Mapping<(AccountId, u128), Id>,
+		/// index of a token within the tokens of its owner
+		owned_index: Mapping<Id, u128>,
+		/// account allowed to transfer a single token
+		token_approvals: Mapping<Id, AccountId>,
+		/// account allowed to mint tokens
+		minter: AccountId,
+	}
+
+	/// Event emitted when a token transfer occurs.
+	#[ink(event)]
+	pub struct Transfer {
+		#[ink(topic)]
+		from: Option<AccountId>,
+		#[ink(topic)]
+		to: Option<AccountId>,
+		id: Id,
+	}
+
+	/// Event emitted when `operator` is allowed to transfer the token `id` of `owner`.
+	#[ink(event)]
+	pub struct Approval {
+		#[ink(topic)]
+		owner: AccountId,
+		#[ink(topic)]
+		operator: AccountId,
+		id: Option<Id>,
+		approved: bool,
+	}
+
+	impl Psp34Collection {
+		/// Creates an empty collection, the caller is allowed to mint
+		#[ink(constructor, selector = 0x9bae9d5e)]
+		pub fn new() -> Self {
+			ink_lang::utils::initialize_contract(|contract: &mut Self| {
+				contract.minter = Self::env().caller();
+			})
+		}
+
+		#[ink(message, selector = 0xcde7e55f)]
+		pub fn balance_of(&self, owner: AccountId) -> u32 {
+			self.balances.get(owner).unwrap_or_default()
+		}
+
+		#[ink(message, selector = 0x1168624d)]
+		pub fn owner_of(&self, id: Id) -> Option<AccountId> {
+			self.owners.get(id)
+		}
+
+		#[ink(message, selector = 0x628413fe)]
+		pub fn total_supply(&self) -> u128 {
+			self.total_supply
+		}
+
+		/// only approvals of a single token are supported
+		#[ink(message, selector = 0x1932a8b0)]
+		pub fn approve(&mut self, operator: AccountId, id: Option<Id>, approved: bool) -> Ack {
+			self.approve_token(operator, id, approved).into()
+		}
+
+		/// transfer a token of the caller, or a token the caller is approved for
+		#[ink(message, selector = 0x3128d61b)]
+		pub fn transfer(&mut self, to: AccountId, id: Id, _data: Vec<u8>) -> Ack {
+			self.transfer_token(to, id).into()
+		}
+
+		#[ink(message, selector = 0xf19d48d1)]
+		pub fn get_attribute(&self, id: Id, key: Vec<u8>) -> Option<Vec<u8>> {
+			self.owner_of(id)?;
+			match key.as_slice() {
+				b"uri" => Some(b"ipfs://psp34".to_vec()),
+				_ => None,
+			}
+		}
+
+		#[ink(message, selector = 0x3bcfb511)]
+		pub fn owners_token_by_index(
+			&self,
+			owner: AccountId,
+			index: u128,
+		) -> Result<Id, PSP34Error> {
+			self.owned_tokens.get((owner, index)).ok_or(PSP34Error::TokenNotExists)
+		}
+
+		#[ink(message, selector = 0x6c41f2ec)]
+		pub fn mint(&mut self, account: AccountId, id: Id) -> Ack {
+			self.mint_token(account, id).into()
+		}
+
+		fn approve_token(
+			&mut self,
+			operator: AccountId,
+			id: Option<Id>,
+			approved: bool,
+		) -> Result<(), PSP34Error> {
+			let caller = self.env().caller();
+			let id = id.ok_or_else(|| PSP34Error::Custom(String::from("Unsupported")))?;
+			let owner = self.owner_of(id.clone()).ok_or(PSP34Error::TokenNotExists)?;
+			if owner != caller {
+				return Err(PSP34Error::NotApproved)
+			}
+			if operator == caller {
+				return Err(PSP34Error::SelfApprove)
+			}
+
+			if approved {
+				self.token_approvals.insert(&id, &operator);
+			} else {
+				self.token_approvals.remove(&id);
+			}
+			self.env().emit_event(Approval { owner, operator, id: Some(id), approved });
+			Ok(())
+		}
+
+		fn transfer_token(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error> {
+			let caller = self.env().caller();
+			let owner = self.owner_of(id.clone()).ok_or(PSP34Error::TokenNotExists)?;
+			if owner != caller && self.token_approvals.get(&id) != Some(caller) {
+				return Err(PSP34Error::NotApproved)
+			}
+
+			self.token_approvals.remove(&id);
+			self.remove_token(owner, &id);
+			self.add_token(to, &id);
+			self.env().emit_event(Transfer { from: Some(owner), to: Some(to), id });
+			Ok(())
+		}
+
+		fn mint_token(&mut self, account: AccountId, id: Id) -> Result<(), PSP34Error> {
+			if self.env().caller() != self.minter {
+				return Err(PSP34Error::Custom(String::from("NotMinter")))
+			}
+			if self.owner_of(id.clone()).is_some() {
+				return Err(PSP34Error::TokenExists)
+			}
+
+			self.add_token(account, &id);
+			self.total_supply += 1;
+			self.env().emit_event(Transfer { from: None, to: Some(account), id });
+			Ok(())
+		}
+
+		fn add_token(&mut self, to: AccountId, id: &Id) {
+			let count = self.balance_of(to);
+			self.owners.insert(id, &to);
+			self.owned_tokens.insert((&to, &u128::from(count)), id);
+			self.owned_index.insert(id, &u128::from(count));
+			self.balances.insert(&to, &(count + 1));
+		}
+
+		fn remove_token(&mut self, from: AccountId, id: &Id) {
+			let last = u128::from(self.balance_of(from) - 1);
+			let index = self.owned_index.get(id).unwrap_or_default();
+
+			// the last token of the owner takes the place of the removed one
+			if index != last {
+				if let Some(moved) = self.owned_tokens.get((from, last)) {
+					self.owned_tokens.insert((&from, &index), &moved);
+					self.owned_index.insert(&moved, &index);
+				}
+			}
+			self.owned_tokens.remove((&from, &last));
+			self.owned_index.remove(id);
+			self.owners.remove(id);
+			self.balances.insert(&from, &(last as u32));
+		}
+	}
+}
@@ -0,0 +1,73 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_env::chain_extension::FromStatusCode;
+use ink_lang as ink;
+use ink_prelude::vec::Vec;
+
+/// input written to the extension as is, the caller encodes the arguments
+pub struct RawInput(Vec<u8>);
+
+impl scale::Encode for RawInput {
+	fn size_hint(&self) -> usize {
+		self.0.len()
+	}
+
+	fn encode_to<W: scale::Output + ?Sized>(&self, dest: &mut W) {
+		dest.write(&self.0)
+	}
+}
+
+/// output of the extension left undecoded
+pub struct RawOutput(Vec<u8>);
+
+impl scale::Decode for RawOutput {
+	fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+		let mut buf = ink_prelude::vec![0; input.remaining_len()?.unwrap_or_default()];
+		input.read(&mut buf)?;
+		Ok(RawOutput(buf))
+	}
+}
+
+/// status code returned by the extension other than success
+pub struct Status(u32);
+
+impl FromStatusCode for Status {
+	fn from_status_code(status_code: u32) -> Result<(), Self> {
+		match status_code {
+			0 => Ok(()),
+			code => Err(Status(code)),
+		}
+	}
+}
+
+#[ink::contract]
+mod raw_extension_consumer {
+
+	use super::{RawInput, RawOutput, Status, Vec};
+	use ink_env::chain_extension::ChainExtensionMethod;
+
+	#[ink(storage)]
+	pub struct RawExtensionConsumer {}
+
+	impl RawExtensionConsumer {
+		#[ink(constructor, selector = 0x9bae9d5e)]
+		pub fn new() -> Self {
+			Self {}
+		}
+
+		/// call `func_id` with `input`, returns the status code and the output of the extension
+		#[ink(message, selector = 0x00000001)]
+		pub fn call_extension(&mut self, func_id: u32, input: Vec<u8>) -> (u32, Vec<u8>) {
+			let result = ChainExtensionMethod::build(func_id)
+				.input::<RawInput>()
+				.output::<RawOutput>()
+				.handle_error_code::<Status>()
+				.call(&RawInput(input));
+
+			match result {
+				Ok(RawOutput(output)) => (0, output),
+				Err(Status(code)) => (code, Vec::new()),
+			}
+		}
+	}
+}
@@ -8,12 +8,14 @@ use crate::{ExtBuilder, ALICE};
 use codec::{Decode, Encode};
 use frame_support::{assert_ok, sp_runtime::traits::AccountIdConversion};
 use laguna_runtime::{
-	constants::LAGUNAS, Block, Contracts, Event, Origin, Runtime, System, SystemContractDeployer,
+	constants::LAGUNAS, impl_pallet_currencies::account_portfolio, Block, ContractAssetsRegistry,
+	Contracts, Event, Origin, Runtime, System, SystemContractDeployer,
 };
 use pallet_contracts_primitives::ExecReturnValue;
 use pallet_contracts_rpc_runtime_api::runtime_decl_for_ContractsApi::ContractsApi;
-use primitives::{AccountId, Balance, BlockNumber, CurrencyId, Hash, TokenId};
-use sp_core::{crypto::AccountId32, hexdisplay::AsBytesRef, Bytes};
+use pallet_currencies_rpc_runtime_api::runtime_decl_for_CurrenciesApi::CurrenciesApi;
+use primitives::{AccountId, AssetMetadata, Balance, BlockNumber, CurrencyId, Hash, TokenId};
+use sp_core::{crypto::AccountId32, hexdisplay::AsBytesRef, Bytes, U256};
 use std::str::FromStr;
 
 const LAGUNA_TOKEN: CurrencyId = CurrencyId::NativeToken(TokenId::Laguna);
@@ -396,3 +398,95 @@ fn test_fixed_address() {
 			assert_eq!(deployed_addr, &AccountId32::from(expected));
 		})
 }
+
+#[test]
+fn test_account_portfolio() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, LAGUNA_TOKEN, 10 * LAGUNAS)])
+		.build()
+		.execute_with(|| {
+			let blob = std::fs::read(
+				"../integration-tests/contracts-data/solidity/token/dist/DemoToken.wasm",
+			)
+			.expect("cound not find wasm blob");
+
+			let mut sel_constructor = Bytes::from_str("0x835a15cb")
+				.map(|v| v.to_vec())
+				.expect("unable to parse hex string");
+			sel_constructor.append(&mut "ABC".encode());
+			sel_constructor.append(&mut "XYZ".encode());
+			sel_constructor.append(&mut U256::from(1000).encode());
+
+			assert_ok!(Contracts::instantiate_with_code(
+				Origin::signed(ALICE),
+				0,
+				MAX_GAS,
+				None,
+				blob,
+				sel_constructor,
+				vec![],
+			));
+
+			let deployed_address = System::events()
+				.iter()
+				.rev()
+				.find_map(|r| {
+					if let Event::Contracts(pallet_contracts::Event::Instantiated {
+						deployer: _,
+						contract,
+					}) = &r.event
+					{
+						Some(contract.clone())
+					} else {
+						None
+					}
+				})
+				.expect("unable to find the last deployed contract");
+
+			let code_hash = Contracts::code_hash(&deployed_address).expect("contract should exist");
+			assert_ok!(ContractAssetsRegistry::approve_code_hash(Origin::root(), code_hash));
+			assert_ok!(ContractAssetsRegistry::register_asset(
+				Origin::root(),
+				deployed_address.clone(),
+				true,
+				false,
+				None
+			));
+
+			let portfolio = account_portfolio(ALICE);
+
+			let native = portfolio
+				.iter()
+				.find(|asset| asset.currency_id == LAGUNA_TOKEN)
+				.expect("native token should be listed");
+			assert_eq!(native.free, 10 * LAGUNAS);
+			assert_eq!(native.metadata.as_ref().map(|m| m.decimals), Some(18));
+
+			// contract-based tokens are described by the contract itself
+			let erc20 = portfolio
+				.iter()
+				.find(|asset| asset.currency_id == CurrencyId::Erc20(*deployed_address.as_ref()))
+				.expect("registered token should be listed");
+			assert_eq!(erc20.free, 1000);
+			assert_eq!(erc20.reserved, 0);
+			assert_eq!(
+				erc20.metadata,
+				Some(AssetMetadata {
+					name: b"ABC".to_vec(),
+					symbol: b"XYZ".to_vec(),
+					decimals: 18,
+					existential_deposit: 0,
+				})
+			);
+
+			// the runtime API describes contract-based tokens as well
+			assert_eq!(
+				<Runtime as CurrenciesApi<Block, AccountId, Balance>>::metadata(erc20.currency_id),
+				erc20.metadata
+			);
+			assert_eq!(
+				<Runtime as CurrenciesApi<Block, AccountId, Balance>>::metadata(LAGUNA_TOKEN),
+				native.metadata
+			);
+		});
+}
@@ -7,18 +7,100 @@
 #[cfg(test)]
 mod tests {
 
-	use crate::{ExtBuilder, ALICE};
-	use frame_support::assert_ok;
-	use laguna_runtime::{constants::LAGUNAS, Block, Contracts, Event, Origin, Runtime, System};
-	use pallet_contracts_primitives::ExecReturnValue;
+	use crate::{
+		contracts::consume_native_token::{deploy_contract, deploy_system_contract},
+		ExtBuilder, ALICE, BOB,
+	};
+	use codec::{Decode, Encode};
+	use frame_support::{
+		assert_ok,
+		sp_runtime::{traits::AccountIdConversion, DispatchError},
+		storage::unhashed,
+	};
+	use laguna_runtime::{
+		constants::LAGUNAS,
+		impl_pallet_contracts::chain_extensions::TOKEN_ACCESS_GUARD,
+		weights::chain_extensions::{ChainExtensionWeight, WeightInfo},
+		Block, ContractAssetsRegistry, Contracts, Currencies, Event, EvmCompat, FluentFee, Origin,
+		Runtime, System, SystemContractDeployer,
+	};
+	use orml_traits::MultiCurrency;
+	use pallet_contracts_primitives::{ContractExecResult, ExecReturnValue};
 	use pallet_contracts_rpc_runtime_api::runtime_decl_for_ContractsApi::ContractsApi;
+	use pallet_system_contract_deployer::{Capability, SystemContracts};
 	use primitives::{AccountId, Balance, BlockNumber, CurrencyId, Hash, TokenId};
-	use sp_core::Bytes;
+	use sp_core::{Bytes, H160, U256};
 	use std::str::FromStr;
 
 	const LAGUNA_TOKEN: CurrencyId = CurrencyId::NativeToken(TokenId::Laguna);
 	const MAX_GAS: u64 = 200_000_000_000;
 
+	/// contract forwarding raw inputs to the chain extension
+	fn deploy_raw_consumer() -> AccountId {
+		let blob = std::fs::read("../integration-tests/contracts-data/ink/raw_extension_consumer/dist/raw_extension_consumer.wasm")
+			.expect("unable to find wasm blob");
+
+		let sel_constructor = Bytes::from_str("0x9bae9d5e")
+			.map(|v| v.to_vec())
+			.expect("unable to parse selector");
+
+		deploy_contract(blob, sel_constructor)
+	}
+
+	/// solidity ERC20 token minting `supply` to ALICE, registered as a currency
+	fn deploy_erc20(supply: u128) -> CurrencyId {
+		let blob =
+			std::fs::read("../integration-tests/contracts-data/solidity/token/dist/DemoToken.wasm")
+				.expect("unable to find wasm blob");
+
+		let mut sel_constructor = Bytes::from_str("0x835a15cb")
+			.map(|v| v.to_vec())
+			.expect("unable to parse selector");
+		sel_constructor.append(&mut "ABC".encode());
+		sel_constructor.append(&mut "XYZ".encode());
+		sel_constructor.append(&mut U256::from(supply).encode());
+
+		let token = deploy_contract(blob, sel_constructor);
+
+		let code_hash = Contracts::code_hash(&token).expect("contract should exist");
+		assert_ok!(ContractAssetsRegistry::approve_code_hash(Origin::root(), code_hash));
+		assert_ok!(ContractAssetsRegistry::register_asset(
+			Origin::root(),
+			token.clone(),
+			true,
+			false,
+			None
+		));
+
+		CurrencyId::Erc20(*token.as_ref())
+	}
+
+	/// call `func_id` through the raw consumer as ALICE
+	fn call_raw(consumer: &AccountId, func_id: u32, input: Vec<u8>) -> ContractExecResult<Balance> {
+		let mut sel_call = Bytes::from_str("0x00000001")
+			.map(|v| v.to_vec())
+			.expect("unable to parse selector");
+		sel_call.append(&mut func_id.encode());
+		sel_call.append(&mut input.encode());
+
+		<Runtime as ContractsApi<Block, AccountId, Balance, BlockNumber, Hash>>::call(
+			ALICE,
+			consumer.clone(),
+			0,
+			MAX_GAS,
+			None,
+			sel_call,
+		)
+	}
+
+	/// status code and output of the extension
+	fn raw_output(rs: &ContractExecResult<Balance>) -> (u32, Vec<u8>) {
+		let ExecReturnValue { flags, data } = rs.result.clone().expect("execution without result");
+		assert!(flags.is_empty());
+
+		Decode::decode(&mut &data[..]).expect("unable to decode output")
+	}
+
 	#[test]
 	fn test_dummy_extension() {
 		ExtBuilder::default()
@@ -82,4 +164,342 @@ mod tests {
 				assert_eq!(data.to_vec(), [0_u8; 33]);
 			});
 	}
+
+	#[test]
+	fn test_extension_weight() {
+		let deploying_key: AccountId =
+			<Runtime as pallet_system_contract_deployer::Config>::PalletId::get()
+				.try_into_account()
+				.expect("Invalid PalletId");
+
+		ExtBuilder::default()
+			.balances(vec![
+				(ALICE, LAGUNA_TOKEN, 10 * LAGUNAS),
+				(BOB, LAGUNA_TOKEN, 10 * LAGUNAS),
+				(deploying_key, LAGUNA_TOKEN, 10 * LAGUNAS),
+			])
+			.build()
+			.execute_with(|| {
+				let blob = std::fs::read("../integration-tests/contracts-data/ink/native_fungible_token/dist/native_fungible_token.wasm")
+					.expect("unable to find wasm blob");
+
+				let mut sel_constructor = Bytes::from_str("0x45fd0674")
+					.map(|v| v.to_vec())
+					.expect("unable to parse selector");
+				sel_constructor.append(&mut 0_u32.encode());
+
+				let token = deploy_system_contract(blob, sel_constructor);
+
+				let call = |gas_limit: u64, input: Vec<u8>| {
+					<Runtime as ContractsApi<Block, AccountId, Balance, BlockNumber, Hash>>::call(
+						ALICE,
+						token.clone(),
+						0,
+						gas_limit,
+						None,
+						input,
+					)
+				};
+
+				// total_supply() pays for the storage it reads through the extension
+				let sel_total_supply = Bytes::from_str("0x18160ddd")
+					.map(|v| v.to_vec())
+					.expect("unable to parse selector");
+
+				let rs = call(MAX_GAS, sel_total_supply.clone());
+				assert!(rs.result.is_ok());
+				assert!(rs.gas_consumed > ChainExtensionWeight::<Runtime>::total_supply());
+
+				// transfer() is charged for the transfer made by the extension
+				let mut sel_transfer = Bytes::from_str("0xa9059cbb")
+					.map(|v| v.to_vec())
+					.expect("unable to parse selector");
+				sel_transfer.append(&mut BOB.encode());
+				sel_transfer.append(&mut U256::from(LAGUNAS).encode());
+
+				let rs = call(MAX_GAS, sel_transfer.clone());
+				assert!(rs.result.is_ok());
+				assert!(rs.gas_consumed > ChainExtensionWeight::<Runtime>::transfer());
+
+				// out of gas once the extension can't be paid for, leaving balances untouched
+				let rs = call(ChainExtensionWeight::<Runtime>::transfer(), sel_transfer);
+				assert_eq!(rs.result, Err(pallet_contracts::Error::<Runtime>::OutOfGas.into()));
+
+				let rs = call(ChainExtensionWeight::<Runtime>::total_supply(), sel_total_supply);
+				assert_eq!(rs.result, Err(pallet_contracts::Error::<Runtime>::OutOfGas.into()));
+
+				assert_eq!(Currencies::free_balance(BOB, LAGUNA_TOKEN), 11 * LAGUNAS);
+
+				// the worst case is charged up front, the difference with the weight of the path
+				// taken is refunded
+				let consumer = deploy_raw_consumer();
+				let refunded = |rs: &ContractExecResult<Balance>| rs.gas_required - rs.gas_consumed;
+
+				// an unknown token only pays for the metadata lookup
+				let rs = call_raw(&consumer, 204, 99_u32.encode());
+				assert_eq!(raw_output(&rs), (1, vec![]));
+				assert_eq!(
+					refunded(&rs),
+					ChainExtensionWeight::<Runtime>::total_supply() -
+						ChainExtensionWeight::<Runtime>::metadata()
+				);
+
+				// the consumer is not granted NativeTransferFrom
+				let rs = call_raw(&consumer, 207, (0_u32, ALICE, BOB, LAGUNAS).encode());
+				assert_eq!(raw_output(&rs), (403, vec![]));
+				assert_eq!(
+					refunded(&rs),
+					ChainExtensionWeight::<Runtime>::transfer_from() -
+						ChainExtensionWeight::<Runtime>::metadata() -
+						ChainExtensionWeight::<Runtime>::has_capability()
+				);
+
+				// known tokens cost what was charged
+				let rs = call_raw(&consumer, 204, 0_u32.encode());
+				assert_eq!(raw_output(&rs).0, 0);
+				assert_eq!(refunded(&rs), 0);
+
+				let rs = call_raw(&consumer, 206, (0_u32, BOB, LAGUNAS).encode());
+				assert_eq!(raw_output(&rs), (0, vec![]));
+				assert_eq!(refunded(&rs), 0);
+				assert_eq!(Currencies::free_balance(BOB, LAGUNA_TOKEN), 12 * LAGUNAS);
+			});
+	}
+
+	#[test]
+	fn test_extension_routing() {
+		ExtBuilder::default()
+			.balances(vec![(ALICE, LAGUNA_TOKEN, 10 * LAGUNAS)])
+			.enable_fee_source(vec![(LAGUNA_TOKEN, true)])
+			.build()
+			.execute_with(|| {
+				let consumer = deploy_raw_consumer();
+				let fee_token = CurrencyId::NativeToken(TokenId::FeeToken);
+				let source = H160::repeat_byte(1);
+
+				// system
+				let rs = call_raw(&consumer, 100, [7_u8; 32].encode());
+				assert_eq!(raw_output(&rs), (0, [7_u8; 32].to_vec()));
+
+				// currencies
+				let rs = call_raw(&consumer, 214, LAGUNA_TOKEN.encode());
+				assert_eq!(raw_output(&rs), (0, Currencies::total_issuance(LAGUNA_TOKEN).encode()));
+
+				// fees
+				let rs = call_raw(&consumer, 300, LAGUNA_TOKEN.encode());
+				assert_eq!(raw_output(&rs), (0, true.encode()));
+				let rs = call_raw(&consumer, 300, fee_token.encode());
+				assert_eq!(raw_output(&rs), (0, false.encode()));
+
+				let rs = call_raw(&consumer, 301, (ALICE, LAGUNA_TOKEN).encode());
+				assert_eq!(raw_output(&rs), (0, true.encode()));
+				let rs = call_raw(&consumer, 301, (ALICE, fee_token).encode());
+				assert_eq!(raw_output(&rs), (0, false.encode()));
+
+				let rs = call_raw(&consumer, 302, ALICE.encode());
+				assert_eq!(raw_output(&rs), (0, None::<CurrencyId>.encode()));
+				assert_ok!(FluentFee::set_default(Origin::signed(ALICE), LAGUNA_TOKEN));
+				let rs = call_raw(&consumer, 302, ALICE.encode());
+				assert_eq!(raw_output(&rs), (0, Some(LAGUNA_TOKEN).encode()));
+
+				// evm-compat
+				let rs = call_raw(&consumer, 400, source.encode());
+				assert_eq!(raw_output(&rs), (0, EvmCompat::to_mapped_account(source).encode()));
+				let rs = call_raw(&consumer, 401, source.encode());
+				assert_eq!(raw_output(&rs), (0, None::<AccountId>.encode()));
+			});
+	}
+
+	#[test]
+	fn test_extension_fee_preference() {
+		ExtBuilder::default()
+			.balances(vec![(ALICE, LAGUNA_TOKEN, 10 * LAGUNAS)])
+			.build()
+			.execute_with(|| {
+				let consumer = deploy_raw_consumer();
+
+				// only system contracts granted the capability can set fee sources
+				let rs = call_raw(&consumer, 303, (BOB, Some(LAGUNA_TOKEN)).encode());
+				assert_eq!(raw_output(&rs), (403, vec![]));
+				assert_eq!(FluentFee::account_fee_source_priority(&BOB), None);
+
+				SystemContracts::<Runtime>::insert(&consumer, true);
+				assert_ok!(SystemContractDeployer::grant_capability(
+					Origin::root(),
+					consumer.clone(),
+					Capability::FeePreference
+				));
+
+				let rs = call_raw(&consumer, 303, (BOB, Some(LAGUNA_TOKEN)).encode());
+				assert_eq!(raw_output(&rs), (0, vec![]));
+				assert_eq!(FluentFee::account_fee_source_priority(&BOB), Some(LAGUNA_TOKEN));
+				System::assert_has_event(Event::FluentFee(
+					pallet_fluent_fee::Event::AccountPreferenceUpdated {
+						account: BOB,
+						currency: Some(LAGUNA_TOKEN),
+					},
+				));
+
+				let rs = call_raw(&consumer, 303, (BOB, None::<CurrencyId>).encode());
+				assert_eq!(raw_output(&rs), (0, vec![]));
+				assert_eq!(FluentFee::account_fee_source_priority(&BOB), None);
+
+				// the capability is checked on every call
+				assert_ok!(SystemContractDeployer::revoke_capability(
+					Origin::root(),
+					consumer.clone(),
+					Capability::FeePreference
+				));
+				let rs = call_raw(&consumer, 303, (BOB, Some(LAGUNA_TOKEN)).encode());
+				assert_eq!(raw_output(&rs), (403, vec![]));
+				assert_eq!(FluentFee::account_fee_source_priority(&BOB), None);
+			});
+	}
+
+	#[test]
+	fn test_extension_unknown_ids() {
+		ExtBuilder::default()
+			.balances(vec![(ALICE, LAGUNA_TOKEN, 10 * LAGUNAS)])
+			.build()
+			.execute_with(|| {
+				let consumer = deploy_raw_consumer();
+				let unimplemented = Err(DispatchError::Other("Unimplemented func_id"));
+
+				// unimplemented within the range of a module
+				for func_id in [0, 150, 199, 208, 220, 299, 304, 399, 402, 499] {
+					let rs = call_raw(&consumer, func_id, LAGUNA_TOKEN.encode());
+					assert_eq!(rs.result, unimplemented, "func_id {}", func_id);
+				}
+
+				// outside of every range
+				for func_id in [500, 1000, u32::MAX] {
+					let rs = call_raw(&consumer, func_id, vec![]);
+					assert_eq!(rs.result, unimplemented, "func_id {}", func_id);
+				}
+
+				// inputs are bounded and decoded before any function runs
+				let rs = call_raw(&consumer, 300, vec![0; 256]);
+				assert_eq!(rs.result, Err(DispatchError::Other("ChainExtension input too long")));
+				let rs = call_raw(&consumer, 205, vec![0; 2]);
+				assert_eq!(
+					rs.result,
+					Err(DispatchError::Other("ChainExtension failed to decode input"))
+				);
+			});
+	}
+
+	#[test]
+	fn test_extension_currency_ids() {
+		ExtBuilder::default()
+			.balances(vec![(ALICE, LAGUNA_TOKEN, 10 * LAGUNAS), (BOB, LAGUNA_TOKEN, 10 * LAGUNAS)])
+			.build()
+			.execute_with(|| {
+				let erc20 = deploy_erc20(1000);
+				let consumer = deploy_raw_consumer();
+				let output =
+					|func_id: u32, input: Vec<u8>| raw_output(&call_raw(&consumer, func_id, input));
+
+				// native tokens are served as by 200-209
+				assert_eq!(output(210, LAGUNA_TOKEN.encode()), (0, vec![]));
+				assert_eq!(output(213, LAGUNA_TOKEN.encode()), (0, 18_u8.encode()));
+				assert_eq!(output(215, (LAGUNA_TOKEN, BOB).encode()), (0, (10 * LAGUNAS).encode()));
+				assert_eq!(
+					output(215, (LAGUNA_TOKEN, BOB).encode()),
+					output(205, (0_u32, BOB).encode())
+				);
+
+				// Erc20 tokens are read from their contract
+				assert_eq!(output(210, erc20.encode()), (0, vec![]));
+				assert_eq!(output(210, CurrencyId::Erc20([9; 32]).encode()), (1, vec![]));
+				assert_eq!(output(211, erc20.encode()), (0, b"ABC".to_vec().encode()));
+				assert_eq!(output(212, erc20.encode()), (0, b"XYZ".to_vec().encode()));
+				assert_eq!(output(213, erc20.encode()), (0, 18_u8.encode()));
+				assert_eq!(output(214, erc20.encode()), (0, 1000_u128.encode()));
+				assert_eq!(output(215, (erc20, ALICE).encode()), (0, 1000_u128.encode()));
+				assert_eq!(output(215, (erc20, BOB).encode()), (0, 0_u128.encode()));
+
+				// only the weight used by the token contract is kept, decimals don't reach it
+				let refunded = |rs: &ContractExecResult<Balance>| rs.gas_required - rs.gas_consumed;
+				let decimals = call_raw(&consumer, 213, erc20.encode());
+				assert_eq!(refunded(&decimals), 0);
+				for (func_id, input) in
+					[(211, erc20.encode()), (214, erc20.encode()), (215, (erc20, ALICE).encode())]
+				{
+					let rs = call_raw(&consumer, func_id, input);
+					assert!(refunded(&rs) > 0);
+					assert!(rs.gas_consumed > decimals.gas_consumed);
+				}
+
+				for func_id in [218, 219] {
+					assert_eq!(
+						call_raw(&consumer, func_id, erc20.encode()).result,
+						Err(DispatchError::Other("Unimplemented func_id"))
+					);
+				}
+			});
+	}
+
+	#[test]
+	fn test_extension_erc20_transfers() {
+		ExtBuilder::default()
+			.balances(vec![(ALICE, LAGUNA_TOKEN, 10 * LAGUNAS), (BOB, LAGUNA_TOKEN, 10 * LAGUNAS)])
+			.build()
+			.execute_with(|| {
+				let erc20 = deploy_erc20(1000);
+				let consumer = deploy_raw_consumer();
+				let output =
+					|func_id: u32, input: Vec<u8>| raw_output(&call_raw(&consumer, func_id, input));
+
+				// the consumer pays for the storage of the token contract
+				assert_ok!(Currencies::transfer(
+					Origin::signed(ALICE),
+					consumer.clone(),
+					LAGUNA_TOKEN,
+					LAGUNAS
+				));
+
+				// the consumer moves its own tokens, those of the user are left untouched
+				assert_eq!(output(216, (erc20, BOB, 10_u128).encode()), (2, vec![]));
+				assert_eq!(Currencies::free_balance(ALICE, erc20), 1000);
+
+				assert_ok!(Currencies::transfer(
+					Origin::signed(ALICE),
+					consumer.clone(),
+					erc20,
+					100
+				));
+				assert_eq!(output(216, (erc20, BOB, 10_u128).encode()), (0, vec![]));
+				assert_eq!(Currencies::free_balance(consumer.clone(), erc20), 90);
+				assert_eq!(Currencies::free_balance(BOB, erc20), 10);
+				assert_eq!(Currencies::free_balance(ALICE, erc20), 900);
+
+				// native transfers still move the tokens of the caller
+				assert_eq!(output(216, (LAGUNA_TOKEN, BOB, LAGUNAS).encode()), (0, vec![]));
+				assert_eq!(Currencies::free_balance(BOB, LAGUNA_TOKEN), 11 * LAGUNAS);
+
+				// transfer_from is restricted to native tokens, even once the capability is granted
+				SystemContracts::<Runtime>::insert(&consumer, true);
+				assert_ok!(SystemContractDeployer::grant_capability(
+					Origin::root(),
+					consumer.clone(),
+					Capability::NativeTransferFrom
+				));
+				assert_eq!(output(217, (erc20, ALICE, BOB, 10_u128).encode()), (403, vec![]));
+				assert_eq!(Currencies::free_balance(BOB, erc20), 10);
+				assert_eq!(output(217, (LAGUNA_TOKEN, ALICE, BOB, LAGUNAS).encode()), (0, vec![]));
+				assert_eq!(Currencies::free_balance(BOB, LAGUNA_TOKEN), 12 * LAGUNAS);
+
+				// token contracts can't be reached again while one of them is being called
+				unhashed::put(TOKEN_ACCESS_GUARD, &true);
+				assert_eq!(output(214, erc20.encode()), (403, vec![]));
+				assert_eq!(output(216, (erc20, BOB, 10_u128).encode()), (403, vec![]));
+				assert_eq!(Currencies::free_balance(BOB, erc20), 10);
+				assert_eq!(output(210, LAGUNA_TOKEN.encode()), (0, vec![]));
+
+				// the guard is cleared once an access is over
+				unhashed::kill(TOKEN_ACCESS_GUARD);
+				assert_eq!(output(214, erc20.encode()), (0, 1000_u128.encode()));
+				assert!(!unhashed::exists(TOKEN_ACCESS_GUARD));
+			});
+	}
 }
@@ -10,6 +10,7 @@ use laguna_runtime::{
 use orml_traits::MultiCurrency;
 use pallet_contracts_primitives::ExecReturnValue;
 use pallet_contracts_rpc_runtime_api::runtime_decl_for_ContractsApi::ContractsApi;
+use pallet_system_contract_deployer::Capability;
 use primitives::{AccountId, Balance, BlockNumber, CurrencyId, Hash, TokenId, TokenMetadata};
 use sp_core::{hexdisplay::AsBytesRef, Bytes, U256};
 use std::str::FromStr;
@@ -43,9 +44,17 @@ pub fn deploy_system_contract(blob: Vec<u8>, sel_constructor: Vec<u8>) -> Accoun
 				None
 			}
 		})
-		.expect("unable to find contract");
+		.expect("unable to find contract")
+		.clone();
 
-	deployed_address.clone()
+	// the native token wrapper relies on transfer_from
+	assert_ok!(laguna_runtime::SystemContractDeployer::grant_capability(
+		Origin::root(),
+		deployed_address.clone(),
+		Capability::NativeTransferFrom,
+	));
+
+	deployed_address
 }
 
 pub fn deploy_contract(blob: Vec<u8>, sel_constructor: Vec<u8>) -> AccountId {
@@ -133,7 +142,7 @@ fn test_ink_multilayer_erc20() {
 				assert!(flags.is_empty());
 
 				let name = String::decode(&mut data.as_bytes_ref()).expect("failed to decode result");
-				assert_eq!(name, LAGUNA_NATIVE_CURRENCY.name());
+				assert_eq!(Some(name.as_str()), LAGUNA_NATIVE_CURRENCY.name());
 
 				// 3. Test symbol()
 				let sel_symbol = Bytes::from_str("0x95d89b41")
@@ -154,7 +163,7 @@ fn test_ink_multilayer_erc20() {
 				assert!(flags.is_empty());
 
 				let symbol = String::decode(&mut data.as_bytes_ref()).expect("failed to decode result");
-				assert_eq!(symbol, LAGUNA_NATIVE_CURRENCY.symbol());
+				assert_eq!(Some(symbol.as_str()), LAGUNA_NATIVE_CURRENCY.symbol());
 
 				// 4. Test decimals()
 				let sel_decimals = Bytes::from_str("0x313ce567")
@@ -175,7 +184,7 @@ fn test_ink_multilayer_erc20() {
 				assert!(flags.is_empty());
 
 				let decimals = u8::decode(&mut data.as_bytes_ref()).expect("failed to decode result");
-				assert_eq!(decimals, LAGUNA_NATIVE_CURRENCY.decimals());
+				assert_eq!(Some(decimals), LAGUNA_NATIVE_CURRENCY.decimals());
 
 				// 5. Test total_supply()
 				let sel_total_supply = Bytes::from_str("0x18160ddd")
@@ -23,7 +23,7 @@ use pallet_transaction_payment::ChargeTransactionPayment;
 use crate::contracts::Contract;
 use frame_support::sp_runtime::{traits::AccountIdConversion, FixedPointNumber, FixedU128};
 use sp_core::{Bytes, U256};
-use traits::fee::FeeMeasure;
+use traits::fee::{FeeMeasure, FeeSource, InvalidFeeSource};
 
 fn balance_of(who: AccountId, asset_id: CurrencyId) -> Balance {
 	Currencies::free_balance(who, asset_id)
@@ -82,6 +82,37 @@ fn test_basic_fee_payout() {
 		});
 }
 
+#[test]
+fn test_frozen_fee_source() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, NATIVE_CURRENCY_ID, 10 * LAGUNAS)])
+		.enable_fee_source(vec![(NATIVE_CURRENCY_ID, true)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::freeze_account(Origin::root(), NATIVE_CURRENCY_ID, ALICE));
+
+			// frozen balances are not eligible to pay fees
+			assert!(matches!(
+				FeeEnablement::accepted(&ALICE, &NATIVE_CURRENCY_ID),
+				Err(InvalidFeeSource::Frozen)
+			));
+			assert_ok!(FeeEnablement::accepted(&BOB, &NATIVE_CURRENCY_ID));
+
+			let call = laguna_runtime::Call::Currencies(pallet_currencies::Call::transfer {
+				to: BOB,
+				currency_id: NATIVE_CURRENCY_ID,
+				balance: LAGUNAS,
+			});
+			let len = call.encoded_size();
+			let info = call.get_dispatch_info();
+
+			assert!(ChargeTransactionPayment::<Runtime>::from(0)
+				.pre_dispatch(&ALICE, &call, &info, len)
+				.is_err());
+			assert_eq!(balance_of(ALICE, NATIVE_CURRENCY_ID), 10 * LAGUNAS);
+		});
+}
+
 #[test]
 fn test_alt_fee_path() {
 	ExtBuilder::default()
@@ -1,17 +1,16 @@
-use crate::{Event, Runtime};
+use crate::{impl_pallet_native_asset_registry::DustReceiver, Event, NativeAssetRegistry, Runtime};
 use frame_support::{
 	parameter_types,
-	sp_runtime::traits::Zero,
-	traits::{ConstU32, Contains},
+	traits::{ConstU32, Contains, Get},
 };
 use primitives::{AccountId, Amount, Balance, CurrencyId};
 
 pub struct DustRemovalWhitelist;
 
 impl Contains<AccountId> for DustRemovalWhitelist {
-	fn contains(_: &AccountId) -> bool {
-		// TODO: all account are possible to be dust-removed now
-		false
+	fn contains(who: &AccountId) -> bool {
+		// the dust receiver must be able to take dust below the existential deposit
+		*who == DustReceiver::get()
 	}
 }
 
@@ -22,10 +21,9 @@ parameter_types! {
 orml_traits::parameter_type_with_key! {
 	pub ExistentialDeposits: |currency_id: CurrencyId| -> Balance {
 
-		match currency_id {
-			&CurrencyId::NativeToken(_) => Zero::zero(),
-			_ => Balance::max_value() // unreachable ED value for unverified currency type
-		}
+		// managed by governance through the native asset registry, unregistered custom tokens
+		// and non-native currency types get an unreachable ED, so they are not allowed to be held
+		NativeAssetRegistry::existential_deposit(*currency_id).unwrap_or(Balance::max_value())
 	};
 }
 
@@ -43,7 +41,8 @@ impl orml_tokens::Config for Runtime {
 	type CurrencyId = primitives::CurrencyId;
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
-	type OnDust = ();
+	// dust is swept to the treasury
+	type OnDust = NativeAssetRegistry;
 	type MaxLocks = MaxLocks;
 	type DustRemovalWhitelist = DustRemovalWhitelist;
 
@@ -1,20 +1,73 @@
-use crate::Runtime;
-use frame_support::{parameter_types, PalletId};
+use crate::{
+	constants::{DAYS, LAGUNAS},
+	impl_frame_system::BlockWeights,
+	ContractAssetsRegistry, Event, Runtime, Treasury, Weight,
+};
+use frame_support::{parameter_types, sp_runtime::Perbill, sp_std::prelude::*, PalletId};
 use frame_system::EnsureRoot;
-use primitives::AccountId;
+use pallet_evm::AddressMapping;
+use primitives::{AccountId, AssetStatus, Balance, BlockNumber, ContractAssetInfo, Hash};
+use sp_core::H160;
 
 parameter_types! {
 	pub const PALLET_ID: PalletId = PalletId(*b"tkn_rgst");
-	pub const MAXGAX: u64 = u64::MAX;
+	// a single token access is bounded, since transfers are declared with its max weight
+	pub MAXGAX: Weight = Perbill::from_percent(5) * BlockWeights::get().max_block;
 	pub const DEBUG: bool = true;
+	// every listed token is read from the collection
+	pub const MaxListedTokens: u32 = 100;
+	pub const RegistrationDeposit: Balance = 100 * LAGUNAS;
+	pub const ChallengePeriod: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_contract_asset_registry::Config for Runtime {
+	type Event = Event;
 	type AllowedOrigin = EnsureRoot<AccountId>;
 	type PalletId = PALLET_ID;
 	type MaxGas = MAXGAX;
+	type MaxListedTokens = MaxListedTokens;
 
 	type ContractDebugFlag = DEBUG;
 
+	type RegistrationDeposit = RegistrationDeposit;
+	type ChallengePeriod = ChallengePeriod;
+	type OnSlash = Treasury;
+
 	type WeightInfo = ();
 }
+
+/// evm address of a contract, if its account is the mapping of an H160 address
+pub fn evm_address(address: &AccountId) -> Option<H160> {
+	let source = H160::from_slice(&AsRef::<[u8]>::as_ref(address)[12..]);
+	let mapped =
+		<<Runtime as pallet_evm_compat::Config>::ContractAddressMapping as AddressMapping<
+			AccountId,
+		>>::into_account_id(source);
+
+	(mapped == *address).then_some(source)
+}
+
+pub fn contract_asset(address: AccountId) -> Option<ContractAssetInfo<AccountId, Balance, Hash>> {
+	let status = match ContractAssetsRegistry::get_registered(&address)? {
+		true => AssetStatus::Enabled,
+		false => AssetStatus::Suspended,
+	};
+
+	Some(ContractAssetInfo {
+		evm_address: evm_address(&address),
+		status,
+		verified: ContractAssetsRegistry::is_verified(&address),
+		standard: ContractAssetsRegistry::asset_standard(&address),
+		metadata: ContractAssetsRegistry::asset_metadata(address.clone()),
+		code_hash: ContractAssetsRegistry::asset_code_hash(&address),
+		address,
+	})
+}
+
+/// every registered asset, including suspended ones
+pub fn contract_assets() -> Vec<ContractAssetInfo<AccountId, Balance, Hash>> {
+	ContractAssetsRegistry::registered_assets()
+		.into_iter()
+		.filter_map(contract_asset)
+		.collect()
+}
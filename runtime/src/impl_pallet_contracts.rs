@@ -11,8 +11,10 @@ use frame_support::{parameter_types, traits::ConstU32};
 use orml_tokens::CurrencyAdapter;
 use pallet_contracts::DefaultContractAccessWeight;
 
-mod chain_extensions;
-use chain_extensions::DemoExtension;
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+pub mod chain_extensions;
+use chain_extensions::LagunaExtension;
 use frame_support::sp_runtime::Perbill;
 use pallet_contracts::weights::WeightInfo;
 use primitives::Balance;
@@ -63,7 +65,7 @@ impl pallet_contracts::Config for Runtime {
 	type CallFilter = frame_support::traits::Nothing;
 	type WeightPrice = TransactionPayment;
 	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
-	type ChainExtension = DemoExtension;
+	type ChainExtension = LagunaExtension;
 
 	type Schedule = Schedule;
 
@@ -0,0 +1,165 @@
+//! bench items of the runtime chain extension
+//!
+//! only the runtime work behind each func_id is measured, reading the input and writing the
+//! output buffer are metered by pallet-contracts itself
+
+use super::Runtime;
+use crate::{
+	constants::LAGUNAS, Currencies, EvmCompat, FeeEnablement, FluentFee, NativeAssetRegistry,
+	SystemContractDeployer,
+};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{assert_ok, sp_std::marker::PhantomData};
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrency;
+use pallet_system_contract_deployer::{Capability, SystemContracts};
+use primitives::{AccountId, CurrencyId, TokenId};
+use sp_core::H160;
+use traits::fee::FeeSource;
+
+const CURRENCY: CurrencyId = CurrencyId::NativeToken(TokenId::Laguna);
+
+pub struct Pallet<T: Config>(PhantomData<T>);
+
+pub trait Config: frame_system::Config {}
+
+impl Config for Runtime {}
+
+benchmarks! {
+	has_capability {
+		// a system contract holding the capability reads both maps
+		let contract: AccountId = whitelisted_caller();
+		SystemContracts::<Runtime>::insert(&contract, true);
+		assert_ok!(SystemContractDeployer::grant_capability(
+			RawOrigin::Root.into(),
+			contract.clone(),
+			Capability::Whitelisted,
+		));
+	}: {
+		assert!(SystemContractDeployer::has_capability(&contract, Capability::Whitelisted));
+	}
+
+	echo {
+		let input = [1_u8; 32];
+	}: {
+		// the input is echoed back as is
+		let _output = input.to_vec();
+	}
+
+	metadata {
+	}: {
+		NativeAssetRegistry::metadata(CURRENCY).expect("native token has metadata");
+	}
+
+	total_supply {
+	}: {
+		NativeAssetRegistry::metadata(CURRENCY).expect("native token has metadata");
+		Currencies::total_issuance(CURRENCY);
+	}
+
+	balance_of {
+		let who: AccountId = account("who", 0, 0);
+	}: {
+		NativeAssetRegistry::metadata(CURRENCY).expect("native token has metadata");
+		Currencies::free_balance(who, CURRENCY);
+	}
+
+	transfer {
+		let caller: AccountId = whitelisted_caller();
+		let to: AccountId = account("to", 0, 0);
+		assert_ok!(Currencies::deposit(CURRENCY, &caller, 100 * LAGUNAS));
+	}: {
+		NativeAssetRegistry::metadata(CURRENCY).expect("native token has metadata");
+		assert_ok!(Currencies::transfer(
+			RawOrigin::Signed(caller).into(),
+			to.clone(),
+			CURRENCY,
+			10 * LAGUNAS,
+		));
+	}
+	verify {
+		assert_eq!(Currencies::free_balance(to, CURRENCY), 10 * LAGUNAS);
+	}
+
+	transfer_from {
+		let contract: AccountId = account("contract", 0, 0);
+		let from: AccountId = whitelisted_caller();
+		let to: AccountId = account("to", 0, 0);
+		assert_ok!(Currencies::deposit(CURRENCY, &from, 100 * LAGUNAS));
+		// capabilities can only be granted to system contracts
+		SystemContracts::<Runtime>::insert(&contract, true);
+		assert_ok!(SystemContractDeployer::grant_capability(
+			RawOrigin::Root.into(),
+			contract.clone(),
+			Capability::NativeTransferFrom,
+		));
+	}: {
+		NativeAssetRegistry::metadata(CURRENCY).expect("native token has metadata");
+		assert!(SystemContractDeployer::has_capability(&contract, Capability::NativeTransferFrom));
+		assert_ok!(Currencies::transfer(
+			RawOrigin::Signed(from).into(),
+			to.clone(),
+			CURRENCY,
+			10 * LAGUNAS,
+		));
+	}
+	verify {
+		assert_eq!(Currencies::free_balance(to, CURRENCY), 10 * LAGUNAS);
+	}
+
+	fee_listed {
+		assert_ok!(FeeEnablement::onboard_asset(RawOrigin::Root.into(), CURRENCY, true));
+	}: {
+		assert!(FeeEnablement::listed(&CURRENCY).is_ok());
+	}
+
+	fee_accepted {
+		// an Erc20 currency is checked for its verification too
+		let token: AccountId = account("token", 0, 0);
+		let currency = CurrencyId::Erc20(token.clone().into());
+		pallet_contract_asset_registry::VerifiedAsset::<Runtime>::insert(&token, true);
+		assert_ok!(FeeEnablement::onboard_asset(RawOrigin::Root.into(), currency, true));
+		let who: AccountId = account("who", 0, 0);
+	}: {
+		assert!(FeeEnablement::listed(&currency).is_ok());
+		assert!(FeeEnablement::accepted(&who, &currency).is_ok());
+	}
+
+	default_fee_source {
+		let who: AccountId = whitelisted_caller();
+		assert_ok!(FluentFee::set_default(RawOrigin::Signed(who.clone()).into(), CURRENCY));
+	}: {
+		assert_eq!(FluentFee::account_fee_source_priority(&who), Some(CURRENCY));
+	}
+
+	set_fee_source {
+		let contract: AccountId = account("contract", 0, 0);
+		let who: AccountId = whitelisted_caller();
+		SystemContracts::<Runtime>::insert(&contract, true);
+		assert_ok!(SystemContractDeployer::grant_capability(
+			RawOrigin::Root.into(),
+			contract.clone(),
+			Capability::FeePreference,
+		));
+	}: {
+		assert!(SystemContractDeployer::has_capability(&contract, Capability::FeePreference));
+		assert_ok!(FluentFee::set_default(RawOrigin::Signed(who.clone()).into(), CURRENCY));
+	}
+	verify {
+		assert_eq!(FluentFee::account_fee_source_priority(&who), Some(CURRENCY));
+	}
+
+	mapped_account {
+		let source = H160::repeat_byte(1);
+	}: {
+		EvmCompat::to_mapped_account(source);
+	}
+
+	proxy_account {
+		let source = H160::repeat_byte(1);
+		let proxy: AccountId = account("proxy", 0, 0);
+		pallet_evm_compat::ProxyAccount::<Runtime>::insert(source, proxy);
+	}: {
+		assert!(EvmCompat::is_delegated_by(source).is_some());
+	}
+}
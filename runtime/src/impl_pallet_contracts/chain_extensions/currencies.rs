@@ -0,0 +1,397 @@
+//! currencies exposed as ERC20 tokens
+//!
+//! every function takes the currency as its first argument, followed by its own arguments:
+//!
+//! | u32 | CurrencyId | function                       |
+//! |-----|------------|--------------------------------|
+//! | 200 | 210        | is_valid_token                 |
+//! | 201 | 211        | name                           |
+//! | 202 | 212        | symbol                         |
+//! | 203 | 213        | decimals                       |
+//! | 204 | 214        | total_supply                   |
+//! | 205 | 215        | balance_of(owner)              |
+//! | 206 | 216        | transfer(to, value)            |
+//! | 207 | 217        | transfer_from(from, to, value) |
+//!
+//! 200-209 identify native tokens by a `u32`, 0 being Laguna and 1 the fee token, while 210-219
+//! take any SCALE encoded `CurrencyId`, including Erc20 tokens of the contract asset registry.
+//!
+//! transfers of native tokens move the tokens of the caller, while transfers of Erc20 tokens move
+//! the tokens held by the calling contract. transfer_from is restricted to native tokens.
+
+use super::{
+	decode_arg, unimplemented, Env, ExtensionInput, ExtensionModule, Response, RetCode, Runtime,
+	Weights,
+};
+use crate::{
+	impl_pallet_currencies::asset_metadata, weights::chain_extensions::WeightInfo,
+	ContractAssetsRegistry, Currencies, NativeAssetRegistry, SystemContractDeployer,
+};
+use codec::{Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::DispatchResultWithPostInfo,
+	sp_std::{ops::Range, vec::Vec},
+	storage::unhashed,
+	traits::Get,
+	weights::Weight,
+};
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrency;
+use pallet_contracts::chain_extension::{ChargedAmount, Ext, Result, SysConfig, UncheckedFrom};
+use pallet_currencies::weights::WeightInfo as _;
+use pallet_system_contract_deployer::Capability;
+use primitives::{AccountId, Balance, CurrencyId, TokenId};
+use traits::currencies::{TokenAccess, TokenAccessResult};
+
+type ContractAssets = <Runtime as pallet_currencies::Config>::ContractAssets;
+
+/// first func_id taking a SCALE encoded `CurrencyId`
+const CURRENCY_ID_FUNCS: u32 = 210;
+
+/// set while a token contract is called through the extension
+pub const TOKEN_ACCESS_GUARD: &[u8] = b":laguna:chain_extension:token_access";
+
+/// function called on a currency
+pub enum CurrencyCall {
+	IsValidToken,
+	Name,
+	Symbol,
+	Decimals,
+	TotalSupply,
+	BalanceOf { owner: AccountId },
+	Transfer { to: AccountId, value: Balance },
+	TransferFrom { from: AccountId, to: AccountId, value: Balance },
+}
+
+/// arguments of the currencies functions, the currency always comes first
+pub struct CurrencyInput {
+	pub currency: CurrencyId,
+	pub call: CurrencyCall,
+}
+
+impl ExtensionInput for CurrencyInput {
+	fn max_len() -> u32 {
+		(CurrencyId::max_encoded_len() + <(AccountId, AccountId, Balance)>::max_encoded_len())
+			as u32
+	}
+
+	fn decode(func_id: u32, input: &mut &[u8]) -> Result<Self> {
+		// unknown functions fail before their arguments are decoded
+		if func_id >= CURRENCY_ID_FUNCS + 10 || func_id % 10 > 7 {
+			return Err(unimplemented(func_id))
+		}
+
+		let currency = if func_id < CURRENCY_ID_FUNCS {
+			CurrencyId::NativeToken(match decode_arg::<u32>(input)? {
+				0 => TokenId::Laguna,
+				1 => TokenId::FeeToken,
+				id => TokenId::Custom(id),
+			})
+		} else {
+			decode_arg(input)?
+		};
+
+		// both ranges serve the same functions
+		let call = match func_id % 10 {
+			0 => CurrencyCall::IsValidToken,
+			1 => CurrencyCall::Name,
+			2 => CurrencyCall::Symbol,
+			3 => CurrencyCall::Decimals,
+			4 => CurrencyCall::TotalSupply,
+			5 => CurrencyCall::BalanceOf { owner: decode_arg(input)? },
+			6 => {
+				let (to, value) = decode_arg(input)?;
+				CurrencyCall::Transfer { to, value }
+			},
+			7 => {
+				let (from, to, value) = decode_arg(input)?;
+				CurrencyCall::TransferFrom { from, to, value }
+			},
+			_ => return Err(unimplemented(func_id)),
+		};
+
+		Ok(CurrencyInput { currency, call })
+	}
+}
+
+/// output of the currencies functions, encoded without any variant index
+pub enum CurrencyOutput {
+	/// name or symbol
+	Text(Vec<u8>),
+	Decimals(u8),
+	Balance(Balance),
+}
+
+impl Encode for CurrencyOutput {
+	fn size_hint(&self) -> usize {
+		match self {
+			CurrencyOutput::Text(text) => text.size_hint(),
+			CurrencyOutput::Decimals(decimals) => decimals.size_hint(),
+			CurrencyOutput::Balance(balance) => balance.size_hint(),
+		}
+	}
+
+	fn encode_to<W: codec::Output + ?Sized>(&self, dest: &mut W) {
+		match self {
+			CurrencyOutput::Text(text) => text.encode_to(dest),
+			CurrencyOutput::Decimals(decimals) => decimals.encode_to(dest),
+			CurrencyOutput::Balance(balance) => balance.encode_to(dest),
+		}
+	}
+}
+
+/// weight of a transfer made through the extension once the currency transfer itself settled,
+/// the benchmarked overhead is kept while the transfer part is replaced by its actual weight
+fn settled_weight(benchmarked: Weight, result: DispatchResultWithPostInfo) -> (Weight, RetCode) {
+	let expected = <Runtime as pallet_currencies::Config>::WeightInfo::transfer();
+	let (post_info, code) = match result {
+		Ok(post_info) => (post_info, RetCode::Success),
+		Err(e) => (e.post_info, RetCode::InsufficientBalance),
+	};
+
+	let weight = post_info
+		.actual_weight
+		.map_or(benchmarked, |actual| benchmarked.saturating_sub(expected).saturating_add(actual));
+
+	(weight, code)
+}
+
+/// upper bound of the weight spent in the token contract behind the currency
+fn contract_weight(call: &CurrencyCall, currency: &CurrencyId) -> Weight {
+	let access = ContractAssets::max_weight();
+	let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+
+	match (call, currency) {
+		(_, CurrencyId::NativeToken(_)) => 0,
+		// decimals are recorded by the registry
+		(CurrencyCall::Decimals, _) => db_weight.reads(2),
+		(CurrencyCall::Name | CurrencyCall::Symbol, _) => access.saturating_add(db_weight.reads(2)),
+		// balances read from the contract are cached
+		(CurrencyCall::TotalSupply | CurrencyCall::BalanceOf { .. }, _) =>
+			access.saturating_add(db_weight.reads_writes(2, 2)),
+		(CurrencyCall::Transfer { .. }, _) => Currencies::contract_weight(currency),
+		// only native tokens can be moved on behalf of other accounts
+		_ => 0,
+	}
+}
+
+fn is_valid(currency: CurrencyId) -> bool {
+	match currency {
+		CurrencyId::NativeToken(_) => NativeAssetRegistry::metadata(currency).is_some(),
+		CurrencyId::Erc20(address) =>
+			ContractAssetsRegistry::get_registered(AccountId::from(address)).unwrap_or_default(),
+	}
+}
+
+/// weight of reading, setting and clearing the token access guard
+fn guard_weight() -> Weight {
+	<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 2)
+}
+
+/// read a registered token, the weight charged up front is replaced by `base` and the weight
+/// actually consumed by the token contract. A failed read is reported as an invalid token
+fn read_token<E>(
+	access: TokenAccessResult<CurrencyOutput>,
+	base: Weight,
+	env: &mut Env<E>,
+	charged: ChargedAmount,
+	overhead: Weight,
+) -> Result<Response<CurrencyOutput>>
+where
+	E: Ext<T = Runtime>,
+	<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+{
+	let TokenAccessResult { weight, result } = access;
+	env.adjust_weight(charged, base.saturating_add(weight).saturating_add(overhead));
+
+	Ok(match result {
+		Ok(output) => Response::Output(output),
+		Err(_) => Response::Status(RetCode::InvalidTokenId),
+	})
+}
+
+/// functions served once the currency and the caller are known to be allowed, `overhead` is
+/// added to the actual weight of transfers
+fn execute<E>(
+	currency: CurrencyId,
+	call: CurrencyCall,
+	env: &mut Env<E>,
+	charged: ChargedAmount,
+	overhead: Weight,
+) -> Result<Response<CurrencyOutput>>
+where
+	E: Ext<T = Runtime>,
+	<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+{
+	if let CurrencyId::Erc20(address) = currency {
+		let token = AccountId::from(address);
+		match call {
+			CurrencyCall::Name =>
+				return read_token(
+					ContractAssets::name(token).map(CurrencyOutput::Text),
+					Weights::metadata(),
+					env,
+					charged,
+					overhead,
+				),
+			CurrencyCall::Symbol =>
+				return read_token(
+					ContractAssets::symbol(token).map(CurrencyOutput::Text),
+					Weights::metadata(),
+					env,
+					charged,
+					overhead,
+				),
+			CurrencyCall::Decimals =>
+				return read_token(
+					ContractAssets::decimals(token).map(CurrencyOutput::Decimals),
+					Weights::metadata(),
+					env,
+					charged,
+					overhead,
+				),
+			CurrencyCall::TotalSupply =>
+				return read_token(
+					ContractAssets::total_supply(token).map(CurrencyOutput::Balance),
+					Weights::total_supply(),
+					env,
+					charged,
+					overhead,
+				),
+			CurrencyCall::BalanceOf { owner } =>
+				return read_token(
+					ContractAssets::balance_of(token, owner).map(CurrencyOutput::Balance),
+					Weights::balance_of(),
+					env,
+					charged,
+					overhead,
+				),
+			_ => (),
+		}
+	}
+
+	match call {
+		CurrencyCall::IsValidToken => Ok(Response::Status(RetCode::Success)),
+		CurrencyCall::Name | CurrencyCall::Symbol | CurrencyCall::Decimals =>
+			match asset_metadata(currency) {
+				Some(metadata) => Ok(Response::Output(match call {
+					CurrencyCall::Name => CurrencyOutput::Text(metadata.name),
+					CurrencyCall::Symbol => CurrencyOutput::Text(metadata.symbol),
+					_ => CurrencyOutput::Decimals(metadata.decimals),
+				})),
+				None => Ok(Response::Status(RetCode::InvalidTokenId)),
+			},
+		CurrencyCall::TotalSupply =>
+			Ok(Response::Output(CurrencyOutput::Balance(Currencies::total_issuance(currency)))),
+		CurrencyCall::BalanceOf { owner } =>
+			Ok(Response::Output(CurrencyOutput::Balance(Currencies::free_balance(owner, currency)))),
+		CurrencyCall::Transfer { to, value } => {
+			let from: AccountId = match currency {
+				CurrencyId::NativeToken(_) => env.ext().caller().clone(),
+				// the user can't be impersonated in a token contract, the calling contract moves
+				// its own tokens
+				CurrencyId::Erc20(_) => env.ext().address().clone(),
+			};
+
+			let origin = RawOrigin::Signed(from);
+			let result = Currencies::transfer(origin.into(), to, currency, value);
+
+			let (actual, code) = settled_weight(Weights::transfer(), result);
+			env.adjust_weight(charged, actual.saturating_add(overhead));
+
+			Ok(Response::Status(code))
+		},
+		CurrencyCall::TransferFrom { from, to, value } => {
+			// @dev: This is an UNSAFE method. Only whitelisted contracts can access it!
+
+			let contract: AccountId = env.ext().address().clone();
+
+			// Verify that the contract is authorised to do this operation
+			if !SystemContractDeployer::has_capability(&contract, Capability::NativeTransferFrom) {
+				env.adjust_weight(
+					charged,
+					Weights::metadata().saturating_add(Weights::has_capability()),
+				);
+				return Ok(Response::Status(RetCode::AccessDenied))
+			}
+
+			let origin = RawOrigin::Signed(from);
+			let result = Currencies::transfer(origin.into(), to, currency, value);
+
+			let (actual, code) = settled_weight(Weights::transfer_from(), result);
+			env.adjust_weight(charged, actual.saturating_add(overhead));
+
+			Ok(Response::Status(code))
+		},
+	}
+}
+
+pub struct CurrenciesExtension;
+
+impl ExtensionModule for CurrenciesExtension {
+	const FUNC_IDS: Range<u32> = 200..300;
+
+	type Input = CurrencyInput;
+	type Output = CurrencyOutput;
+
+	fn call<E>(input: CurrencyInput, env: &mut Env<E>) -> Result<Response<CurrencyOutput>>
+	where
+		E: Ext<T = Runtime>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		let CurrencyInput { currency, call } = input;
+		let token = match currency {
+			CurrencyId::NativeToken(_) => None,
+			CurrencyId::Erc20(address) => Some(AccountId::from(address)),
+		};
+
+		// @dev: the worst case is charged up front and refunded once the actual path is known
+		let charged = env.charge_weight(
+			match call {
+				CurrencyCall::TotalSupply => Weights::total_supply(),
+				CurrencyCall::BalanceOf { .. } => Weights::balance_of(),
+				CurrencyCall::Transfer { .. } => Weights::transfer(),
+				CurrencyCall::TransferFrom { .. } => Weights::transfer_from(),
+				_ => Weights::metadata(),
+			}
+			.saturating_add(contract_weight(&call, &currency))
+			.saturating_add(token.as_ref().map_or(0, |_| guard_weight())),
+		)?;
+
+		if !is_valid(currency) {
+			env.adjust_weight(charged, Weights::metadata());
+			return Ok(Response::Status(RetCode::InvalidTokenId))
+		}
+
+		let token = match token {
+			Some(token) => token,
+			None => return execute(currency, call, env, charged, 0),
+		};
+
+		// only native tokens can be moved on behalf of any account
+		if let CurrencyCall::TransferFrom { .. } = call {
+			env.adjust_weight(charged, Weights::metadata());
+			return Ok(Response::Status(RetCode::AccessDenied))
+		}
+
+		// @dev: token contracts are reached through `bare_call`, which starts a new call stack
+		// not bounded by the depth of the calling contract. A token contract calling back into
+		// the extension while one of its accesses is in progress, or accessing itself, is
+		// denied so that contracts can't recurse through the extension or reenter a token
+		// halfway through its own execution.
+		if unhashed::exists(TOKEN_ACCESS_GUARD) || token == *env.ext().address() {
+			env.adjust_weight(
+				charged,
+				Weights::metadata()
+					.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(1)),
+			);
+			return Ok(Response::Status(RetCode::AccessDenied))
+		}
+
+		unhashed::put(TOKEN_ACCESS_GUARD, &true);
+		let response = execute(currency, call, env, charged, guard_weight());
+		unhashed::kill(TOKEN_ACCESS_GUARD);
+
+		response
+	}
+}
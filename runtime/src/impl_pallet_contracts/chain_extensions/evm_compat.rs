@@ -0,0 +1,89 @@
+//! accounts behind eth addresses
+//!
+//! | u32 | function              | output            |
+//! |-----|-----------------------|-------------------|
+//! | 400 | mapped_account(H160)  | AccountId         |
+//! | 401 | proxy_account(H160)   | Option<AccountId> |
+//!
+//! every eth address is mapped to an account, which may delegate to a proxy account set through
+//! pallet-evm-compat.
+
+use super::{
+	decode_arg, unimplemented, Env, ExtensionInput, ExtensionModule, Response, Runtime, Weights,
+};
+use crate::{weights::chain_extensions::WeightInfo, EvmCompat};
+use codec::Encode;
+use frame_support::sp_std::ops::Range;
+use pallet_contracts::chain_extension::{Ext, Result, SysConfig, UncheckedFrom};
+use primitives::AccountId;
+use sp_core::H160;
+
+/// arguments of the evm-compat functions
+pub enum EvmCompatInput {
+	MappedAccount { source: H160 },
+	ProxyAccount { source: H160 },
+}
+
+impl ExtensionInput for EvmCompatInput {
+	fn max_len() -> u32 {
+		H160::len_bytes() as u32
+	}
+
+	fn decode(func_id: u32, input: &mut &[u8]) -> Result<Self> {
+		match func_id {
+			400 => Ok(EvmCompatInput::MappedAccount { source: decode_arg(input)? }),
+			401 => Ok(EvmCompatInput::ProxyAccount { source: decode_arg(input)? }),
+			_ => Err(unimplemented(func_id)),
+		}
+	}
+}
+
+/// output of the evm-compat functions, encoded without any variant index
+pub enum EvmCompatOutput {
+	Account(AccountId),
+	Proxy(Option<AccountId>),
+}
+
+impl Encode for EvmCompatOutput {
+	fn size_hint(&self) -> usize {
+		match self {
+			EvmCompatOutput::Account(account) => account.size_hint(),
+			EvmCompatOutput::Proxy(proxy) => proxy.size_hint(),
+		}
+	}
+
+	fn encode_to<W: codec::Output + ?Sized>(&self, dest: &mut W) {
+		match self {
+			EvmCompatOutput::Account(account) => account.encode_to(dest),
+			EvmCompatOutput::Proxy(proxy) => proxy.encode_to(dest),
+		}
+	}
+}
+
+pub struct EvmCompatExtension;
+
+impl ExtensionModule for EvmCompatExtension {
+	const FUNC_IDS: Range<u32> = 400..500;
+
+	type Input = EvmCompatInput;
+	type Output = EvmCompatOutput;
+
+	fn call<E>(input: EvmCompatInput, env: &mut Env<E>) -> Result<Response<EvmCompatOutput>>
+	where
+		E: Ext<T = Runtime>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		let output = match input {
+			EvmCompatInput::MappedAccount { source } => {
+				env.charge_weight(Weights::mapped_account())?;
+				EvmCompatOutput::Account(EvmCompat::to_mapped_account(source))
+			},
+			EvmCompatInput::ProxyAccount { source } => {
+				env.charge_weight(Weights::proxy_account())?;
+				EvmCompatOutput::Proxy(EvmCompat::is_delegated_by(source))
+			},
+		};
+
+		Ok(Response::Output(output))
+	}
+}
@@ -0,0 +1,134 @@
+//! fee sources of accounts
+//!
+//! | u32 | function                                      | output             |
+//! |-----|-----------------------------------------------|--------------------|
+//! | 300 | is_listed(CurrencyId)                         | bool               |
+//! | 301 | is_accepted(AccountId, CurrencyId)            | bool               |
+//! | 302 | default_fee_source(AccountId)                 | Option<CurrencyId> |
+//! | 303 | set_fee_source(AccountId, Option<CurrencyId>) |                    |
+//!
+//! a currency is listed once enabled as a fee source, an account is accepted to pay fees with a
+//! listed currency as long as the currency is healthy and the account not frozen.
+//!
+//! set_fee_source updates the default fee source of any account and requires the `FeePreference`
+//! capability, `None` unsets it.
+
+use super::{
+	decode_arg, unimplemented, Env, ExtensionInput, ExtensionModule, Response, RetCode, Runtime,
+	Weights,
+};
+use crate::{
+	weights::chain_extensions::WeightInfo, FeeEnablement, FluentFee, Origin, SystemContractDeployer,
+};
+use codec::{Encode, MaxEncodedLen};
+use frame_support::sp_std::ops::Range;
+use frame_system::RawOrigin;
+use pallet_contracts::chain_extension::{Ext, Result, SysConfig, UncheckedFrom};
+use pallet_system_contract_deployer::Capability;
+use primitives::{AccountId, CurrencyId};
+use traits::fee::FeeSource;
+
+/// arguments of the fees functions
+pub enum FeesInput {
+	IsListed { currency: CurrencyId },
+	IsAccepted { who: AccountId, currency: CurrencyId },
+	DefaultFeeSource { who: AccountId },
+	SetFeeSource { who: AccountId, currency: Option<CurrencyId> },
+}
+
+impl ExtensionInput for FeesInput {
+	fn max_len() -> u32 {
+		<(AccountId, Option<CurrencyId>)>::max_encoded_len() as u32
+	}
+
+	fn decode(func_id: u32, input: &mut &[u8]) -> Result<Self> {
+		match func_id {
+			300 => Ok(FeesInput::IsListed { currency: decode_arg(input)? }),
+			301 => {
+				let (who, currency) = decode_arg(input)?;
+				Ok(FeesInput::IsAccepted { who, currency })
+			},
+			302 => Ok(FeesInput::DefaultFeeSource { who: decode_arg(input)? }),
+			303 => {
+				let (who, currency) = decode_arg(input)?;
+				Ok(FeesInput::SetFeeSource { who, currency })
+			},
+			_ => Err(unimplemented(func_id)),
+		}
+	}
+}
+
+/// output of the fees functions, encoded without any variant index
+pub enum FeesOutput {
+	Bool(bool),
+	FeeSource(Option<CurrencyId>),
+}
+
+impl Encode for FeesOutput {
+	fn size_hint(&self) -> usize {
+		match self {
+			FeesOutput::Bool(value) => value.size_hint(),
+			FeesOutput::FeeSource(source) => source.size_hint(),
+		}
+	}
+
+	fn encode_to<W: codec::Output + ?Sized>(&self, dest: &mut W) {
+		match self {
+			FeesOutput::Bool(value) => value.encode_to(dest),
+			FeesOutput::FeeSource(source) => source.encode_to(dest),
+		}
+	}
+}
+
+pub struct FeesExtension;
+
+impl ExtensionModule for FeesExtension {
+	const FUNC_IDS: Range<u32> = 300..400;
+
+	type Input = FeesInput;
+	type Output = FeesOutput;
+
+	fn call<E>(input: FeesInput, env: &mut Env<E>) -> Result<Response<FeesOutput>>
+	where
+		E: Ext<T = Runtime>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		let output = match input {
+			FeesInput::IsListed { currency } => {
+				env.charge_weight(Weights::fee_listed())?;
+				FeesOutput::Bool(FeeEnablement::listed(&currency).is_ok())
+			},
+			FeesInput::IsAccepted { who, currency } => {
+				env.charge_weight(Weights::fee_accepted())?;
+				FeesOutput::Bool(
+					FeeEnablement::listed(&currency)
+						.and_then(|_| FeeEnablement::accepted(&who, &currency))
+						.is_ok(),
+				)
+			},
+			FeesInput::DefaultFeeSource { who } => {
+				env.charge_weight(Weights::default_fee_source())?;
+				FeesOutput::FeeSource(FluentFee::account_fee_source_priority(&who))
+			},
+			FeesInput::SetFeeSource { who, currency } => {
+				let charged = env.charge_weight(Weights::set_fee_source())?;
+
+				let contract: AccountId = env.ext().address().clone();
+				if !SystemContractDeployer::has_capability(&contract, Capability::FeePreference) {
+					env.adjust_weight(charged, Weights::has_capability());
+					return Ok(Response::Status(RetCode::AccessDenied))
+				}
+
+				let origin: Origin = RawOrigin::Signed(who).into();
+				match currency {
+					Some(currency) => FluentFee::set_default(origin, currency)?,
+					None => FluentFee::unset_default(origin)?,
+				}
+
+				return Ok(Response::Status(RetCode::Success))
+			},
+		};
+
+		Ok(Response::Output(output))
+	}
+}
@@ -0,0 +1,196 @@
+//! ## runtime chain extension
+//!
+//! func_ids are routed by range to independent extension modules:
+//!
+//! | func_id  | module                |
+//! |----------|-----------------------|
+//! | 0..200   | system                |
+//! | 200..300 | currencies            |
+//! | 300..400 | fees                  |
+//! | 400..500 | evm-compat            |
+//!
+//! a new capability is added to the module owning its range, or as a new [`ExtensionModule`]
+//! listed in [`RuntimeModules`]. Modules decode their arguments through [`ExtensionInput`] and
+//! answer with a [`Response`], either an output or a [`RetCode`] shared across modules. func_ids
+//! left unimplemented within a range, as well as those outside of every range, fail the call.
+
+use super::Runtime;
+use crate::weights::chain_extensions::ChainExtensionWeight;
+use codec::{Decode, Encode};
+use frame_support::{
+	log::error,
+	sp_runtime::{DispatchError, RuntimeDebug},
+	sp_std::ops::Range,
+};
+use pallet_contracts::chain_extension::{
+	BufInBufOutState, ChainExtension, Environment, Ext, InitState, Result, RetVal, SysConfig,
+	UncheckedFrom,
+};
+
+mod currencies;
+mod evm_compat;
+mod fees;
+mod system;
+
+pub use currencies::{CurrenciesExtension, TOKEN_ACCESS_GUARD};
+pub use evm_compat::EvmCompatExtension;
+pub use fees::FeesExtension;
+pub use system::SystemExtension;
+
+type Weights = ChainExtensionWeight<Runtime>;
+
+/// modules served by the runtime chain extension, their ranges must not overlap
+pub type RuntimeModules = (SystemExtension, CurrenciesExtension, FeesExtension, EvmCompatExtension);
+
+/// environment handed to extension modules, with input and output buffers set up
+pub type Env<'a, 'b, E> = Environment<'a, 'b, E, BufInBufOutState>;
+
+/// status code returned to contracts by every extension function
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum RetCode {
+	Success = 0,
+	InvalidTokenId = 1,
+	InsufficientBalance = 2,
+	AccessDenied = 403,
+}
+
+impl From<RetCode> for RetVal {
+	fn from(code: RetCode) -> Self {
+		RetVal::Converging(code as u32)
+	}
+}
+
+/// arguments of the functions of a module, decoded from the input buffer along with the func_id
+/// they were called with
+pub trait ExtensionInput: Sized {
+	/// upper bound of the encoded arguments of every function of the module
+	fn max_len() -> u32;
+
+	/// fails with [`unimplemented`] for func_ids the module doesn't serve
+	fn decode(func_id: u32, input: &mut &[u8]) -> Result<Self>;
+}
+
+/// answer of an extension function
+#[derive(RuntimeDebug)]
+pub enum Response<O> {
+	/// success, the output is written to the output buffer
+	Output(O),
+	/// the status code is returned without output
+	Status(RetCode),
+}
+
+/// set of chain extension functions implemented independently of the other modules
+pub trait ExtensionModule {
+	/// func_ids routed to the module
+	const FUNC_IDS: Range<u32>;
+
+	type Input: ExtensionInput;
+
+	/// outputs of the functions of the module, SCALE encoded into the output buffer
+	type Output: Encode;
+
+	fn call<E>(input: Self::Input, env: &mut Env<E>) -> Result<Response<Self::Output>>
+	where
+		E: Ext<T = Runtime>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>;
+}
+
+/// routes a func_id to the module whose range contains it, if any
+pub trait ExtensionModules {
+	fn dispatch<E>(func_id: u32, env: &mut Env<E>) -> Option<Result<RetCode>>
+	where
+		E: Ext<T = Runtime>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>;
+}
+
+macro_rules! impl_extension_modules {
+	($($module:ident),+) => {
+		impl<$($module: ExtensionModule),+> ExtensionModules for ($($module,)+) {
+			fn dispatch<E>(func_id: u32, env: &mut Env<E>) -> Option<Result<RetCode>>
+			where
+				E: Ext<T = Runtime>,
+				<E::T as SysConfig>::AccountId:
+					UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+			{
+				$(
+					if <$module as ExtensionModule>::FUNC_IDS.contains(&func_id) {
+						return Some(call_module::<$module, E>(func_id, env))
+					}
+				)+
+				None
+			}
+		}
+	};
+}
+
+impl_extension_modules!(M1);
+impl_extension_modules!(M1, M2);
+impl_extension_modules!(M1, M2, M3);
+impl_extension_modules!(M1, M2, M3, M4);
+
+/// decode the input of a module, call it and write its output
+fn call_module<M, E>(func_id: u32, env: &mut Env<E>) -> Result<RetCode>
+where
+	M: ExtensionModule,
+	E: Ext<T = Runtime>,
+	<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+{
+	// @dev: like `read_as`, the copy of a bounded input is covered by the weight each function
+	// charges
+	let len = env.in_len();
+	if len > M::Input::max_len() {
+		return Err(DispatchError::Other("ChainExtension input too long"))
+	}
+	let buffer = env.read(len)?;
+	let input = M::Input::decode(func_id, &mut &buffer[..])?;
+
+	match M::call(input, env)? {
+		Response::Output(output) => write_output(env, &output),
+		Response::Status(code) => Ok(code),
+	}
+}
+
+/// decode the next argument of a function
+pub fn decode_arg<A: Decode>(input: &mut &[u8]) -> Result<A> {
+	A::decode(input).map_err(|_| DispatchError::Other("ChainExtension failed to decode input"))
+}
+
+/// SCALE encode the output of a function into the output buffer
+pub fn write_output<E>(env: &mut Env<E>, output: &impl Encode) -> Result<RetCode>
+where
+	E: Ext<T = Runtime>,
+	<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+{
+	env.write(&output.encode(), false, None)
+		.map_err(|_| DispatchError::Other("ChainExtension failed to write output"))?;
+
+	Ok(RetCode::Success)
+}
+
+/// error of a func_id left unimplemented within the range of a module
+pub fn unimplemented(func_id: u32) -> DispatchError {
+	error!("Called an unregistered `func_id`: {:}", func_id);
+	DispatchError::Other("Unimplemented func_id")
+}
+
+#[derive(Default)]
+pub struct LagunaExtension;
+
+impl ChainExtension<Runtime> for LagunaExtension {
+	fn call<E>(&mut self, env: Environment<E, InitState>) -> Result<RetVal>
+	where
+		E: Ext<T = Runtime>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		let mut env = env.buf_in_buf_out();
+		let func_id = env.func_id();
+
+		RuntimeModules::dispatch(func_id, &mut env)
+			.unwrap_or_else(|| Err(unimplemented(func_id)))
+			.map(Into::into)
+	}
+
+	fn enabled() -> bool {
+		true
+	}
+}
@@ -0,0 +1,79 @@
+//! system level functions
+//!
+//! | u32 | function                  | output   |
+//! |-----|---------------------------|----------|
+//! | 10  | is_whitelisted            |          |
+//! | 100 | echo(bytes)               | [u8; 32] |
+
+use super::{
+	decode_arg, unimplemented, Env, ExtensionInput, ExtensionModule, Response, RetCode, Runtime,
+	Weights,
+};
+use crate::{weights::chain_extensions::WeightInfo, SystemContractDeployer};
+use frame_support::{sp_runtime::traits::AccountIdConversion, sp_std::ops::Range};
+use pallet_contracts::chain_extension::{Ext, Result, SysConfig, UncheckedFrom};
+use pallet_system_contract_deployer::Capability;
+use primitives::AccountId;
+
+/// arguments of the system functions
+pub enum SystemInput {
+	IsWhitelisted,
+	Echo([u8; 32]),
+}
+
+impl ExtensionInput for SystemInput {
+	fn max_len() -> u32 {
+		32
+	}
+
+	fn decode(func_id: u32, input: &mut &[u8]) -> Result<Self> {
+		match func_id {
+			10 => Ok(SystemInput::IsWhitelisted),
+			100 => Ok(SystemInput::Echo(decode_arg(input)?)),
+			_ => Err(unimplemented(func_id)),
+		}
+	}
+}
+
+pub struct SystemExtension;
+
+impl ExtensionModule for SystemExtension {
+	const FUNC_IDS: Range<u32> = 0..200;
+
+	type Input = SystemInput;
+	type Output = [u8; 32];
+
+	fn call<E>(input: SystemInput, env: &mut Env<E>) -> Result<Response<[u8; 32]>>
+	where
+		E: Ext<T = Runtime>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		match input {
+			SystemInput::IsWhitelisted => {
+				// Whitelist contract after verification
+				// @dev: the system deployer and system contracts granted the capability are
+				// whitelisted
+				env.charge_weight(Weights::has_capability())?;
+
+				let caller: AccountId = env.ext().caller().clone();
+				let approved_deployer =
+					<Runtime as pallet_system_contract_deployer::Config>::PalletId::get()
+						.try_into_account()
+						.expect("Invalid PalletId");
+
+				match caller == approved_deployer ||
+					SystemContractDeployer::has_capability(&caller, Capability::Whitelisted)
+				{
+					true => Ok(Response::Status(RetCode::Success)),
+					false => Ok(Response::Status(RetCode::AccessDenied)),
+				}
+			},
+			SystemInput::Echo(arg) => {
+				// echo the input back
+				env.charge_weight(Weights::echo())?;
+
+				Ok(Response::Output(arg))
+			},
+		}
+	}
+}
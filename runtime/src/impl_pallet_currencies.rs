@@ -1,12 +1,18 @@
-use frame_support::parameter_types;
+use frame_support::{parameter_types, traits::ConstU32, PalletId};
 
-use frame_support::sp_runtime::traits::ConvertInto;
-use primitives::CurrencyId;
+use frame_support::{sp_runtime::traits::ConvertInto, sp_std::prelude::*};
+use frame_system::EnsureRoot;
+use primitives::{AccountId, AssetBalance, AssetMetadata, Balance, CurrencyId, TokenId};
 
-use crate::{constants::LAGUNA_NATIVE_CURRENCY, ContractAssetsRegistry, Runtime, Tokens};
+use crate::{
+	constants::LAGUNA_NATIVE_CURRENCY, ContractAssetsRegistry, Currencies, Event,
+	NativeAssetRegistry, Runtime, Tokens, Treasury,
+};
 
 parameter_types! {
 	pub const NativeCurrencyId: CurrencyId = LAGUNA_NATIVE_CURRENCY;
+	pub const CurrenciesPalletId: PalletId = PalletId(*b"lgn/curr");
+	pub SlashReceiver: AccountId = Treasury::account_id();
 }
 
 impl pallet_currencies::Config for Runtime {
@@ -14,4 +20,69 @@ impl pallet_currencies::Config for Runtime {
 	type MultiCurrency = Tokens;
 	type ContractAssets = ContractAssetsRegistry;
 	type ConvertIntoAccountId = ConvertInto;
+	type PalletId = CurrenciesPalletId;
+	type SlashReceiver = SlashReceiver;
+	type MaxLocks = ConstU32<50>;
+	type MaxBatchTransfers = ConstU32<256>;
+	// each contract-based transfer is declared with two token accesses of 5% of the block
+	type MaxBatchContractTransfers = ConstU32<5>;
+	type AllowedOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+	type Event = Event;
+}
+
+/// all native tokens followed by the enabled contract-based tokens
+pub fn list_assets() -> Vec<CurrencyId> {
+	[CurrencyId::NativeToken(TokenId::Laguna), CurrencyId::NativeToken(TokenId::FeeToken)]
+		.into_iter()
+		.chain(NativeAssetRegistry::custom_assets())
+		.chain(
+			ContractAssetsRegistry::enabled_assets()
+				.iter()
+				.map(|v| CurrencyId::Erc20(*v.as_ref())),
+		)
+		.collect()
+}
+
+/// metadata of any currency, name and symbol of contract-based tokens are read from the contract
+pub fn asset_metadata(currency_id: CurrencyId) -> Option<AssetMetadata<Balance, Vec<u8>>> {
+	match currency_id {
+		CurrencyId::NativeToken(_) => NativeAssetRegistry::metadata(currency_id),
+		CurrencyId::Erc20(address) => ContractAssetsRegistry::asset_metadata(address.into()),
+	}
+}
+
+/// balances of the account in every listed currency
+pub fn account_portfolio(account: AccountId) -> Vec<AssetBalance<Balance, Vec<u8>>> {
+	list_assets()
+		.into_iter()
+		.map(|currency_id| match currency_id {
+			CurrencyId::NativeToken(_) => {
+				let data = Tokens::accounts(&account, currency_id);
+
+				AssetBalance {
+					currency_id,
+					free: data.free,
+					reserved: data.reserved,
+					frozen: data.frozen,
+					locks: Tokens::locks(&account, currency_id)
+						.into_iter()
+						.map(|lock| (lock.id, lock.amount))
+						.collect(),
+					metadata: asset_metadata(currency_id),
+				}
+			},
+			CurrencyId::Erc20(_) => AssetBalance {
+				currency_id,
+				free: Currencies::free_balance(account.clone(), currency_id),
+				reserved: Currencies::contract_reserves(currency_id, &account),
+				frozen: Currencies::contract_frozen_balance(currency_id, &account),
+				locks: Currencies::contract_locks(currency_id, &account)
+					.into_iter()
+					.map(|lock| (lock.id, lock.amount))
+					.collect(),
+				metadata: asset_metadata(currency_id),
+			},
+		})
+		.collect()
 }
@@ -1,11 +1,11 @@
 use crate::{impl_pallet_authorship::AuraAccountAdapter, Event, Runtime};
 use ethereum::TransactionV2 as EthereumTransaction;
 
-use crate::Timestamp;
+use crate::{impl_pallet_system_contract_deployer::ReservedEvmAddresses, Timestamp};
 use frame_support::{
 	sp_runtime::traits::{AccountIdConversion, Convert, Keccak256},
 	sp_std::prelude::*,
-	traits::{ConstU64, FindAuthor},
+	traits::{ConstU64, Contains, FindAuthor},
 };
 use pallet_contracts::AddressGenerator;
 use pallet_evm::{AddressMapping, HashedAddressMapping};
@@ -65,23 +65,35 @@ impl AddressGenerator<Runtime> for EvmCompatAdderssGenerator {
 			.try_into_account()
 			.expect("Invalid PalletId");
 
-		let generated = <CustomAddressGenerator as AddressGenerator<Runtime>>::generate_address(
-			deploying_address,
-			code_hash,
-			salt,
-		);
-		let raw: [u8; 32] = generated.into();
+		let generate = |salt: &[u8]| -> [u8; 32] {
+			<CustomAddressGenerator as AddressGenerator<Runtime>>::generate_address(
+				deploying_address,
+				code_hash,
+				salt,
+			)
+			.into()
+		};
 
-		let h_addr = if *deploying_address == key {
+		if *deploying_address == key {
 			// we took trailing 20 bytes as input for system contracts
-			H160::from_slice(&raw[12..])
-		} else {
-			// we took leading 20 bytes as input from normal contracts
-			H160::from_slice(&raw[0..20])
-		};
+			let raw = generate(salt);
+			return PlainContractAddressMapping::into_account_id(H160::from_slice(&raw[12..]))
+		}
 
-		// add contract-specific prefix
-		PlainContractAddressMapping::into_account_id(h_addr)
+		// we took leading 20 bytes as input from normal contracts, the salt is extended until
+		// the address is out of the reserved range
+		let mut salt = salt.to_vec();
+		loop {
+			let raw = generate(&salt);
+
+			// add contract-specific prefix
+			let address =
+				PlainContractAddressMapping::into_account_id(H160::from_slice(&raw[0..20]));
+			if !ReservedEvmAddresses::contains(&address) {
+				return address
+			}
+			salt.push(0);
+		}
 	}
 }
 
@@ -1,4 +1,4 @@
-use crate::{Currencies, Runtime};
+use crate::{ContractAssetsRegistry, Currencies, Runtime};
 use frame_system::EnsureRoot;
 use primitives::{AccountId, CurrencyId};
 use traits::fee::{Eligibility, FeeAssetHealth};
@@ -22,7 +22,13 @@ impl FeeAssetHealth for DefaultImpl {
 	fn health_status(asset_id: &Self::AssetId) -> Result<(), traits::fee::HealthStatusError> {
 		match asset_id {
 			CurrencyId::NativeToken(_) => Ok(()),
-			CurrencyId::Erc20(_) => Err(traits::fee::HealthStatusError::Unavailable),
+			// permissionlessly registered assets are only accepted once promoted
+			CurrencyId::Erc20(addr) =>
+				if ContractAssetsRegistry::is_verified(AccountId::from(*addr)) {
+					Ok(())
+				} else {
+					Err(traits::fee::HealthStatusError::Unverified)
+				},
 		}
 	}
 }
@@ -33,9 +39,14 @@ impl Eligibility for DefaultImpl {
 	type AssetId = CurrencyId;
 
 	fn eligible(
-		_who: &Self::AccountId,
-		_asset_id: &Self::AssetId,
+		who: &Self::AccountId,
+		asset_id: &Self::AssetId,
 	) -> Result<(), traits::fee::EligibilityError> {
+		// frozen balances can not be used to pay fees either
+		if Currencies::is_frozen(*asset_id, who) {
+			return Err(traits::fee::EligibilityError::Frozen)
+		}
+
 		Ok(())
 	}
 }
@@ -0,0 +1,18 @@
+use crate::{constants::LAGUNAS, Currencies, Event, Runtime};
+use frame_support::{parameter_types, traits::ConstU32, PalletId};
+use primitives::Balance;
+
+parameter_types! {
+	pub const MinVestedTransfer: Balance = LAGUNAS;
+	pub const MultiVestingPalletId: PalletId = PalletId(*b"lgn/vest");
+}
+
+impl pallet_multi_vesting::Config for Runtime {
+	type Event = Event;
+	type MultiCurrency = Currencies;
+	type MinVestedTransfer = MinVestedTransfer;
+	type MaxVestingSchedules = ConstU32<10>;
+	type PalletId = MultiVestingPalletId;
+
+	type WeightInfo = ();
+}
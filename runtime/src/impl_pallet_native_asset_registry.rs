@@ -0,0 +1,18 @@
+use crate::{Currencies, Event, Runtime, Treasury};
+use frame_support::{parameter_types, traits::ConstU32};
+use frame_system::EnsureRoot;
+use primitives::AccountId;
+
+parameter_types! {
+	pub DustReceiver: AccountId = Treasury::account_id();
+}
+
+impl pallet_native_asset_registry::Config for Runtime {
+	type Event = Event;
+	type AllowedOrigin = EnsureRoot<AccountId>;
+	type MultiCurrency = Currencies;
+	type StringLimit = ConstU32<32>;
+	type DustReceiver = DustReceiver;
+
+	type WeightInfo = ();
+}
@@ -1,11 +1,39 @@
-use crate::{Event, Runtime};
-use frame_support::{parameter_types, PalletId};
+use crate::{impl_pallet_evm_compat::ETH_CONTRACT_PREFIX, Event, Runtime};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, Contains},
+	PalletId,
+};
+use frame_system::EnsureRoot;
+use pallet_system_contract_deployer::SequentialAddresses;
+use primitives::AccountId;
 
 parameter_types! {
 	pub const PId: PalletId = PalletId(*b"sys_depl");
+	pub const ReservedSystemAddresses: u32 = 1024;
 }
 
 impl pallet_system_contract_deployer::Config for Runtime {
 	type Event = Event;
 	type PalletId = PId;
+	type DeployOrigin = EnsureRoot<AccountId>;
+	type UpgradeOrigin = EnsureRoot<AccountId>;
+	type ReservedAddresses = ReservedEvmAddresses;
+	type MaxCodeHashHistory = ConstU32<16>;
+}
+
+/// the first sequential addresses in their evm-compatible form, from 0x00..01 onwards
+pub struct ReservedEvmAddresses;
+
+impl Contains<AccountId> for ReservedEvmAddresses {
+	fn contains(address: &AccountId) -> bool {
+		let raw: &[u8] = address.as_ref();
+		if !raw.starts_with(ETH_CONTRACT_PREFIX) {
+			return false
+		}
+
+		let mut sequential = [0_u8; 32];
+		sequential[12..].copy_from_slice(&raw[12..]);
+		SequentialAddresses::<ReservedSystemAddresses>::contains(&sequential)
+	}
 }
@@ -39,14 +39,13 @@ use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{H160, H256, U256};
 use sp_runtime::{traits::UniqueSaturatedInto, DispatchError};
+use traits::currencies::NonFungibleAccess;
 
 use ethereum::{BlockV2 as EthereumBlock, EIP658ReceiptData, TransactionV2};
 use frame_support::sp_std::prelude::*;
 use pallet_evm_compat::tx_adapter::WEVMAdapter;
 use scale_info::prelude::format;
 
-pub mod contract_extensions;
-
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
@@ -57,7 +56,8 @@ use pallet_grandpa::{
 
 use frame_support::weights::Weight;
 use primitives::{
-	AccountId, Address, Balance, BlockNumber, CurrencyId, Hash, Header, Index, Signature,
+	AccountId, Address, AssetBalance, AssetMetadata, Balance, BlockNumber, CollectionTokens,
+	ContractAssetInfo, CurrencyEvent, CurrencyId, Hash, Header, Index, Signature,
 };
 
 // include all needed pallets and their impl below
@@ -76,6 +76,8 @@ pub mod impl_pallet_evm_compat;
 pub mod impl_pallet_fee_enablement;
 pub mod impl_pallet_fluent_fee;
 pub mod impl_pallet_granda;
+pub mod impl_pallet_multi_vesting;
+pub mod impl_pallet_native_asset_registry;
 pub mod impl_pallet_prepaid;
 pub mod impl_pallet_proxy;
 pub mod impl_pallet_scheduler;
@@ -89,7 +91,7 @@ use impl_pallet_evm_compat::ETH_CONTRACT_PREFIX;
 pub mod constants;
 
 // placeholder module to collect WeightInfo provided by runtime-benchmark
-mod weights;
+pub mod weights;
 
 // opaque module copied from substrate-node-template, allows cli to sync the network without knowing
 // runtime specific formats
@@ -178,6 +180,10 @@ construct_runtime!(
 			RandomnessCollectiveFlip: pallet_randomness_collective_flip,
 			EvmCompat: pallet_evm_compat,
 			Proxy: pallet_proxy,
+
+			// appended to keep the index of the pallets above on existing chains
+			NativeAssetRegistry: pallet_native_asset_registry,
+			MultiVesting: pallet_multi_vesting,
 		}
 );
 
@@ -217,6 +223,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	pallet_system_contract_deployer::migrations::v1::MigrateToV1<Runtime>,
 >;
 
 /// create default extra for tx request coming from eth rpc
@@ -548,7 +555,7 @@ impl_runtime_apis! {
 
 	impl pallet_currencies_rpc_runtime_api::CurrenciesApi<Block, AccountId, Balance> for Runtime {
 		fn list_assets() -> Vec<CurrencyId> {
-			ContractAssetsRegistry::enabled_assets().iter().map(|v| CurrencyId::Erc20(*v.as_ref())).collect::<_>()
+			impl_pallet_currencies::list_assets()
 		}
 
 		fn free_balance(account: AccountId, asset: CurrencyId) -> Option<Balance> {
@@ -558,6 +565,65 @@ impl_runtime_apis! {
 		fn total_balance(account: AccountId, asset: CurrencyId) -> Option<Balance> {
 			Some(Currencies::total_balance(account, asset))
 		}
+
+		fn metadata(asset: CurrencyId) -> Option<AssetMetadata<Balance, Vec<u8>>> {
+			impl_pallet_currencies::asset_metadata(asset)
+		}
+
+		fn account_portfolio(account: AccountId) -> Vec<AssetBalance<Balance, Vec<u8>>> {
+			impl_pallet_currencies::account_portfolio(account)
+		}
+
+		fn account_events(account: AccountId) -> Vec<CurrencyEvent<AccountId, Balance>> {
+			System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					Event::Currencies(event) => Currencies::account_event(event, &account),
+					_ => None,
+				})
+				.collect()
+		}
+
+		fn is_frozen(account: AccountId, asset: CurrencyId) -> bool {
+			Currencies::is_frozen(asset, &account)
+		}
+
+		fn frozen_accounts(asset: CurrencyId) -> Vec<AccountId> {
+			Currencies::frozen_accounts(asset)
+		}
+	}
+
+	impl pallet_contract_asset_registry_rpc_runtime_api::ContractAssetRegistryApi<Block, AccountId, Balance, Hash> for Runtime {
+		fn assets() -> Vec<ContractAssetInfo<AccountId, Balance, Hash>> {
+			impl_pallet_contract_asset_registry::contract_assets()
+		}
+
+		fn asset(address: AccountId) -> Option<ContractAssetInfo<AccountId, Balance, Hash>> {
+			impl_pallet_contract_asset_registry::contract_asset(address)
+		}
+	}
+
+	impl pallet_contract_asset_registry_rpc_runtime_api::NonFungibleApi<Block, AccountId> for Runtime {
+		fn collections() -> Vec<AccountId> {
+			ContractAssetsRegistry::registered_collections()
+		}
+
+		fn account_tokens(account: AccountId) -> Vec<CollectionTokens<AccountId>> {
+			ContractAssetsRegistry::account_nfts(account)
+		}
+
+		fn owner_of(collection: AccountId, token_id: U256) -> Option<AccountId> {
+			<ContractAssetsRegistry as NonFungibleAccess<Runtime>>::owner_of(collection, token_id)
+				.result
+				.ok()
+				.flatten()
+		}
+
+		fn token_uri(collection: AccountId, token_id: U256) -> Option<Vec<u8>> {
+			<ContractAssetsRegistry as NonFungibleAccess<Runtime>>::token_uri(collection, token_id)
+				.result
+				.ok()
+		}
 	}
 
 
@@ -605,6 +671,21 @@ impl_runtime_apis! {
 			 }
 		}
 
+		fn predict_contract_address(
+			deployer: pallet_evm_compat_rpc_runtime_api::ContractDeployer<AccountId>,
+			code_hash: H256,
+			salt: Vec<u8>,
+		) -> (AccountId, H160) {
+			use pallet_evm_compat_rpc_runtime_api::ContractDeployer;
+
+			match deployer {
+				ContractDeployer::Eth(source) =>
+					EvmCompat::predict_eth_contract_address(source, &code_hash, &salt),
+				ContractDeployer::Substrate(account) =>
+					EvmCompat::predict_contract_address(&account, &code_hash, &salt),
+			}
+		}
+
 		fn chain_id() -> u64 {
 			<Runtime as pallet_evm_compat::Config>::ChainId::get()
 		}
@@ -715,6 +796,7 @@ impl_runtime_apis! {
 			use frame_support::traits::StorageInfoTrait;
 			use frame_system_benchmarking::Pallet as SystemBench;
 			use baseline::Pallet as BaselineBench;
+			use impl_pallet_contracts::benchmarking::Pallet as ChainExtensionBench;
 
 			let mut list = Vec::<BenchmarkList>::new();
 
@@ -723,6 +805,7 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, frame_system, SystemBench::<Runtime>);
 
 			// include pallet benchmarks
+			list_benchmark!(list, extra, chain_extensions, ChainExtensionBench::<Runtime>);
 
 			// TODO: add all benchmarks defined by pallets
 
@@ -739,6 +822,7 @@ impl_runtime_apis! {
 
 			use frame_system_benchmarking::Pallet as SystemBench;
 			use baseline::Pallet as BaselineBench;
+			use impl_pallet_contracts::benchmarking::Pallet as ChainExtensionBench;
 
 			impl frame_system_benchmarking::Config for Runtime {}
 			impl baseline::Config for Runtime {}
@@ -765,6 +849,7 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, frame_system, SystemBench::<Runtime>);
 
 			// pallet-specific bench items
+			add_benchmark!(params, batches, chain_extensions, ChainExtensionBench::<Runtime>);
 
 			// TODO: add pallet-specific bench items below
 
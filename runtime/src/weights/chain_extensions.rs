@@ -0,0 +1,142 @@
+//! weights of the functions exposed by the runtime chain extension
+//!
+//! bench items are defined in `impl_pallet_contracts::benchmarking`. The values below are hand
+//! written estimates, the benchmarks have not been run yet: the storage accesses are exact while
+//! the base weights are not measured. Every func_id is charged, but the charges can't be relied
+//! upon to bound the work of contracts until this file is replaced by the output of
+//!
+//! ```sh
+//! cargo build --release --features runtime-benchmarks
+//! ./target/release/laguna-node benchmark pallet --chain dev --pallet chain_extensions \
+//! 	--extrinsic '*' --steps 50 --repeat 20 --output runtime/src/weights/chain_extensions.rs
+//! ```
+
+use frame_support::{sp_std::marker::PhantomData, traits::Get, weights::Weight};
+
+pub trait WeightInfo {
+	/// lookup of a system contract capability, func_id 10
+	fn has_capability() -> Weight;
+
+	/// func_id 100
+	fn echo() -> Weight;
+
+	/// currency metadata, func_id 200-203 and 210-213
+	fn metadata() -> Weight;
+
+	/// func_id 204 and 214
+	fn total_supply() -> Weight;
+
+	/// func_id 205 and 215
+	fn balance_of() -> Weight;
+
+	/// func_id 206 and 216
+	fn transfer() -> Weight;
+
+	/// func_id 207 and 217
+	fn transfer_from() -> Weight;
+
+	/// func_id 300
+	fn fee_listed() -> Weight;
+
+	/// func_id 301
+	fn fee_accepted() -> Weight;
+
+	/// func_id 302
+	fn default_fee_source() -> Weight;
+
+	/// func_id 303
+	fn set_fee_source() -> Weight;
+
+	/// func_id 400
+	fn mapped_account() -> Weight;
+
+	/// func_id 401
+	fn proxy_account() -> Weight;
+}
+
+pub struct ChainExtensionWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for ChainExtensionWeight<T> {
+	// Storage: SystemContractDeployer SystemContracts (r:1 w:0)
+	// Storage: SystemContractDeployer Capabilities (r:1 w:0)
+	fn has_capability() -> Weight {
+		(4_120_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight))
+	}
+
+	fn echo() -> Weight {
+		2_310_000 as Weight
+	}
+
+	// Storage: NativeAssetRegistry Metadata (r:1 w:0)
+	fn metadata() -> Weight {
+		(5_870_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
+
+	// Storage: NativeAssetRegistry Metadata (r:1 w:0)
+	// Storage: Tokens TotalIssuance (r:1 w:0)
+	fn total_supply() -> Weight {
+		(7_930_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight))
+	}
+
+	// Storage: NativeAssetRegistry Metadata (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:0)
+	fn balance_of() -> Weight {
+		(8_460_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight))
+	}
+
+	// Storage: NativeAssetRegistry Metadata (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn transfer() -> Weight {
+		(38_250_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+
+	// Storage: SystemContractDeployer SystemContracts (r:1 w:0)
+	// Storage: SystemContractDeployer Capabilities (r:1 w:0)
+	// Storage: NativeAssetRegistry Metadata (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn transfer_from() -> Weight {
+		(40_710_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+
+	// Storage: FeeEnablement FeeAssets (r:1 w:0)
+	fn fee_listed() -> Weight {
+		(5_140_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
+
+	// Storage: FeeEnablement FeeAssets (r:1 w:0)
+	// Storage: ContractAssetsRegistry VerifiedAsset (r:1 w:0)
+	// Storage: Currencies FrozenCurrencies (r:1 w:0)
+	// Storage: Currencies FrozenAccounts (r:1 w:0)
+	fn fee_accepted() -> Weight {
+		(11_380_000 as Weight).saturating_add(T::DbWeight::get().reads(4 as Weight))
+	}
+
+	// Storage: FluentFee DefdaultFeeSource (r:1 w:0)
+	fn default_fee_source() -> Weight {
+		(5_260_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
+
+	// Storage: SystemContractDeployer SystemContracts (r:1 w:0)
+	// Storage: SystemContractDeployer Capabilities (r:1 w:0)
+	// Storage: FluentFee DefdaultFeeSource (r:0 w:1)
+	fn set_fee_source() -> Weight {
+		(16_840_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+
+	fn mapped_account() -> Weight {
+		3_050_000 as Weight
+	}
+
+	// Storage: EvmCompat ProxyAccount (r:1 w:0)
+	fn proxy_account() -> Weight {
+		(5_090_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
+}
@@ -1,3 +1,5 @@
 //! # weight module
 //!
 //! runtime specic weight implementation for pallets, generated by `feature = runtime-benchmark`
+
+pub mod chain_extensions;